@@ -1,7 +1,11 @@
+#[cfg(feature = "std")]
+use crate::transformations::{GroupedByKey, InstrumentedCol};
 use crate::{
     transformations::{
-        ChainedCol, FilteredCol, FlattenedCol, FusedCol, ReversedCol, SkippedCol, SkippedWhileCol,
-        SteppedByCol, TakenCol, TakenWhileCol,
+        ChainedCol, DedupedByCol, DedupedByKeyCol, DedupedCol, FilteredCol, FlattenedCol,
+        FlattenedIterablesCol, FusedCol, GroupedConsecutiveBy, InterspersedCol, PaddedToCol, Pair,
+        PartitionCol, ReversedCol, SkippedCol, SkippedWhileCol, SteppedByCol, TakenCol,
+        TakenWhileCol, UnzippedLeftCol, UnzippedRightCol,
     },
     Iterable,
 };
@@ -93,6 +97,73 @@ pub trait Collection {
 
     // provided
 
+    /// Creates two collection views borrowing this collection: the first one contains the elements
+    /// satisfying the predicate `pred` while the second one contains the remaining elements.
+    ///
+    /// Elements are neither moved nor collected; each iterator of the partitions iterates over this
+    /// collection and filters its elements on the fly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (even, odd) = a.as_partitioned(|x| *x % 2 == 0);
+    ///
+    /// assert_eq!(even.iter().collect::<Vec<_>>(), [&2, &4]);
+    /// assert_eq!(odd.iter().collect::<Vec<_>>(), [&1, &3, &5]);
+    ///
+    /// fn sum(numbers: &impl Collection<Item = i32>) -> i32 {
+    ///     numbers.iter().sum()
+    /// }
+    /// assert_eq!(sum(&odd), 9);
+    /// ```
+    fn as_partitioned<P>(&self, pred: P) -> (PartitionCol<'_, Self, P>, PartitionCol<'_, Self, P>)
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool + Copy,
+    {
+        let satisfied = PartitionCol {
+            col: self,
+            pred,
+            value: true,
+        };
+        let others = PartitionCol {
+            col: self,
+            pred,
+            value: false,
+        };
+        (satisfied, others)
+    }
+
+    /// Creates two collection views borrowing this collection of pairs: the first one contains the
+    /// left elements and the second one contains the right elements of the pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![(1, 'a'), (2, 'b'), (3, 'c')];
+    ///
+    /// let (numbers, chars) = a.as_unzipped();
+    ///
+    /// assert_eq!(numbers.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    /// assert_eq!(chars.iter().collect::<String>(), "abc");
+    /// ```
+    fn as_unzipped(&self) -> (UnzippedLeftCol<'_, Self>, UnzippedRightCol<'_, Self>)
+    where
+        Self: Sized,
+        Self::Item: Pair,
+    {
+        (
+            UnzippedLeftCol { col: self },
+            UnzippedRightCol { col: self },
+        )
+    }
+
     /// Consumes this collection and `other`; creates an iterable collection which is a chain of these two
     /// collections.
     ///
@@ -126,6 +197,81 @@ pub trait Collection {
         }
     }
 
+    /// Consumes this collection and creates an iterable collection which skips consecutive duplicate elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 1, 2, 3, 3, 1];
+    ///
+    /// let it = a.into_deduped();
+    ///
+    /// assert_eq!(it.iter().count(), 4);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &3, &1]);
+    /// ```
+    fn into_deduped(self) -> DedupedCol<Self, Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.into_deduped_by(<Self::Item as PartialEq>::eq as fn(&_, &_) -> _)
+    }
+
+    /// Consumes this collection and creates an iterable collection which skips consecutive elements
+    /// that are equal with respect to the given `eq` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = ["a", "A", "b", "c", "C", "c"];
+    ///
+    /// let it = a.into_deduped_by(|x, y| x.eq_ignore_ascii_case(y));
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&"a", &"b", &"c"]);
+    /// ```
+    fn into_deduped_by<Q>(self, eq: Q) -> DedupedByCol<Self, Self, Q>
+    where
+        Self: Sized,
+        Q: Fn(&Self::Item, &Self::Item) -> bool + Copy,
+    {
+        DedupedByCol {
+            it: self,
+            eq,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Consumes this collection and creates an iterable collection which skips consecutive elements
+    /// that map to the same key by the given `key` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 3, 2, 4, 6, 5];
+    ///
+    /// let it = a.into_deduped_by_key(|x| *x % 2);
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &5]);
+    /// ```
+    fn into_deduped_by_key<F, K>(self, key: F) -> DedupedByKeyCol<Self, Self, F, K>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> K + Copy,
+        K: PartialEq,
+    {
+        DedupedByKeyCol {
+            it: self,
+            key,
+            phantom: Default::default(),
+        }
+    }
+
     /// Consumes this collection and creates an iterable collection which is a filtered version of this collection.
     ///
     /// # Examples
@@ -186,6 +332,48 @@ pub trait Collection {
         }
     }
 
+    /// Consumes this collection of collections and creates an iterable collection which flattens the elements
+    /// of the inner collections.
+    ///
+    /// This is similar to [`into_flattened`]; however, the elements of this collection are required to implement
+    /// [`Collection`] rather than `IntoIterator`, which allows flattening collections of collection transformations.
+    ///
+    /// [`into_flattened`]: crate::Collection::into_flattened
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// fn is_even(x: &u32) -> bool {
+    ///     x % 2 == 0
+    /// }
+    ///
+    /// let data = vec![
+    ///     vec![1, 2, 3, 4].into_filtered(is_even),
+    ///     vec![5, 6].into_filtered(is_even),
+    /// ];
+    ///
+    /// let mut it = data.into_flattened_iterables();
+    ///
+    /// for x in it.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().sum::<u32>(), 120);
+    /// ```
+    fn into_flattened_iterables(self) -> FlattenedIterablesCol<Self, Self>
+    where
+        Self: Sized,
+        Self::Item: Collection,
+    {
+        FlattenedIterablesCol {
+            it: self,
+            phantom: Default::default(),
+        }
+    }
+
     /// Consumes this collection and creates an iterable collection which is a fused version of this collection.
     ///
     /// See [`core::iter::Fuse`] for details on fused iterators.
@@ -199,6 +387,179 @@ pub trait Collection {
         }
     }
 
+    /// Creates an iterable over groups of consecutive elements of this collection which share the same key
+    /// computed by the `key` function.
+    ///
+    /// Each group is yielded as a `(key, group)` pair where the group is a [`ConsecutiveGroup`] collection view
+    /// over the elements of this collection; i.e., grouping does not allocate.
+    ///
+    /// Note that elements with equal keys which are not adjacent belong to different groups.
+    /// See [`grouped_by_key`] to group all elements with the same key together.
+    ///
+    /// The collection is required to be slice-backed, such as `Vec<T>` or `[T; N]`, so that each group
+    /// stores only its start position and length and iterates over its elements as a sub-slice.
+    ///
+    /// [`ConsecutiveGroup`]: crate::transformations::ConsecutiveGroup
+    /// [`grouped_by_key`]: crate::Collection::grouped_by_key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let sales = [("a", 1), ("a", 4), ("b", 2), ("c", 3), ("c", 5)];
+    ///
+    /// let groups = sales.grouped_consecutive_by(|x| x.0);
+    ///
+    /// assert_eq!(groups.iter().count(), 3);
+    ///
+    /// let totals: Vec<_> = groups
+    ///     .iter()
+    ///     .map(|(key, group)| (key, group.iter().map(|x| x.1).sum::<i32>()))
+    ///     .collect();
+    /// assert_eq!(totals, [("a", 5), ("b", 2), ("c", 8)]);
+    /// ```
+    fn grouped_consecutive_by<K, F>(&self, key: F) -> GroupedConsecutiveBy<'_, Self, K, F>
+    where
+        Self: Sized + AsRef<[Self::Item]>,
+        K: PartialEq,
+        F: Fn(&Self::Item) -> K + Copy,
+    {
+        GroupedConsecutiveBy { col: self, key }
+    }
+
+    /// Builds an index grouping positions of elements of this collection by the key computed by the `key` function.
+    ///
+    /// The index is built once; afterwards, each group can be accessed by its key or by iterating over
+    /// the index as a [`KeyGroup`] collection view over the elements of this collection.
+    ///
+    /// Groups are ordered by the first appearance of their keys.
+    ///
+    /// Each group stores positions of and references to its elements; hence, iterating over a group does not
+    /// walk over the elements of other groups.
+    ///
+    /// [`KeyGroup`]: crate::transformations::KeyGroup
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let sales = [("a", 1), ("b", 2), ("a", 4), ("c", 3), ("b", 5)];
+    ///
+    /// let groups = sales.grouped_by_key(|x| x.0);
+    ///
+    /// assert_eq!(groups.len(), 3);
+    /// assert_eq!(groups.keys().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+    ///
+    /// let b = groups.get(&"b").unwrap();
+    /// assert_eq!(b.iter().collect::<Vec<_>>(), [&("b", 2), &("b", 5)]);
+    /// assert_eq!(b.iter().map(|x| x.1).sum::<i32>(), 7);
+    ///
+    /// let totals: Vec<_> = groups
+    ///     .iter()
+    ///     .map(|(key, group)| (*key, group.iter().map(|x| x.1).sum::<i32>()))
+    ///     .collect();
+    /// assert_eq!(totals, [("a", 5), ("b", 7), ("c", 3)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn grouped_by_key<K, F>(&self, key: F) -> GroupedByKey<'_, Self, K>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash + Clone,
+        F: Fn(&Self::Item) -> K,
+    {
+        GroupedByKey::new(self, key)
+    }
+
+    /// Consumes this collection and creates an iterable collection which records the number of passes,
+    /// i.e., calls to `iter` or `iter_mut`, and the number of items pulled in each pass.
+    ///
+    /// The counts are accessible through the [`IterationStats`] handle returned by the `stats` method of
+    /// the created collection.
+    ///
+    /// [`IterationStats`]: crate::transformations::IterationStats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let col = vec![1, 2, 3].into_instrumented();
+    /// let stats = col.stats();
+    ///
+    /// assert_eq!(col.iter().sum::<i32>(), 6);
+    /// assert_eq!(col.iter().take(2).count(), 2);
+    ///
+    /// assert_eq!(stats.passes(), 2);
+    /// assert_eq!(stats.items_per_pass(), [3, 2]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn into_instrumented(self) -> InstrumentedCol<Self, Self>
+    where
+        Self: Sized,
+    {
+        InstrumentedCol {
+            it: self,
+            stats: Default::default(),
+            phantom: Default::default(),
+        }
+    }
+
+    /// Consumes this collection together with the `separator` element, and creates an iterable collection which
+    /// yields a reference to the separator between references to adjacent elements of this collection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let words = vec![String::from("a"), String::from("b")];
+    ///
+    /// let it = words.into_interspersed(String::from("-"));
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().map(|x| x.as_str()).collect::<String>(), "a-b");
+    /// ```
+    fn into_interspersed(self, separator: Self::Item) -> InterspersedCol<Self, Self>
+    where
+        Self: Sized,
+    {
+        InterspersedCol {
+            it: self,
+            separator,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Consumes this collection together with the padding `value`, and creates an iterable collection which yields
+    /// references to the elements of this collection followed by references to the padding value until at least
+    /// `len` elements are yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let row = vec![String::from("a"), String::from("b")];
+    ///
+    /// let it = row.into_padded_to(4, String::new());
+    ///
+    /// assert_eq!(it.iter().count(), 4);
+    /// assert_eq!(it.iter().filter(|x| x.is_empty()).count(), 2);
+    /// ```
+    fn into_padded_to(self, len: usize, value: Self::Item) -> PaddedToCol<Self, Self>
+    where
+        Self: Sized,
+    {
+        PaddedToCol {
+            it: self,
+            len,
+            value,
+            phantom: Default::default(),
+        }
+    }
+
     /// Consumes this collection and creates an iterable collection which is a reversed version of this collection.
     ///
     /// # Examples
@@ -1,7 +1,10 @@
+#[cfg(feature = "std")]
+use crate::transformations::InstrumentedCol;
 use crate::{
     transformations::{
-        ChainedCol, FilteredCol, FlattenedCol, FusedCol, ReversedCol, SkippedCol, SkippedWhileCol,
-        SteppedByCol, TakenCol, TakenWhileCol,
+        ChainedCol, EnumeratedFromIter, FilteredCol, FlattenedCol, FlattenedIterablesCol, FusedCol,
+        ReversedCol, SkippedCol, SkippedWhileCol, SteppedByCol, TakenCol, TakenWhileCol,
+        WithPositionIter,
     },
     Collection, Iterable,
 };
@@ -116,6 +119,58 @@ pub trait CollectionMut: Collection {
         }
     }
 
+    /// Creates an iterator yielding mutable references to the elements of this collection together with indices
+    /// starting at `start` and increasing by `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics during iteration if the index of an element overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let mut a = vec![0, 0, 0];
+    ///
+    /// for (i, x) in a.enumerated_by_mut(100, 10) {
+    ///     *x = i;
+    /// }
+    ///
+    /// assert_eq!(a, [100, 110, 120]);
+    /// ```
+    fn enumerated_by_mut(
+        &mut self,
+        start: usize,
+        step: usize,
+    ) -> EnumeratedFromIter<Self::IterMut<'_>> {
+        EnumeratedFromIter::new(self.iter_mut(), start, step)
+    }
+
+    /// Creates an iterator yielding mutable references to the elements of this collection together with indices
+    /// starting at `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics during iteration if the index of an element overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let mut a = vec![0, 0, 0];
+    ///
+    /// for (i, x) in a.enumerated_from_mut(1) {
+    ///     *x = i * i;
+    /// }
+    ///
+    /// assert_eq!(a, [1, 4, 9]);
+    /// ```
+    fn enumerated_from_mut(&mut self, start: usize) -> EnumeratedFromIter<Self::IterMut<'_>> {
+        self.enumerated_by_mut(start, 1)
+    }
+
     /// Creates an iterable collection view which is a filtered version of this collection from its mutable reference.
     ///
     /// # Examples
@@ -183,6 +238,43 @@ pub trait CollectionMut: Collection {
         }
     }
 
+    /// Creates an iterable collection view which flattens the elements of the inner collections of this
+    /// collection of collections from its mutable reference.
+    ///
+    /// This is similar to [`flattened_mut`]; however, the elements of this collection are required to implement
+    /// [`CollectionMut`] rather than `IntoIterator`.
+    ///
+    /// [`flattened_mut`]: crate::CollectionMut::flattened_mut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let mut data = vec![vec![1, 2, 3, 4].into_skipped(1), vec![5, 6].into_skipped(1)];
+    ///
+    /// let mut it = data.flattened_iterables_mut();
+    ///
+    /// for x in it.iter_mut() {
+    ///     *x *= 2;
+    /// }
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&4, &6, &8, &12]);
+    ///
+    /// // data is not consumed
+    /// assert_eq!(data[1].iter().collect::<Vec<_>>(), [&12]);
+    /// ```
+    fn flattened_iterables_mut(&mut self) -> FlattenedIterablesCol<Self, &mut Self>
+    where
+        Self: Sized,
+        Self::Item: CollectionMut,
+    {
+        FlattenedIterablesCol {
+            it: self,
+            phantom: Default::default(),
+        }
+    }
+
     /// Creates an iterable collection view which is a fused version of this collection from its mutable reference.
     ///
     /// See [`core::iter::Fuse`] for details on fused iterators.
@@ -196,6 +288,45 @@ pub trait CollectionMut: Collection {
         }
     }
 
+    /// Creates an iterable collection view which records the number of passes, i.e., calls to `iter` or `iter_mut`,
+    /// and the number of items pulled in each pass, from its mutable reference.
+    ///
+    /// The counts are accessible through the [`IterationStats`] handle returned by the `stats` method of
+    /// the created collection.
+    ///
+    /// [`IterationStats`]: crate::transformations::IterationStats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// let mut col = vec.instrumented_mut();
+    /// let stats = col.stats();
+    ///
+    /// for x in col.iter_mut() {
+    ///     *x *= 10;
+    /// }
+    /// assert_eq!(col.iter().sum::<i32>(), 60);
+    ///
+    /// assert_eq!(stats.passes(), 2);
+    /// assert_eq!(stats.total_items(), 6);
+    /// assert_eq!(vec, [10, 20, 30]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn instrumented_mut(&mut self) -> InstrumentedCol<Self, &mut Self>
+    where
+        Self: Sized,
+    {
+        InstrumentedCol {
+            it: self,
+            stats: Default::default(),
+            phantom: Default::default(),
+        }
+    }
+
     /// Creates an iterable collection view which is a reversed version of this collection from its mutable reference.
     ///
     /// # Examples
@@ -374,6 +505,33 @@ pub trait CollectionMut: Collection {
             phantom: Default::default(),
         }
     }
+
+    /// Creates an iterator yielding mutable references to the elements of this collection together with
+    /// their [`Position`]; i.e., whether the element is the first, a middle, the last or the only element.
+    ///
+    /// [`Position`]: crate::transformations::Position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use orx_iterable::transformations::Position;
+    ///
+    /// let mut a = vec![String::from("a"), String::from("b"), String::from("c")];
+    ///
+    /// for (position, x) in a.with_position_mut() {
+    ///     match position {
+    ///         Position::First => x.insert(0, '['),
+    ///         Position::Last => x.push(']'),
+    ///         _ => {}
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(a, ["[a", "b", "c]"]);
+    /// ```
+    fn with_position_mut(&mut self) -> WithPositionIter<Self::IterMut<'_>> {
+        WithPositionIter::new(self.iter_mut())
+    }
 }
 
 impl<X> CollectionMut for X
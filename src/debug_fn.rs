@@ -0,0 +1,8 @@
+/// Placeholder used in `Debug` implementations to print closures, which do not implement `Debug`, as `<fn>`.
+pub(crate) struct DebugFn;
+
+impl core::fmt::Debug for DebugFn {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("<fn>")
+    }
+}
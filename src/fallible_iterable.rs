@@ -0,0 +1,377 @@
+use crate::transformations::{
+    FallibleChained, FallibleCloned, FallibleCopied, FallibleEnumerated, FallibleFilterMapped,
+    FallibleFiltered, FallibleFlatMapped, FallibleFlattened, FallibleMapped, FallibleResults,
+    FallibleSkipped, FallibleTaken, Panicking,
+};
+
+/// A `FallibleIterable` is any type which can attempt to create a new iterator that yields elements of the
+/// associated type [`Item`] every time [`try_iter`] method is called.
+///
+/// [`Item`]: crate::FallibleIterable::Item
+/// [`try_iter`]: crate::FallibleIterable::try_iter
+///
+/// It is the fallible counterpart of [`Iterable`] for sources which must be re-opened on every pass,
+/// such as files or readers, and hence, might fail at start-up. Once the iterator is successfully
+/// created, iteration itself is infallible.
+///
+/// A fallible iterable can be bridged to an [`Iterable`]:
+/// * [`panicking`] creates an iterable which panics if the iterator cannot be created, while
+/// * [`results`] creates an iterable which yields a single `Err` element if the iterator cannot be created,
+///   and yields all elements wrapped in `Ok` otherwise.
+///
+/// [`Iterable`]: crate::Iterable
+/// [`panicking`]: crate::FallibleIterable::panicking
+/// [`results`]: crate::FallibleIterable::results
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+/// use std::cell::Cell;
+///
+/// let available = Cell::new(true);
+/// let source = fallible_source(|| match available.get() {
+///     true => Ok(vec![1, 2, 3, 4]),
+///     false => Err("source is not available"),
+/// });
+///
+/// let it = source.filtered(|x| x % 2 == 0).mapped(|x| x * 10);
+/// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![20, 40]));
+///
+/// available.set(false);
+/// assert!(it.try_iter().is_err());
+///
+/// let it = it.results();
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [Err("source is not available")]);
+///
+/// available.set(true);
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(20), Ok(40)]);
+/// ```
+pub trait FallibleIterable {
+    /// Type of the element yielded by iterators created by the `try_iter` method.
+    type Item;
+
+    /// Type of the error returned when the iterator cannot be created.
+    type Error;
+
+    /// Type of the iterator created by the `try_iter` method.
+    type Iter: Iterator<Item = Self::Item>;
+
+    /// Attempts to create a new iterator from this iterable yielding elements of type `FallibleIterable::Item`;
+    /// returns the error if the iterator cannot be created.
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error>;
+
+    // provided
+
+    /// Creates a fallible iterable which yields the elements of this iterable followed by the elements of
+    /// the `other` iterable.
+    ///
+    /// Iterators of both iterables are created when `try_iter` is called; the error of the first one which
+    /// fails is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = fallible_source(|| Ok::<_, String>(0..2));
+    /// let b = fallible_source(|| Ok::<_, String>(5..7));
+    ///
+    /// let it = a.chained(b);
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![0, 1, 5, 6]));
+    /// ```
+    fn chained<I>(self, other: I) -> FallibleChained<Self, I>
+    where
+        Self: Sized,
+        I: FallibleIterable<Item = Self::Item, Error = Self::Error>,
+    {
+        FallibleChained {
+            it1: self,
+            it2: other,
+        }
+    }
+
+    /// Creates a fallible iterable whose iterators yield clones of the references yielded by this iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let names = vec![String::from("a"), String::from("b")];
+    /// let source = fallible_source(|| Ok::<_, String>(names.iter()));
+    ///
+    /// let it = source.cloned();
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(names.clone()));
+    /// ```
+    fn cloned<'a, T>(self) -> FallibleCloned<'a, T, Self>
+    where
+        Self: Sized + FallibleIterable<Item = &'a T>,
+        T: Clone,
+    {
+        FallibleCloned { it: self }
+    }
+
+    /// Creates a fallible iterable whose iterators yield copies of the references yielded by this iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let numbers = vec![1, 2, 3];
+    /// let source = fallible_source(|| Ok::<_, String>(numbers.iter()));
+    ///
+    /// let it = source.copied();
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.sum::<i32>()), Ok(6));
+    /// ```
+    fn copied<'a, T>(self) -> FallibleCopied<'a, T, Self>
+    where
+        Self: Sized + FallibleIterable<Item = &'a T>,
+        T: Copy,
+    {
+        FallibleCopied { it: self }
+    }
+
+    /// Creates a fallible iterable which yields the elements together with their indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(['a', 'b']));
+    ///
+    /// let it = source.enumerated();
+    ///
+    /// assert_eq!(
+    ///     it.try_iter().map(|x| x.collect::<Vec<_>>()),
+    ///     Ok(vec![(0, 'a'), (1, 'b')])
+    /// );
+    /// ```
+    fn enumerated(self) -> FallibleEnumerated<Self>
+    where
+        Self: Sized,
+    {
+        FallibleEnumerated { it: self }
+    }
+
+    /// Creates a fallible iterable that both filters and maps.
+    ///
+    /// Iterators of the returned iterable yields only the values for which the supplied closure returns `Some(value)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(["1", "two", "3"]));
+    ///
+    /// let it = source.filter_mapped(|x| x.parse::<u32>().ok());
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.sum::<u32>()), Ok(4));
+    /// ```
+    fn filter_mapped<M, U>(self, filter_map: M) -> FallibleFilterMapped<Self, M, U>
+    where
+        Self: Sized,
+        M: Fn(Self::Item) -> Option<U> + Copy,
+    {
+        FallibleFilterMapped {
+            it: self,
+            filter_map,
+        }
+    }
+
+    /// Creates a fallible iterable which uses a closure to determine if an element should be yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(0..5));
+    ///
+    /// let it = source.filtered(|x| x % 2 == 1);
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![1, 3]));
+    /// ```
+    fn filtered<P>(self, filter: P) -> FallibleFiltered<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool + Copy,
+    {
+        FallibleFiltered { it: self, filter }
+    }
+
+    /// Creates a fallible iterable which maps each element to an iterator by the `flat_map` function and
+    /// flattens the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(1..4));
+    ///
+    /// let it = source.flat_mapped(|x| 0..x);
+    ///
+    /// assert_eq!(
+    ///     it.try_iter().map(|x| x.collect::<Vec<_>>()),
+    ///     Ok(vec![0, 0, 1, 0, 1, 2])
+    /// );
+    /// ```
+    fn flat_mapped<M, U>(self, flat_map: M) -> FallibleFlatMapped<Self, M, U>
+    where
+        Self: Sized,
+        U: IntoIterator,
+        M: Fn(Self::Item) -> U + Copy,
+    {
+        FallibleFlatMapped { it: self, flat_map }
+    }
+
+    /// Creates a fallible iterable which flattens the elements of this iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>([vec![1, 2], vec![], vec![3]]));
+    ///
+    /// let it = source.flattened();
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![1, 2, 3]));
+    /// ```
+    fn flattened(self) -> FallibleFlattened<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        FallibleFlattened { it: self }
+    }
+
+    /// Creates a fallible iterable which maps the elements of this iterable by the `map` function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(0..3));
+    ///
+    /// let it = source.mapped(|x| x * 2);
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![0, 2, 4]));
+    /// ```
+    fn mapped<M, U>(self, map: M) -> FallibleMapped<Self, M, U>
+    where
+        Self: Sized,
+        M: Fn(Self::Item) -> U + Copy,
+    {
+        FallibleMapped { it: self, map }
+    }
+
+    /// Converts this fallible iterable into an [`Iterable`] which panics if the iterator cannot be created.
+    ///
+    /// [`Iterable`]: crate::Iterable
+    ///
+    /// # Panics
+    ///
+    /// The `iter` method of the created iterable panics if `try_iter` of this iterable returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(vec![1, 2, 3]));
+    ///
+    /// let it = source.panicking();
+    ///
+    /// assert_eq!(it.iter().sum::<i32>(), 6);
+    /// assert_eq!(it.iter().count(), 3);
+    /// ```
+    fn panicking(self) -> Panicking<Self>
+    where
+        Self: Sized,
+        Self::Error: core::fmt::Debug,
+    {
+        Panicking { it: self }
+    }
+
+    /// Converts this fallible iterable into an [`Iterable`] yielding `Result<Item, Error>` elements.
+    ///
+    /// Every time `iter` is called, the iterator of this iterable is created:
+    /// * if it succeeds, all of its elements are yielded wrapped in `Ok`;
+    /// * otherwise, only one `Err` element holding the error is yielded.
+    ///
+    /// The created iterable is thus a [`TryIterable`], and its fallible transformations and reducers are available.
+    ///
+    /// [`Iterable`]: crate::Iterable
+    /// [`TryIterable`]: crate::TryIterable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(vec![1, 2, 3]));
+    /// let it = source.results();
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(1), Ok(2), Ok(3)]);
+    /// assert_eq!(it.try_sum::<i32>(), Ok(6));
+    ///
+    /// let source = fallible_source(|| Err::<Vec<i32>, _>("failed to open"));
+    /// let it = source.results();
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [Err("failed to open")]);
+    /// assert_eq!(it.try_sum::<i32>(), Err("failed to open"));
+    /// ```
+    fn results(self) -> FallibleResults<Self>
+    where
+        Self: Sized,
+    {
+        FallibleResults { it: self }
+    }
+
+    /// Creates a fallible iterable that skips the first `n` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(0..5));
+    ///
+    /// let it = source.skipped(3);
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![3, 4]));
+    /// ```
+    fn skipped(self, n: usize) -> FallibleSkipped<Self>
+    where
+        Self: Sized,
+    {
+        FallibleSkipped { it: self, n }
+    }
+
+    /// Creates a fallible iterable that yields the first `n` elements, or fewer if the underlying iterator ends sooner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let source = fallible_source(|| Ok::<_, String>(0..5));
+    ///
+    /// let it = source.taken(2);
+    ///
+    /// assert_eq!(it.try_iter().map(|x| x.collect::<Vec<_>>()), Ok(vec![0, 1]));
+    /// ```
+    fn taken(self, n: usize) -> FallibleTaken<Self>
+    where
+        Self: Sized,
+    {
+        FallibleTaken { it: self, n }
+    }
+}
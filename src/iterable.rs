@@ -1,7 +1,20 @@
+#[cfg(feature = "std")]
 use crate::transformations::{
-    Chained, Cloned, Copied, Enumerated, FilterMapped, Filtered, FlatMapped, Flattened, Fused,
-    Mapped, MappedWhile, Reversed, Skipped, SkippedWhile, SteppedBy, Taken, TakenWhile, Zipped,
+    checked_multipass, Cached, CheckedMultipass, Instrumented, MultipassMismatch, Unique,
+    UniqueByKey,
 };
+use crate::transformations::{
+    scanned, Chained, CircularWindows, Cloned, Copied, DebugListed, Deduped, DedupedBy,
+    DedupedByKey, Differences, Displayed, Enumerated, EnumeratedFrom, FilterMapped, Filtered,
+    FlatMapped, FlatMappedIterable, Flattened, FlattenedIterables, Fused, Inspected, Interleaved,
+    InterleavedShortest, Interspersed, InterspersedWith, IterableAsSeq, Mapped, MappedWhile,
+    PaddedTo, Paged, Pair, Partition, PrefixSums, Reversed, RunningMax, Scanned, Skipped,
+    SkippedWhile, SteppedBy, Taken, TakenWhile, UnzippedLeft, UnzippedRight, WithPosition, Zipped,
+    ZippedExact, ZippedLongest, ZippedWith,
+};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Sub};
 
 /// An `Iterable` is any type which can return a new iterator that yields elements of the associated type [`Item`] every time [`iter`] method is called.
 ///
@@ -183,6 +196,61 @@ pub trait Iterable {
 
     // provided
 
+    /// Creates a collection which materializes the elements of this iterable the first time it is iterated over,
+    /// and replays the stored elements on every later pass.
+    ///
+    /// This is useful when the elements are expensive to compute, such as the results of a [`mapped`] iterable
+    /// with a costly closure, and the iterable is iterated over multiple times.
+    ///
+    /// The created [`Cached`] is a [`Collection`] yielding references to the cached elements; and hence,
+    /// collection transformations can be applied on it. The cache can be filled eagerly by calling
+    /// [`materialize`] and it can be cleared by calling [`invalidate`].
+    ///
+    /// [`mapped`]: crate::Iterable::mapped
+    /// [`Cached`]: crate::transformations::Cached
+    /// [`Collection`]: crate::Collection
+    /// [`materialize`]: crate::transformations::Cached::materialize
+    /// [`invalidate`]: crate::transformations::Cached::invalidate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::cell::Cell;
+    ///
+    /// let num_calls = Cell::new(0);
+    /// let expensive = |x: &i32| {
+    ///     num_calls.set(num_calls.get() + 1);
+    ///     x * 10
+    /// };
+    ///
+    /// let a = [1, 2, 3];
+    /// let it = a.mapped(expensive).cached();
+    ///
+    /// assert_eq!(it.iter().sum::<i32>(), 60);
+    /// assert_eq!(it.iter().max(), Some(&30));
+    /// assert_eq!(it.into_filtered(|x| *x > 10).iter().count(), 2);
+    /// assert_eq!(num_calls.get(), 3);
+    ///
+    /// let mut it = a.mapped(expensive).cached();
+    /// it.materialize();
+    /// assert_eq!(num_calls.get(), 6);
+    ///
+    /// it.invalidate();
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(num_calls.get(), 9);
+    /// ```
+    #[cfg(feature = "std")]
+    fn cached(self) -> Cached<Self>
+    where
+        Self: Sized,
+    {
+        Cached {
+            it: self,
+            cache: Default::default(),
+        }
+    }
+
     /// Takes two iterables and creates a new iterable over both in sequence.
     ///
     /// In other words, it links two iterators together, in a chain.
@@ -214,6 +282,134 @@ pub trait Iterable {
         }
     }
 
+    /// Creates an iterable which yields the same elements as this iterable, while verifying that every
+    /// complete pass yields the same sequence as the first complete pass.
+    ///
+    /// This is a debugging aid for iterables backed by external state, which are expected to be
+    /// deterministic. The first complete pass records a [`Fingerprint`], the length and the hash, of the
+    /// sequence; every later complete pass is compared against it. Passes which are not iterated until
+    /// the end are not checked.
+    ///
+    /// See [`checked_multipass_with`] to report mismatches by a custom handler rather than panicking.
+    ///
+    /// [`Fingerprint`]: crate::transformations::Fingerprint
+    /// [`checked_multipass_with`]: crate::Iterable::checked_multipass_with
+    ///
+    /// # Panics
+    ///
+    /// Iterators of the created iterable panic when they are exhausted if they yielded a different sequence
+    /// than the first complete pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    ///
+    /// let it = a.checked_multipass();
+    ///
+    /// assert_eq!(it.iter().sum::<i32>(), 6);
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.completed_passes(), 2);
+    /// assert_eq!(it.fingerprint().map(|x| x.len), Some(3));
+    /// ```
+    #[cfg(feature = "std")]
+    fn checked_multipass(self) -> CheckedMultipass<Self, fn(&MultipassMismatch)>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+    {
+        self.checked_multipass_with(checked_multipass::panic_on_mismatch as fn(&_))
+    }
+
+    /// Creates an iterable which yields the same elements as this iterable, while verifying that every
+    /// complete pass yields the same sequence as the first complete pass; and calls `on_mismatch` with
+    /// a [`MultipassMismatch`] report whenever a later complete pass differs.
+    ///
+    /// This is the non-panicking variant of [`checked_multipass`], which can be used to log or collect
+    /// mismatches.
+    ///
+    /// [`MultipassMismatch`]: crate::transformations::MultipassMismatch
+    /// [`checked_multipass`]: crate::Iterable::checked_multipass
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::cell::Cell;
+    ///
+    /// let len = Cell::new(3);
+    /// let mismatches = Cell::new(0);
+    ///
+    /// let source = 0..10;
+    /// let it = source
+    ///     .filtered(|x| *x < len.get())
+    ///     .checked_multipass_with(|m| {
+    ///         assert_eq!(m.expected.len, 3);
+    ///         mismatches.set(mismatches.get() + 1);
+    ///     });
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(mismatches.get(), 0);
+    ///
+    /// len.set(4);
+    /// assert_eq!(it.iter().count(), 4);
+    /// assert_eq!(mismatches.get(), 1);
+    /// ```
+    #[cfg(feature = "std")]
+    fn checked_multipass_with<F>(self, on_mismatch: F) -> CheckedMultipass<Self, F>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+        F: Fn(&MultipassMismatch) + Copy,
+    {
+        CheckedMultipass::new(self, on_mismatch)
+    }
+
+    /// Creates an iterable borrowing this iterable as its source and yielding one window starting at each element
+    /// of the source; each window is itself an iterable over `size` consecutive elements which wraps around to the
+    /// beginning of the source when it reaches its end.
+    ///
+    /// Elements are not collected; each window skips the elements preceding its start and iterates over the source,
+    /// re-creating the source iterator when it wraps around.
+    ///
+    /// When `size` is greater than the number of elements of the source, windows wrap around more than once and
+    /// therefore repeat elements; for instance, windows of size 5 over `[1, 2]` are `[1, 2, 1, 2, 1]` and
+    /// `[2, 1, 2, 1, 2]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3, 4];
+    /// let numbers = a.copied();
+    ///
+    /// let windows = numbers.circular_windows(3);
+    ///
+    /// let contents: Vec<Vec<_>> = windows.iter().map(|w| w.iter().collect()).collect();
+    /// assert_eq!(
+    ///     contents,
+    ///     [vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 1], vec![4, 1, 2]]
+    /// );
+    ///
+    /// let sums: Vec<i32> = windows.iter().map(|w| w.iter().sum()).collect();
+    /// assert_eq!(sums, [6, 9, 8, 7]);
+    /// ```
+    fn circular_windows(&self, size: usize) -> CircularWindows<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(size > 0, "window size must be non-zero");
+        CircularWindows { it: self, size }
+    }
+
     /// Creates an iterable, iterators of which clone all of its elements.
     ///
     /// This is useful when you have an iterable over &T, but you need an iterable over T.
@@ -268,293 +464,1140 @@ pub trait Iterable {
         Copied { it: self }
     }
 
-    /// Creates an iterable which gives the current iteration count as well as the next value.
+    /// Creates a wrapper which implements `Debug` by writing the elements of this iterable as a list.
     ///
-    /// The iterators created by enumerated iterable yields pairs `(i, val)`,
-    /// where `i` is the current index of iteration and `val` is the value returned by the iterator.
+    /// The iterable is iterated over every time the wrapper is formatted; hence, no allocation is required.
+    /// The output can be limited to a number of elements by the [`truncated`] method of the wrapper; and texts can be
+    /// written around the list by its [`prefix`] and [`suffix`] methods.
+    ///
+    /// [`truncated`]: crate::transformations::DebugListed::truncated
+    /// [`prefix`]: crate::transformations::DebugListed::prefix
+    /// [`suffix`]: crate::transformations::DebugListed::suffix
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = ['a', 'b', 'c'];
-    /// let it = a.enumerated();
+    /// let a = vec![1, 2, 3, 4];
     ///
-    /// assert_eq!(it.iter().count(), 3);
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), vec![(0, &'a'), (1, &'b'), (2, &'c')]);
+    /// let it = a.mapped(|x| x * 10).debug_listed();
+    /// assert_eq!(format!("{:?}", it), "[10, 20, 30, 40]");
+    /// assert_eq!(format!("{:?}", it.truncated(2)), "[10, 20, ..]");
     /// ```
-    fn enumerated(self) -> Enumerated<Self>
+    fn debug_listed<'a>(self) -> DebugListed<'a, Self>
     where
         Self: Sized,
     {
-        Enumerated { it: self }
+        DebugListed {
+            it: self,
+            prefix: "",
+            suffix: "",
+            max_items: None,
+        }
     }
 
-    /// Creates an iterable that both filters and maps.
+    /// Creates an iterable which skips consecutive duplicate elements of this iterable.
     ///
-    /// Iterators of the returned iterable yields only the values for which the supplied closure returns `Some(value)`.
+    /// The created iterators yield the first element of every run of equal elements.
+    /// Note that equal elements which are not adjacent are still yielded; see [`unique`] to
+    /// remove all duplicates.
     ///
-    /// `filter_mapped` can be used to make chains of `filtered` and `mapped` more concise.
-    /// The example below shows how a `mapped().filtered().mapped()` can be shortened to a single call
-    /// to `filter_mapped`.
+    /// This transformation does not allocate.
+    ///
+    /// [`unique`]: crate::Iterable::unique
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = ["1", "two", "NaN", "four", "5"];
+    /// let a = [1, 1, 2, 3, 3, 3, 1];
     ///
-    /// let it = a.filter_mapped(|s| s.parse::<u32>().ok());
+    /// let it = a.deduped();
     ///
-    /// assert_eq!(it.iter().count(), 2);
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), vec![1, 5]);
+    /// assert_eq!(it.iter().count(), 4);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &3, &1]);
     /// ```
-    fn filter_mapped<M, U>(self, filter_map: M) -> FilterMapped<Self, M, U>
+    fn deduped(self) -> Deduped<Self>
     where
         Self: Sized,
-        M: Fn(Self::Item) -> Option<U> + Copy,
+        Self::Item: PartialEq,
     {
-        FilterMapped {
-            it: self,
-            filter_map,
-        }
+        self.deduped_by(<Self::Item as PartialEq>::eq as fn(&_, &_) -> _)
     }
 
-    /// Creates an iterable which uses a closure to determine if an element should be yielded.
+    /// Creates an iterable which skips consecutive elements of this iterable that are equal
+    /// with respect to the given `eq` function.
     ///
-    /// Given an element the closure must return true or false. Iterators of the returned iterable
-    /// will yield only the elements for which the closure returns true.
+    /// The created iterators yield the first element of every run of elements for which `eq`
+    /// returns true when compared to the first element of the run.
+    ///
+    /// This transformation does not allocate.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [0i32, 1, 2];
+    /// let a = ["a", "A", "b", "c", "C", "c"];
     ///
-    /// let it = a.filtered(|x| x.is_positive());
+    /// let it = a.deduped_by(|x, y| x.eq_ignore_ascii_case(y));
     ///
-    /// assert_eq!(it.iter().count(), 2);
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&"a", &"b", &"c"]);
     /// ```
-    fn filtered<P>(self, filter: P) -> Filtered<Self, P>
+    fn deduped_by<E>(self, eq: E) -> DedupedBy<Self, E>
     where
         Self: Sized,
-        P: Fn(&Self::Item) -> bool + Copy,
+        E: Fn(&Self::Item, &Self::Item) -> bool + Copy,
     {
-        Filtered { it: self, filter }
+        DedupedBy { it: self, eq }
     }
 
-    /// Creates an iterable that works like map, but flattens nested structure.
-    ///
-    /// You can think of `flat_mapped(f)` as the semantic equivalent of mapping,
-    /// and then flattening as in `mapped(f).flattened()`.
+    /// Creates an iterable which skips consecutive elements of this iterable that map to the
+    /// same key by the given `key` function.
     ///
-    /// Another way of thinking about `flat_mapped()`:
+    /// The created iterators yield the first element of every run of elements with equal keys.
     ///
-    /// * `mapped`’s closure returns one item for each element, and
-    /// * `flat_map()`’s closure returns an iterator for each element.
+    /// This transformation does not allocate.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let words = ["al", "p", "ha"];
+    /// let a = [1, 3, 2, 4, 6, 5];
     ///
-    /// let it = words.flat_mapped(|s| s.chars());
+    /// let it = a.deduped_by_key(|x| *x % 2);
     ///
-    /// assert_eq!(it.iter().count(), 5);
-    /// assert_eq!(it.iter().collect::<String>().as_str(), "alpha");
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &5]);
     /// ```
-    fn flat_mapped<M, U>(self, flat_map: M) -> FlatMapped<Self, M, U>
+    fn deduped_by_key<F, K>(self, key: F) -> DedupedByKey<Self, F, K>
     where
         Self: Sized,
-        U: IntoIterator,
-        M: Fn(Self::Item) -> U + Copy,
+        F: Fn(&Self::Item) -> K + Copy,
+        K: PartialEq,
     {
-        FlatMapped { it: self, flat_map }
+        DedupedByKey { it: self, key }
     }
 
-    /// Creates an iterable that flattens nested structure.
+    /// Creates an iterable which yields the differences between consecutive elements of this iterable.
     ///
-    /// This is useful when you have an iterable of iterators or an iterable of things that can be
-    /// turned into iterators and you want to remove one level of indirection.
+    /// The created iterators yield `x[i] - x[i - 1]` for `i` in `1..n`; and hence, one element fewer than
+    /// this iterable. It is built on [`scanned`].
+    ///
+    /// [`scanned`]: crate::Iterable::scanned
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let data = vec![vec![1, 2, 3, 4], vec![5, 6]];
+    /// let a = [1, 4, 6, 5];
     ///
-    /// let it = data.flattened();
+    /// let it = a.copied().differences();
     ///
-    /// assert_eq!(it.iter().count(), 6);
-    /// assert_eq!(it.iter().sum::<u32>(), 21);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [3, 2, -1]);
+    /// assert_eq!(it.iter().sum::<i32>(), 4);
     /// ```
-    fn flattened(self) -> Flattened<Self>
+    fn differences(self) -> Differences<Self>
     where
         Self: Sized,
-        Self::Item: IntoIterator,
+        Self::Item: Sub<Output = Self::Item> + Clone,
     {
-        Flattened { it: self }
+        self.scanned(None, scanned::difference as fn(&mut _, _) -> _)
+            .flattened()
     }
 
-    /// Creates an iterable which ends after the first `None`.
-    ///
-    /// After an iterator returns `None`, future calls may or may not yield `Some(T)` again.
-    /// fuse() adapts an iterator, ensuring that after a `None` is given, it will always return `None` forever.
+    /// Creates a wrapper which implements `Display` by writing the elements of this iterable joined by the `separator`.
     ///
-    /// Note that the Fuse wrapper is a no-op on iterators that implement the FusedIterator trait.
-    /// fuse() may therefore behave incorrectly if the FusedIterator trait is improperly implemented.
-    fn fused(self) -> Fused<Self>
-    where
-        Self: Sized,
-    {
-        Fused { it: self }
-    }
-
-    /// Creates an iterable that both yields elements based on a predicate and maps.
+    /// The iterable is iterated over every time the wrapper is formatted; hence, no allocation is required.
+    /// The [`prefix`], [`suffix`] and [`truncated`] methods of the wrapper can be used to customize the output.
     ///
-    /// `map_while()` takes a closure as an argument. It will call this closure on each element
-    /// of the iterator, and yield elements while it returns `Some(_)`.
+    /// [`prefix`]: crate::transformations::Displayed::prefix
+    /// [`suffix`]: crate::transformations::Displayed::suffix
+    /// [`truncated`]: crate::transformations::Displayed::truncated
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [0, 1, 2, -3, 4, 5, -6];
+    /// let a = vec![3, 1, 4, 1, 5];
     ///
-    /// let it = a.mapped_while(|x| u32::try_from(*x).ok());
+    /// let it = a.filtered(|x| **x > 1).displayed(", ");
+    /// assert_eq!(format!("{}", it), "3, 4, 5");
     ///
-    /// assert_eq!(it.iter().count(), 3);
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2]);
+    /// let it = a.displayed(" ").prefix("(").suffix(")").truncated(3);
+    /// assert_eq!(format!("{}", it), "(3 1 4 ...)");
+    /// assert_eq!(format!("{:>2}", it), "( 3  1  4 ...)");
     /// ```
-    fn mapped_while<M, U>(self, map_while: M) -> MappedWhile<Self, M, U>
+    fn displayed(self, separator: &str) -> Displayed<'_, Self>
     where
         Self: Sized,
-        M: Fn(Self::Item) -> Option<U> + Copy,
     {
-        MappedWhile {
+        Displayed {
             it: self,
-            map_while,
+            separator,
+            prefix: "",
+            suffix: "",
+            max_items: None,
         }
     }
 
-    /// Takes a closure and creates an iterable which calls that closure on each element.
+    /// Creates an iterable which gives the current iteration count as well as the next value.
     ///
-    /// map() transforms one iterator into another, by means of its argument `map`.
-    /// It produces a new iterable, iterators of which calls this closure on each element of
-    /// the original iterable.
+    /// The iterators created by enumerated iterable yields pairs `(i, val)`,
+    /// where `i` is the current index of iteration and `val` is the value returned by the iterator.
     ///
-    /// # Example
+    /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [1, 3, 6];
-    ///
-    /// let it = a.mapped(|x| 2 * x);
+    /// let a = ['a', 'b', 'c'];
+    /// let it = a.enumerated();
     ///
-    /// assert_eq!(it.iter().sum::<i32>(), 20);
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [2, 6, 12]);
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), vec![(0, &'a'), (1, &'b'), (2, &'c')]);
     /// ```
-    fn mapped<M, U>(self, map: M) -> Mapped<Self, M, U>
+    fn enumerated(self) -> Enumerated<Self>
     where
         Self: Sized,
-        M: Fn(Self::Item) -> U + Copy,
     {
-        Mapped { it: self, map }
+        Enumerated { it: self }
     }
 
-    /// Creates an iterable iterators of which reverses the traversal direction.
+    /// Creates an iterable which yields the elements together with indices starting at `start` and
+    /// increasing by `step`.
     ///
-    /// This is only possible if the iterable's iterator type has an end,
-    /// so `reversed()` only works when `Iterable::Iter` is a `DoubleEndedIterator`.
+    /// # Panics
     ///
-    /// # Example
+    /// Panics during iteration if the index of an element overflows `usize`.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [1, 2, 3];
-    ///
-    /// let it = a.reversed();
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&3, &2, &1]);
+    /// let a = ['a', 'b', 'c'];
+    /// let it = a.enumerated_by(10, 5);
     ///
-    /// let it = it.reversed();
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [(10, &'a'), (15, &'b'), (20, &'c')]);
     /// ```
-    fn reversed(self) -> Reversed<Self>
+    fn enumerated_by(self, start: usize, step: usize) -> EnumeratedFrom<Self>
     where
         Self: Sized,
-        Self::Iter: DoubleEndedIterator,
     {
-        Reversed { it: self }
+        EnumeratedFrom {
+            it: self,
+            start,
+            step,
+        }
     }
 
-    /// Creates an iterable, iterators of which skip the first `n` elements.
+    /// Creates an iterable which yields the elements together with indices starting at `start`, such as
+    /// 1-based indices.
     ///
-    /// Created iterators skip elements until n elements are skipped or the end of the iterator
-    /// is reached (whichever happens first).
-    /// After that, all the remaining elements are yielded. In particular, if the original iterator
-    /// is too short, then the returned iterator is empty.
+    /// # Panics
+    ///
+    /// Panics during iteration if the index of an element overflows `usize`.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [1, 2, 3];
-    ///
-    /// let it = a.skipped(2);
+    /// let a = ['a', 'b', 'c'];
+    /// let it = a.enumerated_from(1);
     ///
-    /// assert_eq!(it.iter().count(), 1);
-    /// assert_eq!(it.iter().next(), Some(&3));
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [(1, &'a'), (2, &'b'), (3, &'c')]);
     /// ```
-    fn skipped(self, n: usize) -> Skipped<Self>
+    fn enumerated_from(self, start: usize) -> EnumeratedFrom<Self>
     where
         Self: Sized,
     {
-        Skipped { it: self, n }
+        self.enumerated_by(start, 1)
     }
 
-    /// Creates an iterable, iterators of which skip elements based on a predicate.
+    /// Creates an iterable that both filters and maps.
     ///
-    /// `skipped_while()` takes a closure as an argument. It will call this closure on each element
-    /// of the iterator, and ignore elements until it returns false.
+    /// Iterators of the returned iterable yields only the values for which the supplied closure returns `Some(value)`.
     ///
-    /// After false is returned, `skip_while`’s job is over, and the rest of the elements are yielded.
+    /// `filter_mapped` can be used to make chains of `filtered` and `mapped` more concise.
+    /// The example below shows how a `mapped().filtered().mapped()` can be shortened to a single call
+    /// to `filter_mapped`.
     ///
     /// # Examples
     ///
     /// ```
     /// use orx_iterable::*;
     ///
-    /// let a = [-1i32, 0, 1];
+    /// let a = ["1", "two", "NaN", "four", "5"];
     ///
-    /// let it = a.skipped_while(|x| x.is_negative());
+    /// let it = a.filter_mapped(|s| s.parse::<u32>().ok());
     ///
-    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&0, &1]);
+    /// assert_eq!(it.iter().count(), 2);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), vec![1, 5]);
     /// ```
-    fn skipped_while<P>(self, skip_while: P) -> SkippedWhile<Self, P>
+    fn filter_mapped<M, U>(self, filter_map: M) -> FilterMapped<Self, M, U>
     where
         Self: Sized,
-        P: Fn(&Self::Item) -> bool + Copy,
+        M: Fn(Self::Item) -> Option<U> + Copy,
     {
-        SkippedWhile {
+        FilterMapped {
             it: self,
-            skip_while,
+            filter_map,
         }
     }
 
-    /// Creates an iterable starting at the same point, but stepping by the given amount at each iteration.
+    /// Creates an iterable which uses a closure to determine if an element should be yielded.
     ///
-    /// The first element of the iterator will always be returned, regardless of the step given.
+    /// Given an element the closure must return true or false. Iterators of the returned iterable
+    /// will yield only the elements for which the closure returns true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [0i32, 1, 2];
+    ///
+    /// let it = a.filtered(|x| x.is_positive());
+    ///
+    /// assert_eq!(it.iter().count(), 2);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2]);
+    /// ```
+    fn filtered<P>(self, filter: P) -> Filtered<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool + Copy,
+    {
+        Filtered { it: self, filter }
+    }
+
+    /// Creates an iterable that works like map, but flattens nested structure.
+    ///
+    /// You can think of `flat_mapped(f)` as the semantic equivalent of mapping,
+    /// and then flattening as in `mapped(f).flattened()`.
+    ///
+    /// Another way of thinking about `flat_mapped()`:
+    ///
+    /// * `mapped`’s closure returns one item for each element, and
+    /// * `flat_map()`’s closure returns an iterator for each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let words = ["al", "p", "ha"];
+    ///
+    /// let it = words.flat_mapped(|s| s.chars());
+    ///
+    /// assert_eq!(it.iter().count(), 5);
+    /// assert_eq!(it.iter().collect::<String>().as_str(), "alpha");
+    /// ```
+    fn flat_mapped<M, U>(self, flat_map: M) -> FlatMapped<Self, M, U>
+    where
+        Self: Sized,
+        U: IntoIterator,
+        M: Fn(Self::Item) -> U + Copy,
+    {
+        FlatMapped { it: self, flat_map }
+    }
+
+    /// Creates an iterable that maps each element to an iterable, and flattens the elements of the mapped iterables.
+    ///
+    /// This is similar to [`flat_mapped`]; however, the closure returns an [`Iterable`] rather than an `IntoIterator`,
+    /// allowing the closure to return iterable transformations such as mapped or filtered iterables.
+    ///
+    /// [`flat_mapped`]: crate::Iterable::flat_mapped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let data = vec![1, 2, 3];
+    /// let factors = [1, 10];
+    ///
+    /// let it = data.flat_mapped_iterable(|x| factors.mapped(move |f| x * f));
+    ///
+    /// assert_eq!(it.iter().count(), 6);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 10, 2, 20, 3, 30]);
+    /// ```
+    fn flat_mapped_iterable<M, U>(self, flat_map: M) -> FlatMappedIterable<Self, M, U>
+    where
+        Self: Sized,
+        U: Iterable,
+        M: Fn(Self::Item) -> U + Copy,
+    {
+        FlatMappedIterable { it: self, flat_map }
+    }
+
+    /// Creates an iterable that flattens nested structure.
+    ///
+    /// This is useful when you have an iterable of iterators or an iterable of things that can be
+    /// turned into iterators and you want to remove one level of indirection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let data = vec![vec![1, 2, 3, 4], vec![5, 6]];
+    ///
+    /// let it = data.flattened();
+    ///
+    /// assert_eq!(it.iter().count(), 6);
+    /// assert_eq!(it.iter().sum::<u32>(), 21);
+    /// ```
+    fn flattened(self) -> Flattened<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        Flattened { it: self }
+    }
+
+    /// Creates an iterable that flattens the elements of the iterables yielded by this iterable.
+    ///
+    /// This is similar to [`flattened`]; however, the elements of this iterable are required to implement
+    /// [`Iterable`] rather than `IntoIterator`, and the `iter` method of each element is called to traverse it.
+    ///
+    /// [`flattened`]: crate::Iterable::flattened
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// let multiples_of = |k: i32| data.filtered(move |x| *x % k == 0).copied();
+    ///
+    /// let pipelines = vec![multiples_of(2), multiples_of(3)];
+    /// let it = pipelines.copied().flattened_iterables();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [2, 4, 6, 3, 6]);
+    ///
+    /// let prefixes = (1..4usize).mapped(|n| data.taken(n)).flattened_iterables();
+    /// assert_eq!(prefixes.iter().collect::<Vec<_>>(), [&1, &1, &2, &1, &2, &3]);
+    /// ```
+    fn flattened_iterables(self) -> FlattenedIterables<Self>
+    where
+        Self: Sized,
+        Self::Item: Iterable,
+    {
+        FlattenedIterables { it: self }
+    }
+
+    /// Creates an iterable which ends after the first `None`.
+    ///
+    /// After an iterator returns `None`, future calls may or may not yield `Some(T)` again.
+    /// fuse() adapts an iterator, ensuring that after a `None` is given, it will always return `None` forever.
+    ///
+    /// Note that the Fuse wrapper is a no-op on iterators that implement the FusedIterator trait.
+    /// fuse() may therefore behave incorrectly if the FusedIterator trait is improperly implemented.
+    fn fused(self) -> Fused<Self>
+    where
+        Self: Sized,
+    {
+        Fused { it: self }
+    }
+
+    /// Creates an iterable which calls the `inspect` closure on a reference of each element before yielding it.
+    ///
+    /// This is useful for debugging or for side effects such as logging or counting, without changing
+    /// the elements of the iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::cell::Cell;
+    ///
+    /// let a = [1, 2, 3];
+    /// let sum = Cell::new(0);
+    ///
+    /// let it = a.copied().inspected(|x| sum.set(sum.get() + x));
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(sum.get(), 6);
+    ///
+    /// assert_eq!(it.iter().max(), Some(3));
+    /// assert_eq!(sum.get(), 12);
+    /// ```
+    fn inspected<F>(self, inspect: F) -> Inspected<Self, F>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) + Copy,
+    {
+        Inspected { it: self, inspect }
+    }
+
+    /// Creates an iterable which records the number of passes, i.e., calls to `iter`, and the number of
+    /// items pulled in each pass.
+    ///
+    /// The counts are accessible through the [`IterationStats`] handle returned by the `stats` method of
+    /// the created iterable. The handle can be obtained before the iterable is moved into further
+    /// transformations, and it observes the counts made afterwards.
+    ///
+    /// [`IterationStats`]: crate::transformations::IterationStats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    ///
+    /// let it = a.instrumented();
+    /// let stats = it.stats();
+    ///
+    /// let it = it.filtered(|x| *x % 2 == 1);
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().next(), Some(&1));
+    ///
+    /// assert_eq!(stats.passes(), 2);
+    /// assert_eq!(stats.items_per_pass(), [5, 1]);
+    /// assert_eq!(stats.total_items(), 6);
+    /// ```
+    #[cfg(feature = "std")]
+    fn instrumented(self) -> Instrumented<Self>
+    where
+        Self: Sized,
+    {
+        Instrumented {
+            it: self,
+            stats: Default::default(),
+        }
+    }
+
+    /// Takes two iterables and creates a new iterable which alternates between the elements of both.
+    ///
+    /// The created iterators yield the first element of this iterable, then the first element of `other`,
+    /// then the second element of this iterable, and so on. Once either of them is exhausted,
+    /// the remaining elements of the other one are yielded.
+    ///
+    /// See [`interleaved_shortest`] to stop as soon as either iterable is exhausted,
+    /// and [`round_robin`] to interleave more than two iterables.
+    ///
+    /// [`interleaved_shortest`]: crate::Iterable::interleaved_shortest
+    /// [`round_robin`]: crate::round_robin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3, 4];
+    /// let b = [10, 20];
+    ///
+    /// let it = a.interleaved(&b).copied();
+    ///
+    /// assert_eq!(it.iter().count(), 6);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 10, 2, 20, 3, 4]);
+    /// ```
+    fn interleaved<I>(self, other: I) -> Interleaved<Self, I>
+    where
+        Self: Sized,
+        I: Iterable<Item = Self::Item>,
+    {
+        Interleaved {
+            it1: self,
+            it2: other,
+        }
+    }
+
+    /// Takes two iterables and creates a new iterable which alternates between the elements of both,
+    /// ending as soon as the iterable whose turn it is is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3, 4];
+    /// let b = [10, 20];
+    ///
+    /// let it = a.interleaved_shortest(&b).copied();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 10, 2, 20, 3]);
+    ///
+    /// let it = b.interleaved_shortest(&a).copied();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [10, 1, 20, 2]);
+    /// ```
+    fn interleaved_shortest<I>(self, other: I) -> InterleavedShortest<Self, I>
+    where
+        Self: Sized,
+        I: Iterable<Item = Self::Item>,
+    {
+        InterleavedShortest {
+            it1: self,
+            it2: other,
+        }
+    }
+
+    /// Creates an iterable which places a clone of the `separator` between adjacent elements of this iterable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let words = ["a", "b", "c"];
+    ///
+    /// let it = words.copied().interspersed(", ");
+    ///
+    /// assert_eq!(it.iter().count(), 5);
+    /// assert_eq!(it.iter().collect::<String>(), "a, b, c");
+    /// ```
+    fn interspersed(self, separator: Self::Item) -> Interspersed<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Interspersed {
+            it: self,
+            separator,
+        }
+    }
+
+    /// Creates an iterable which places the value returned by the `separator` function between adjacent elements
+    /// of this iterable.
+    ///
+    /// The separator function is called each time a separator is yielded, which is useful when the separator
+    /// is not cloneable or expensive to clone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![vec![1, 2], vec![3]];
+    ///
+    /// let it = a.cloned().interspersed_with(|| vec![0]).flattened();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 0, 3]);
+    /// ```
+    fn interspersed_with<F>(self, separator: F) -> InterspersedWith<Self, F>
+    where
+        Self: Sized,
+        F: Fn() -> Self::Item + Copy,
+    {
+        InterspersedWith {
+            it: self,
+            separator,
+        }
+    }
+
+    /// Wraps this iterable into an [`IterableAsSeq`] which implements `PartialEq`, `Eq`, `PartialOrd`, `Ord`
+    /// and `Hash` by the sequence of elements it yields.
+    ///
+    /// This allows any iterable to be compared with another iterable of a different type,
+    /// used in assertions or used as a key of a map.
+    ///
+    /// [`IterableAsSeq`]: crate::transformations::IterableAsSeq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::collections::BTreeSet;
+    ///
+    /// let a = vec![1, 2, 3, 4];
+    /// let b = [2, 4];
+    ///
+    /// assert_eq!(a.filtered(|x| *x % 2 == 0).into_seq(), b.into_seq());
+    /// assert_ne!(a.into_seq(), b.into_seq());
+    /// assert!(a.into_seq() < b.into_seq());
+    ///
+    /// let c = vec![1, 2];
+    ///
+    /// let set = BTreeSet::from_iter([a.into_seq(), c.into_seq(), a.into_seq()]);
+    /// assert_eq!(set.len(), 2);
+    /// assert_eq!(set.first(), Some(&c.into_seq()));
+    /// ```
+    fn into_seq(self) -> IterableAsSeq<Self>
+    where
+        Self: Sized,
+    {
+        IterableAsSeq { it: self }
+    }
+
+    /// Lexicographically compares the elements of this iterable with those of `other`.
+    ///
+    /// Returns the same result as `self.iter().cmp(other.iter())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = vec![1, 2, 3];
+    /// let it = a.mapped(|x| x * 10);
+    ///
+    /// assert_eq!(it.iter_cmp([10, 20, 30].copied()), Ordering::Equal);
+    /// assert_eq!(it.iter_cmp([10, 20].copied()), Ordering::Greater);
+    /// assert_eq!(it.iter_cmp([10, 30].copied()), Ordering::Less);
+    /// ```
+    fn iter_cmp<I>(&self, other: I) -> Ordering
+    where
+        I: Iterable<Item = Self::Item>,
+        Self::Item: Ord,
+    {
+        self.iter().cmp(other.iter())
+    }
+
+    /// Determines whether the elements of this iterable are equal to those of `other`.
+    ///
+    /// Returns the same result as `self.iter().eq(other.iter())`; the iterables may be
+    /// of different types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::collections::VecDeque;
+    ///
+    /// let a = vec![1, 2, 3, 4];
+    /// let b = VecDeque::from_iter([2, 4]);
+    ///
+    /// assert!(a.filtered(|x| *x % 2 == 0).iter_eq(&b));
+    /// assert!(!a.taken(3).iter_eq(&b));
+    /// ```
+    fn iter_eq<I>(&self, other: I) -> bool
+    where
+        I: Iterable,
+        Self::Item: PartialEq<I::Item>,
+    {
+        self.iter().eq(other.iter())
+    }
+
+    /// Feeds the sequence of elements of this iterable into the given [`Hasher`].
+    ///
+    /// Each element is hashed in order, followed by the number of elements; hence, iterables
+    /// which are equal by [`iter_eq`] produce the same hash.
+    ///
+    /// [`Hasher`]: core::hash::Hasher
+    /// [`iter_eq`]: crate::Iterable::iter_eq
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// fn hash(it: impl Iterable<Item = i32>) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     it.iter_hash(&mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// let a = vec![1, 2, 3];
+    /// let b = [0, 1, 2, 3];
+    ///
+    /// assert_eq!(hash(a.copied()), hash(b.copied().skipped(1)));
+    /// assert_ne!(hash(a.copied()), hash(b.copied()));
+    /// ```
+    fn iter_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+        Self::Item: Hash,
+    {
+        let mut count = 0;
+        for x in self.iter() {
+            x.hash(state);
+            count += 1;
+        }
+        state.write_usize(count);
+    }
+
+    /// Lexicographically compares the elements of this iterable with those of `other`,
+    /// the elements of which are partially comparable to the elements of this iterable.
+    ///
+    /// Returns the same result as `self.iter().partial_cmp(other.iter())`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = vec![1.0, 2.0, 3.0];
+    /// let it = a.taken(2);
+    ///
+    /// assert_eq!(it.iter_partial_cmp(&[1.0, 2.0]), Some(Ordering::Equal));
+    /// assert_eq!(it.iter_partial_cmp(&[1.0, 2.5]), Some(Ordering::Less));
+    /// assert_eq!(it.iter_partial_cmp(&[1.0, f64::NAN]), None);
+    /// ```
+    fn iter_partial_cmp<I>(&self, other: I) -> Option<Ordering>
+    where
+        I: Iterable,
+        Self::Item: PartialOrd<I::Item>,
+    {
+        self.iter().partial_cmp(other.iter())
+    }
+
+    /// Creates an iterable that both yields elements based on a predicate and maps.
+    ///
+    /// `map_while()` takes a closure as an argument. It will call this closure on each element
+    /// of the iterator, and yield elements while it returns `Some(_)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [0, 1, 2, -3, 4, 5, -6];
+    ///
+    /// let it = a.mapped_while(|x| u32::try_from(*x).ok());
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2]);
+    /// ```
+    fn mapped_while<M, U>(self, map_while: M) -> MappedWhile<Self, M, U>
+    where
+        Self: Sized,
+        M: Fn(Self::Item) -> Option<U> + Copy,
+    {
+        MappedWhile {
+            it: self,
+            map_while,
+        }
+    }
+
+    /// Takes a closure and creates an iterable which calls that closure on each element.
+    ///
+    /// map() transforms one iterator into another, by means of its argument `map`.
+    /// It produces a new iterable, iterators of which calls this closure on each element of
+    /// the original iterable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 3, 6];
+    ///
+    /// let it = a.mapped(|x| 2 * x);
+    ///
+    /// assert_eq!(it.iter().sum::<i32>(), 20);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [2, 6, 12]);
+    /// ```
+    fn mapped<M, U>(self, map: M) -> Mapped<Self, M, U>
+    where
+        Self: Sized,
+        M: Fn(Self::Item) -> U + Copy,
+    {
+        Mapped { it: self, map }
+    }
+
+    /// Creates an iterable which yields the elements of this iterable followed by clones of the padding `value`
+    /// until at least `len` elements are yielded.
+    ///
+    /// Iterators yield all elements of this iterable even when there are more than `len` of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3];
+    ///
+    /// let it = a.copied().padded_to(5, 0);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 3, 0, 0]);
+    ///
+    /// let it = a.copied().padded_to(2, 0);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    fn padded_to(self, len: usize, value: Self::Item) -> PaddedTo<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        PaddedTo {
+            it: self,
+            len,
+            value,
+        }
+    }
+
+    /// Creates an iterable borrowing this iterable as its source and yielding its pages; each page is itself an
+    /// iterable over at most `page_size` consecutive elements of the source.
+    ///
+    /// Elements are not collected; each page skips the elements of the preceding pages and takes the next `page_size`
+    /// elements of the source. Further, the returned [`Paged`] provides [`page`] for random access to a page by its
+    /// index and [`page_count`] when the length of the source is known.
+    ///
+    /// [`Paged`]: crate::transformations::Paged
+    /// [`page`]: crate::transformations::Paged::page
+    /// [`page_count`]: crate::transformations::Paged::page_count
+    ///
+    /// # Panics
+    ///
+    /// Panics if `page_size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3, 4, 5, 6, 7];
+    /// let numbers = a.copied();
+    ///
+    /// let pages = numbers.paged(3);
+    ///
+    /// assert_eq!(pages.page_count(), 3);
+    /// assert_eq!(pages.iter().count(), 3);
+    ///
+    /// let contents: Vec<Vec<_>> = pages.iter().map(|page| page.iter().collect()).collect();
+    /// assert_eq!(contents, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    ///
+    /// let page = pages.page(1);
+    /// assert_eq!(page.offset(), 3);
+    /// assert_eq!(page.iter().sum::<i32>(), 15);
+    /// assert_eq!(pages.page(3).iter().count(), 0);
+    /// ```
+    fn paged(&self, page_size: usize) -> Paged<'_, Self>
+    where
+        Self: Sized,
+    {
+        assert!(page_size > 0, "page_size must be positive");
+        Paged {
+            it: self,
+            page_size,
+        }
+    }
+
+    /// Creates two iterables borrowing this iterable as their source: the first one yields the elements
+    /// satisfying the predicate `pred` while the second one yields the remaining elements.
+    ///
+    /// Elements are not collected; each iterator of the partitions iterates over the source and filters
+    /// its elements on the fly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![1, 2, 3, 4, 5];
+    /// let numbers = a.copied();
+    ///
+    /// let (even, odd) = numbers.partitioned(|x| *x % 2 == 0);
+    ///
+    /// assert_eq!(even.iter().collect::<Vec<_>>(), [2, 4]);
+    /// assert_eq!(odd.iter().collect::<Vec<_>>(), [1, 3, 5]);
+    /// assert_eq!(odd.iter().sum::<i32>(), 9);
+    /// ```
+    fn partitioned<P>(&self, pred: P) -> (Partition<'_, Self, P>, Partition<'_, Self, P>)
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool + Copy,
+    {
+        let satisfied = Partition {
+            it: self,
+            pred,
+            value: true,
+        };
+        let others = Partition {
+            it: self,
+            pred,
+            value: false,
+        };
+        (satisfied, others)
+    }
+
+    /// Creates an iterable which yields the cumulative sums of the elements of this iterable.
+    ///
+    /// The `i`-th element of the created iterators is the sum of the first `i + 1` elements of this iterable.
+    /// It is built on [`scanned`].
+    ///
+    /// [`scanned`]: crate::Iterable::scanned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 4, 6, 5];
+    ///
+    /// let it = a.copied().prefix_sums();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 5, 11, 16]);
+    /// assert_eq!(it.iter().last(), Some(16));
+    /// ```
+    fn prefix_sums(self) -> PrefixSums<Self>
+    where
+        Self: Sized,
+        Self::Item: Add<Output = Self::Item> + Clone,
+    {
+        self.scanned(None, scanned::prefix_sum as fn(&mut _, _) -> _)
+    }
+
+    /// Creates an iterable iterators of which reverses the traversal direction.
+    ///
+    /// This is only possible if the iterable's iterator type has an end,
+    /// so `reversed()` only works when `Iterable::Iter` is a `DoubleEndedIterator`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let it = a.reversed();
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&3, &2, &1]);
+    ///
+    /// let it = it.reversed();
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &2, &3]);
+    /// ```
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+        Self::Iter: DoubleEndedIterator,
+    {
+        Reversed { it: self }
+    }
+
+    /// Creates an iterable which yields the maximum of the elements of this iterable observed so far.
+    ///
+    /// The `i`-th element of the created iterators is the maximum of the first `i + 1` elements of this iterable.
+    /// It is built on [`scanned`].
+    ///
+    /// [`scanned`]: crate::Iterable::scanned
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 4, 2, 6, 5];
+    ///
+    /// let it = a.copied().running_max();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 4, 4, 6, 6]);
+    /// ```
+    fn running_max(self) -> RunningMax<Self>
+    where
+        Self: Sized,
+        Self::Item: Ord + Clone,
+    {
+        self.scanned(None, scanned::running_max as fn(&mut _, _) -> _)
+    }
+
+    /// Creates an iterable which scans the elements of this iterable with a state.
+    ///
+    /// This is the re-iterable counterpart of [`Iterator::scan`].
+    /// Every time the `iter` method is called, the initial state `init` is cloned; and hence,
+    /// each iteration starts with a fresh state.
+    ///
+    /// The closure `scan` receives a mutable reference to the state and the next element; it might
+    /// update the state and returns the next value to be yielded. The iteration ends as soon as
+    /// `scan` returns `None`.
+    ///
+    /// See [`prefix_sums`], [`running_max`] and [`differences`] for common transformations
+    /// built on scanning.
+    ///
+    /// [`prefix_sums`]: crate::Iterable::prefix_sums
+    /// [`running_max`]: crate::Iterable::running_max
+    /// [`differences`]: crate::Iterable::differences
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// let it = a.scanned(1, |product, x| {
+    ///     *product *= x;
+    ///     (*product < 20).then_some(-*product)
+    /// });
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [-1, -2, -6]);
+    ///
+    /// // each iteration starts with the initial state
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [-1, -2, -6]);
+    /// ```
+    fn scanned<S, F, U>(self, init: S, scan: F) -> Scanned<Self, S, F, U>
+    where
+        Self: Sized,
+        S: Clone,
+        F: Fn(&mut S, Self::Item) -> Option<U> + Copy,
+    {
+        Scanned {
+            it: self,
+            init,
+            scan,
+        }
+    }
+
+    /// Creates an iterable, iterators of which skip the first `n` elements.
+    ///
+    /// Created iterators skip elements until n elements are skipped or the end of the iterator
+    /// is reached (whichever happens first).
+    /// After that, all the remaining elements are yielded. In particular, if the original iterator
+    /// is too short, then the returned iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let it = a.skipped(2);
+    ///
+    /// assert_eq!(it.iter().count(), 1);
+    /// assert_eq!(it.iter().next(), Some(&3));
+    /// ```
+    fn skipped(self, n: usize) -> Skipped<Self>
+    where
+        Self: Sized,
+    {
+        Skipped { it: self, n }
+    }
+
+    /// Creates an iterable, iterators of which skip elements based on a predicate.
+    ///
+    /// `skipped_while()` takes a closure as an argument. It will call this closure on each element
+    /// of the iterator, and ignore elements until it returns false.
+    ///
+    /// After false is returned, `skip_while`’s job is over, and the rest of the elements are yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [-1i32, 0, 1];
+    ///
+    /// let it = a.skipped_while(|x| x.is_negative());
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&0, &1]);
+    /// ```
+    fn skipped_while<P>(self, skip_while: P) -> SkippedWhile<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Item) -> bool + Copy,
+    {
+        SkippedWhile {
+            it: self,
+            skip_while,
+        }
+    }
+
+    /// Creates an iterable starting at the same point, but stepping by the given amount at each iteration.
+    ///
+    /// The first element of the iterator will always be returned, regardless of the step given.
     ///
     /// # Examples
     ///
@@ -624,6 +1667,131 @@ pub trait Iterable {
         }
     }
 
+    /// Creates an iterable which yields only the first occurrence of every distinct element of this iterable.
+    ///
+    /// Every time the `iter` method is called, a new hash set of visited elements is created;
+    /// and hence, each iteration yields all distinct elements.
+    ///
+    /// See [`deduped`] for a non-allocating alternative which skips only consecutive duplicates.
+    ///
+    /// [`deduped`]: crate::Iterable::deduped
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [3, 1, 3, 2, 1, 4];
+    ///
+    /// let it = a.unique();
+    ///
+    /// assert_eq!(it.iter().count(), 4);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&3, &1, &2, &4]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn unique(self) -> Unique<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash + Clone,
+    {
+        self.unique_by_key(<Self::Item as Clone>::clone as fn(&_) -> _)
+    }
+
+    /// Creates an iterable which yields only the first element of every distinct key computed by
+    /// the `key` function.
+    ///
+    /// Every time the `iter` method is called, a new hash set of visited keys is created;
+    /// and hence, each iteration yields the first elements of all distinct keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = ["apple", "avocado", "banana", "blueberry", "cherry"];
+    ///
+    /// let it = a.unique_by_key(|x| x.chars().next());
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [&"apple", &"banana", &"cherry"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn unique_by_key<F, K>(self, key: F) -> UniqueByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: Fn(&Self::Item) -> K + Copy,
+        K: Eq + core::hash::Hash,
+    {
+        UniqueByKey { it: self, key }
+    }
+
+    /// Creates two iterables borrowing this iterable of pairs as their source: the first one yields the
+    /// left elements and the second one yields the right elements of the pairs.
+    ///
+    /// Elements are not collected; each iterator of the unzipped iterables iterates over the source and
+    /// maps its elements on the fly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = vec![(1, 'a'), (2, 'b'), (3, 'c')];
+    /// let pairs = a.copied();
+    ///
+    /// let (numbers, chars) = pairs.unzipped();
+    ///
+    /// assert_eq!(numbers.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(numbers.iter().sum::<i32>(), 6);
+    /// assert_eq!(chars.iter().collect::<String>(), "abc");
+    /// ```
+    fn unzipped(&self) -> (UnzippedLeft<'_, Self>, UnzippedRight<'_, Self>)
+    where
+        Self: Sized,
+        Self::Item: Pair,
+    {
+        (UnzippedLeft { it: self }, UnzippedRight { it: self })
+    }
+
+    /// Creates an iterable which yields the elements together with their [`Position`]; i.e., whether the element is the
+    /// first, a middle, the last or the only element of the sequence.
+    ///
+    /// The created iterators look one element ahead in order to detect the last element.
+    ///
+    /// [`Position`]: crate::transformations::Position
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use orx_iterable::transformations::Position;
+    ///
+    /// let a = vec!["x", "y", "z"];
+    ///
+    /// let it = a.copied().with_position();
+    /// assert_eq!(
+    ///     it.iter().collect::<Vec<_>>(),
+    ///     [(Position::First, "x"), (Position::Middle, "y"), (Position::Last, "z")]
+    /// );
+    ///
+    /// let row: String = it
+    ///     .iter()
+    ///     .map(|(p, x)| match p.is_last() {
+    ///         true => format!("{}", x),
+    ///         false => format!("{} | ", x),
+    ///     })
+    ///     .collect();
+    /// assert_eq!(row, "x | y | z");
+    ///
+    /// let it = a.taken(1).with_position();
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [(Position::Only, &"x")]);
+    /// ```
+    fn with_position(self) -> WithPosition<Self>
+    where
+        Self: Sized,
+    {
+        WithPosition { it: self }
+    }
+
     /// ‘Zips up’ two iterables into a single iterable of pairs.
     ///
     /// The zipped iterable creates zipped iterators.
@@ -656,6 +1824,113 @@ pub trait Iterable {
             it2: other,
         }
     }
+    /// ‘Zips up’ two iterables into a single iterable of pairs, which continues until both iterables are exhausted.
+    ///
+    /// Unlike [`zipped`] which stops at the end of the shorter iterable, iterators of the created iterable
+    /// yield [`EitherOrBoth`] values:
+    ///
+    /// * `Both(a, b)` while both iterables have elements,
+    /// * `Left(a)` or `Right(b)` for the remaining elements of the longer iterable.
+    ///
+    /// [`zipped`]: crate::Iterable::zipped
+    /// [`EitherOrBoth`]: crate::transformations::EitherOrBoth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use orx_iterable::transformations::EitherOrBoth;
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = ['x'];
+    ///
+    /// let it = a.copied().zipped_longest(b.copied());
+    ///
+    /// assert_eq!(it.iter().count(), 3);
+    /// assert_eq!(
+    ///     it.iter().collect::<Vec<_>>(),
+    ///     [
+    ///         EitherOrBoth::Both(1, 'x'),
+    ///         EitherOrBoth::Left(2),
+    ///         EitherOrBoth::Left(3)
+    ///     ]
+    /// );
+    /// ```
+    fn zipped_longest<I>(self, other: I) -> ZippedLongest<Self, I>
+    where
+        Self: Sized,
+        I: Iterable,
+    {
+        ZippedLongest {
+            it1: self,
+            it2: other,
+        }
+    }
+
+    /// ‘Zips up’ two iterables which are expected to have the same length into a single iterable of pairs.
+    ///
+    /// Iterators of the created iterable yield `Ok((a, b))` pairs while both iterables have elements.
+    /// If one of the iterables ends before the other, a single `Err(LengthMismatch)` is yielded
+    /// and the iteration ends; i.e., a length mismatch is never silently ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    /// use orx_iterable::transformations::LengthMismatch;
+    ///
+    /// let a = [1, 2, 3];
+    /// let b = ['x', 'y', 'z'];
+    ///
+    /// let it = a.copied().zipped_exact(b.copied());
+    /// let pairs: Result<Vec<_>, _> = it.iter().collect();
+    /// assert_eq!(pairs, Ok(vec![(1, 'x'), (2, 'y'), (3, 'z')]));
+    ///
+    /// let it = a.copied().zipped_exact(b.taken(2).copied());
+    /// let pairs: Result<Vec<_>, _> = it.iter().collect();
+    /// assert_eq!(pairs, Err(LengthMismatch::FirstIsLonger(2)));
+    /// ```
+    fn zipped_exact<I>(self, other: I) -> ZippedExact<Self, I>
+    where
+        Self: Sized,
+        I: Iterable,
+    {
+        ZippedExact {
+            it1: self,
+            it2: other,
+        }
+    }
+
+    /// ‘Zips up’ two iterables and maps each pair of elements into a new value with the `map` function.
+    ///
+    /// It is equivalent to `self.zipped(other).mapped(|(a, b)| map(a, b))`; however, avoids the
+    /// intermediate tuple.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let prices = [10, 20, 5];
+    /// let quantities = [2, 1, 4, 100];
+    ///
+    /// let it = prices.zipped_with(&quantities, |p, q| p * q);
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [20, 20, 20]);
+    /// assert_eq!(it.iter().sum::<i32>(), 60);
+    /// ```
+    fn zipped_with<I, M, U>(self, other: I, map: M) -> ZippedWith<Self, I, M, U>
+    where
+        Self: Sized,
+        I: Iterable,
+        M: Fn(Self::Item, I::Item) -> U + Copy,
+    {
+        ZippedWith {
+            it1: self,
+            it2: other,
+            map,
+        }
+    }
 }
 
 // impl
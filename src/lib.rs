@@ -17,8 +17,12 @@ extern crate std;
 
 mod collection;
 mod collection_mut;
+mod debug_fn;
+mod fallible_iterable;
 mod iterable;
+mod macros;
 mod producing_iterables;
+mod try_iterable;
 /// Module defining types implementing iterable traits behaving as source of iterables.
 pub mod sources;
 /// Module defining transformations among iterables.
@@ -28,8 +32,23 @@ pub mod transformations;
 #[cfg(feature = "std")]
 pub mod obj_safe;
 
+/// Parallel processing of index-addressable collections using scoped threads.
+#[cfg(feature = "std")]
+pub mod parallel;
+
 pub use collection::Collection;
 pub use collection_mut::CollectionMut;
+pub use fallible_iterable::FallibleIterable;
 pub use iterable::Iterable;
-pub use sources::{empty, empty_col, once, once_col, repeat, repeat_n};
+#[cfg(feature = "std")]
+pub use sources::{bfs, descendants, dfs_postorder, dfs_preorder, file_chunks, file_lines};
+pub use sources::{
+    empty, empty_col, fallible_source, once, once_col, repeat, repeat_n, AsGuardedIterable,
+};
+#[cfg(feature = "std")]
+pub use transformations::round_robin;
 pub use transformations::IntoCloningIterable;
+#[cfg(feature = "std")]
+pub use transformations::{DerefCol, IntoDerefCol};
+pub use transformations::{zip3, zip4, zip5, zip6, zip_array};
+pub use try_iterable::TryIterable;
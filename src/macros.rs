@@ -0,0 +1,205 @@
+/// Implements `Collection`, and optionally `CollectionMut`, for a wrapper type by delegating to one of its fields.
+///
+/// The macro generates the `IntoIterator` implementations for the type, its shared reference and, when
+/// the `mut` keyword is provided, its mutable reference; by delegating to the corresponding implementations
+/// of the field. The collection traits, as well as their object safe variants, are then automatically
+/// implemented through the blanket implementations.
+///
+/// The syntax is as follows:
+///
+/// ```ignore
+/// impl_collection_via_field!($(mut)? [$generics] $type, $field: $field_type $(where $bounds)?);
+/// ```
+///
+/// * `mut` is optional; when provided, `CollectionMut` is also implemented.
+/// * `[$generics]` contains the generic parameters of the implementation, such as `[T]` or `[T, const N: usize]`;
+///   it is `[]` for non-generic types.
+/// * `$field` is the name of the field, or its index for tuple structs, to delegate to.
+/// * `$field_type` is the type of the field, which must itself be a collection following the `IntoIterator`
+///   pattern, such as `Vec<T>`, `[T; N]` or `VecDeque<T>`.
+/// * the optional `where` clause is appended to all generated implementations.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// pub struct Stack<T> {
+///     items: Vec<T>,
+/// }
+///
+/// impl_collection_via_field!(mut [T] Stack<T>, items: Vec<T>);
+///
+/// pub struct Ring<T, const N: usize>([T; N]);
+///
+/// impl_collection_via_field!([T, const N: usize] Ring<T, N>, 0: [T; N]);
+///
+/// pub struct Numbers {
+///     values: Vec<i32>,
+/// }
+///
+/// impl_collection_via_field!([] Numbers, values: Vec<i32>);
+///
+/// fn sum(numbers: &impl Collection<Item = i32>) -> i32 {
+///     numbers.iter().sum()
+/// }
+///
+/// let mut stack = Stack { items: vec![1, 2, 3] };
+/// for x in stack.iter_mut() {
+///     *x *= 10;
+/// }
+/// assert_eq!(sum(&stack), 60);
+/// assert_eq!(stack.into_filtered(|x| *x > 10).iter().count(), 2);
+///
+/// assert_eq!(sum(&Ring([4, 5, 6])), 15);
+/// assert_eq!(sum(&Numbers { values: vec![7, 8] }), 15);
+/// ```
+#[macro_export]
+macro_rules! impl_collection_via_field {
+    (mut [$($gen:tt)*] $ty:ty, $field:tt : $field_ty:ty $(where $($bound:tt)*)?) => {
+        $crate::impl_collection_via_field!([$($gen)*] $ty, $field: $field_ty $(where $($bound)*)?);
+
+        impl<'__i, $($gen)*> ::core::iter::IntoIterator for &'__i mut $ty
+        where
+            $($($bound)*)?
+        {
+            type Item = <&'__i mut $field_ty as ::core::iter::IntoIterator>::Item;
+
+            type IntoIter = <&'__i mut $field_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&mut self.$field)
+            }
+        }
+    };
+
+    ([$($gen:tt)*] $ty:ty, $field:tt : $field_ty:ty $(where $($bound:tt)*)?) => {
+        impl<$($gen)*> ::core::iter::IntoIterator for $ty
+        where
+            $($($bound)*)?
+        {
+            type Item = <$field_ty as ::core::iter::IntoIterator>::Item;
+
+            type IntoIter = <$field_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.$field)
+            }
+        }
+
+        impl<'__i, $($gen)*> ::core::iter::IntoIterator for &'__i $ty
+        where
+            $($($bound)*)?
+        {
+            type Item = <&'__i $field_ty as ::core::iter::IntoIterator>::Item;
+
+            type IntoIter = <&'__i $field_ty as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(&self.$field)
+            }
+        }
+    };
+}
+
+/// Implements `Iterable` for references of a type by delegating to accessor methods returning references
+/// to a collection.
+///
+/// The macro generates the `IntoIterator` implementation for the shared reference of the type and, when the
+/// `mut` keyword is provided, for its mutable reference. Therefore, `&X` implements `Iterable` and `IterableObj`,
+/// and the `for` loop can be used on `&X` and `&mut X`.
+///
+/// Since the owned type cannot be converted into an iterator through accessors, the type does not
+/// automatically become a `Collection`; [`impl_collection_via_field`] can be used when the collection is a field
+/// of the type.
+///
+/// The syntax is as follows:
+///
+/// ```ignore
+/// impl_iterable_via!([$generics] $type, $accessor() -> $target $(where $bounds)?);
+/// impl_iterable_via!(mut [$generics] $type, $accessor() -> $target, $accessor_mut() $(where $bounds)?);
+/// ```
+///
+/// * `[$generics]` contains the generic parameters of the implementation, such as `[T]` or `[T, const N: usize]`;
+///   it is `[]` for non-generic types.
+/// * `$accessor` is a method of the type with signature `fn(&self) -> &$target`.
+/// * `$accessor_mut` is a method of the type with signature `fn(&mut self) -> &mut $target`.
+/// * the optional `where` clause is appended to all generated implementations.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// pub struct Sorted<T> {
+///     data: Vec<T>,
+/// }
+///
+/// impl<T> Sorted<T> {
+///     fn as_slice(&self) -> &[T] {
+///         &self.data
+///     }
+/// }
+///
+/// impl_iterable_via!([T] Sorted<T>, as_slice() -> [T]);
+///
+/// pub struct Grid<const N: usize> {
+///     cells: [u8; N],
+/// }
+///
+/// impl<const N: usize> Grid<N> {
+///     fn cells(&self) -> &[u8; N] {
+///         &self.cells
+///     }
+///
+///     fn cells_mut(&mut self) -> &mut [u8; N] {
+///         &mut self.cells
+///     }
+/// }
+///
+/// impl_iterable_via!(mut [const N: usize] Grid<N>, cells() -> [u8; N], cells_mut());
+///
+/// let sorted = Sorted { data: vec![1, 3, 7] };
+/// let it = (&sorted).mapped(|x| x * 2);
+/// assert_eq!(it.iter().sum::<i32>(), 22);
+///
+/// let mut grid = Grid { cells: [1, 2, 3] };
+/// for x in &mut grid {
+///     *x += 1;
+/// }
+/// assert_eq!((&grid).iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+/// ```
+#[macro_export]
+macro_rules! impl_iterable_via {
+    (mut [$($gen:tt)*] $ty:ty, $accessor:ident() -> $target:ty, $accessor_mut:ident() $(where $($bound:tt)*)?) => {
+        $crate::impl_iterable_via!([$($gen)*] $ty, $accessor() -> $target $(where $($bound)*)?);
+
+        impl<'__i, $($gen)*> ::core::iter::IntoIterator for &'__i mut $ty
+        where
+            $($($bound)*)?
+        {
+            type Item = <&'__i mut $target as ::core::iter::IntoIterator>::Item;
+
+            type IntoIter = <&'__i mut $target as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.$accessor_mut())
+            }
+        }
+    };
+
+    ([$($gen:tt)*] $ty:ty, $accessor:ident() -> $target:ty $(where $($bound:tt)*)?) => {
+        impl<'__i, $($gen)*> ::core::iter::IntoIterator for &'__i $ty
+        where
+            $($($bound)*)?
+        {
+            type Item = <&'__i $target as ::core::iter::IntoIterator>::Item;
+
+            type IntoIter = <&'__i $target as ::core::iter::IntoIterator>::IntoIter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::core::iter::IntoIterator::into_iter(self.$accessor())
+            }
+        }
+    };
+}
@@ -0,0 +1,63 @@
+use crate::FallibleIterable;
+use std::boxed::Box;
+
+/// A `FallibleIterableObj` is any type which can attempt to create a new boxed iterator that yields
+/// elements of the associated type [`Item`] every time [`try_boxed_iter`] method is called.
+///
+/// It is the object safe counterpart of [`FallibleIterable`] trait which can conveniently be made into a trait object.
+///
+/// Instead of `try_iter`, it implements `try_boxed_iter` which returns the same iterator in a box.
+///
+/// Note that `FallibleIterableObj` is automatically implemented for all fallible iterables, including
+/// their transformations.
+///
+/// In order to use object safe iterables and collections please add `--features std` and use
+/// `use orx_iterable::{*, obj_safe::*}` to import dependencies rather than `use orx_iterable::*`.
+///
+/// [`Item`]: crate::obj_safe::FallibleIterableObj::Item
+/// [`try_boxed_iter`]: crate::obj_safe::FallibleIterableObj::try_boxed_iter
+/// [`FallibleIterable`]: crate::FallibleIterable
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::{*, obj_safe::*};
+///
+/// fn total(numbers: &dyn FallibleIterableObj<Item = u32, Error = String>) -> Result<u32, String> {
+///     numbers.try_boxed_iter().map(|x| x.sum())
+/// }
+///
+/// let source = fallible_source(|| Ok::<_, String>(["1", "two", "3"]));
+/// assert_eq!(total(&source.filter_mapped(|x| x.parse().ok())), Ok(4));
+///
+/// let source = fallible_source(|| Err::<Vec<u32>, _>(String::from("unavailable")));
+/// assert_eq!(total(&source.enumerated().mapped(|(i, x)| i as u32 + x)), Err(String::from("unavailable")));
+/// ```
+pub trait FallibleIterableObj {
+    /// Type of the item that the iterators created by the [`try_boxed_iter`] method yields.
+    ///
+    /// [`try_boxed_iter`]: crate::obj_safe::FallibleIterableObj::try_boxed_iter
+    type Item;
+
+    /// Type of the error returned when the iterator cannot be created.
+    type Error;
+
+    /// Attempts to create a new iterator in a box from this iterable yielding elements of type
+    /// `FallibleIterableObj::Item`; returns the error if the iterator cannot be created.
+    #[allow(clippy::type_complexity)]
+    fn try_boxed_iter(&self) -> Result<Box<dyn Iterator<Item = Self::Item> + '_>, Self::Error>;
+}
+
+impl<X> FallibleIterableObj for X
+where
+    X: FallibleIterable,
+{
+    type Item = X::Item;
+
+    type Error = X::Error;
+
+    fn try_boxed_iter(&self) -> Result<Box<dyn Iterator<Item = Self::Item> + '_>, Self::Error> {
+        self.try_iter()
+            .map(|x| Box::new(x) as Box<dyn Iterator<Item = Self::Item>>)
+    }
+}
@@ -1,5 +1,6 @@
 mod collection_mut_obj;
 mod collection_obj;
+mod fallible_iterable_obj;
 mod iterable_obj;
 mod producing_iterables;
 mod sources;
@@ -7,4 +8,5 @@ mod transformations;
 
 pub use collection_mut_obj::CollectionMutObj;
 pub use collection_obj::CollectionObj;
+pub use fallible_iterable_obj::FallibleIterableObj;
 pub use iterable_obj::IterableObj;
@@ -0,0 +1,16 @@
+use crate::{obj_safe::IterableObj, sources::Bfs, Iterable};
+use core::hash::Hash;
+use std::boxed::Box;
+
+impl<T, F, N> IterableObj for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
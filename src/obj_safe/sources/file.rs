@@ -0,0 +1,22 @@
+use crate::{
+    obj_safe::IterableObj,
+    sources::{FileChunks, FileLines},
+    Iterable,
+};
+use std::{boxed::Box, io, string::String, vec::Vec};
+
+impl IterableObj for FileLines {
+    type Item = io::Result<String>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl IterableObj for FileChunks {
+    type Item = io::Result<Vec<u8>>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
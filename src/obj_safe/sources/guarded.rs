@@ -0,0 +1,43 @@
+use crate::{
+    obj_safe::IterableObj,
+    sources::{MutexIterable, RefCellIterable, RwLockIterable},
+    Collection, Iterable,
+};
+use core::ops::Index;
+use std::boxed::Box;
+
+impl<C> IterableObj for RefCellIterable<'_, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<C> IterableObj for MutexIterable<'_, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<C> IterableObj for RwLockIterable<'_, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -1,4 +1,8 @@
+mod bfs;
+mod dfs;
 mod empty;
+mod file;
+mod guarded;
 mod once;
 mod repeat;
 mod repeat_n;
@@ -0,0 +1,37 @@
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::Cached,
+    Collection, CollectionMut, Iterable,
+};
+use std::boxed::Box;
+
+impl<'a, I> IterableObj for &'a Cached<I>
+where
+    I: Iterable,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.materialize().iter())
+    }
+}
+
+impl<I> CollectionObj for Cached<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I> CollectionMutObj for Cached<I>
+where
+    I: Iterable,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(self.iter_mut())
+    }
+}
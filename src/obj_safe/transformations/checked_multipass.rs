@@ -0,0 +1,20 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{CheckedMultipass, MultipassMismatch},
+    Iterable,
+};
+use core::hash::Hash;
+use std::boxed::Box;
+
+impl<I, F> IterableObj for CheckedMultipass<I, F>
+where
+    I: Iterable,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
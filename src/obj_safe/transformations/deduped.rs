@@ -0,0 +1,58 @@
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::{DedupedBy, DedupedByCol, DedupedByIter},
+    Collection, CollectionMut, Iterable,
+};
+use orx_self_or::SoM;
+use std::boxed::Box;
+
+impl<I, E> IterableObj for DedupedBy<I, E>
+where
+    I: Iterable,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(DedupedByIter::new(self.it.iter(), self.eq))
+    }
+}
+
+// col
+
+impl<'a, I, E, Q> IterableObj for &'a DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(DedupedByIter::new(self.it.get_ref().iter(), self.eq))
+    }
+}
+
+impl<I, E, Q> CollectionObj for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E, Q> CollectionMutObj for DedupedByCol<I, E, Q>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(DedupedByIter::new(self.it.get_mut().iter_mut(), self.eq))
+    }
+}
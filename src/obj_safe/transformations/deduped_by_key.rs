@@ -0,0 +1,65 @@
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::{DedupedByKey, DedupedByKeyCol, DedupedByKeyIter},
+    Collection, CollectionMut, Iterable,
+};
+use orx_self_or::SoM;
+use std::boxed::Box;
+
+impl<I, F, K> IterableObj for DedupedByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(DedupedByKeyIter::new(self.it.iter(), self.key))
+    }
+}
+
+// col
+
+impl<'a, I, E, F, K> IterableObj for &'a DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(DedupedByKeyIter::new(self.it.get_ref().iter(), self.key))
+    }
+}
+
+impl<I, E, F, K> CollectionObj for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E, F, K> CollectionMutObj for DedupedByKeyCol<I, E, F, K>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(DedupedByKeyIter::new(
+            self.it.get_mut().iter_mut(),
+            self.key,
+        ))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::DerefCol,
+    Collection, CollectionMut, Iterable,
+};
+use core::ops::{Deref, DerefMut};
+use std::boxed::Box;
+
+impl<'a, P> IterableObj for &'a DerefCol<P>
+where
+    P: Deref,
+    &'a P::Target: IntoIterator,
+{
+    type Item = <&'a P::Target as IntoIterator>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<P> CollectionObj for DerefCol<P>
+where
+    P: Deref,
+    Self: Collection,
+{
+    type Item = <Self as Collection>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(Collection::iter(self))
+    }
+}
+
+impl<P> CollectionMutObj for DerefCol<P>
+where
+    P: DerefMut,
+    Self: CollectionMut,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(CollectionMut::iter_mut(self))
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{obj_safe::IterableObj, transformations::Enumerated, Iterable};
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{Enumerated, EnumeratedFrom},
+    Iterable,
+};
 use std::boxed::Box;
 
 impl<I> IterableObj for Enumerated<I>
@@ -11,3 +15,14 @@ where
         Box::new(self.it.iter().enumerate())
     }
 }
+
+impl<I> IterableObj for EnumeratedFrom<I>
+where
+    I: Iterable,
+{
+    type Item = (usize, I::Item);
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
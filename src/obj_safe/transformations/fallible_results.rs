@@ -0,0 +1,13 @@
+use crate::{obj_safe::IterableObj, transformations::FallibleResults, FallibleIterable, Iterable};
+use std::boxed::Box;
+
+impl<I> IterableObj for FallibleResults<I>
+where
+    I: FallibleIterable,
+{
+    type Item = Result<I::Item, I::Error>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{obj_safe::IterableObj, transformations::FlatMappedIterable, Iterable};
+use std::boxed::Box;
+
+impl<I, M, U> IterableObj for FlatMappedIterable<I, M, U>
+where
+    I: Iterable,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    type Item = U::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,59 @@
+use orx_self_or::SoM;
+
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::{FlattenedIterables, FlattenedIterablesCol},
+    Collection, CollectionMut, Iterable,
+};
+use std::boxed::Box;
+
+impl<I> IterableObj for FlattenedIterables<I>
+where
+    I: Iterable,
+    I::Item: Iterable,
+{
+    type Item = <I::Item as Iterable>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'a, I, E> IterableObj for &'a FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a <I::Item as Collection>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E> CollectionObj for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I>,
+{
+    type Item = <I::Item as Collection>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E> CollectionMutObj for FlattenedIterablesCol<I, E>
+where
+    I: CollectionMut,
+    I::Item: CollectionMut,
+    E: SoM<I>,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(self.iter_mut())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{GroupedByKey, KeyGroup},
+    Collection, Iterable,
+};
+use core::hash::Hash;
+use std::boxed::Box;
+
+impl<'a, I, K> IterableObj for &'a GroupedByKey<'_, I, K>
+where
+    I: Collection,
+    K: Eq + Hash,
+{
+    type Item = (&'a K, KeyGroup<'a, I>);
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// group
+
+impl<'i, I> IterableObj for &'i KeyGroup<'_, I>
+where
+    I: Collection,
+{
+    type Item = &'i I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I> CollectionObj for KeyGroup<'_, I>
+where
+    I: Collection,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{ConsecutiveGroup, GroupedConsecutiveBy},
+    Collection, Iterable,
+};
+use std::boxed::Box;
+
+impl<'a, I, K, F> IterableObj for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    type Item = (K, ConsecutiveGroup<'a, I>);
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// group
+
+impl<'i, I> IterableObj for &'i ConsecutiveGroup<'_, I>
+where
+    I: Collection + AsRef<[<I as Collection>::Item]>,
+{
+    type Item = &'i I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I> CollectionObj for ConsecutiveGroup<'_, I>
+where
+    I: Collection + AsRef<[<I as Collection>::Item]>,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
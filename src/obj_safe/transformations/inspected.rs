@@ -0,0 +1,14 @@
+use crate::{obj_safe::IterableObj, transformations::Inspected, Iterable};
+use std::boxed::Box;
+
+impl<I, F> IterableObj for Inspected<I, F>
+where
+    I: Iterable,
+    F: Fn(&I::Item) + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    obj_safe::{CollectionMutObj, CollectionObj, IterableObj},
+    transformations::{Instrumented, InstrumentedCol},
+    Collection, CollectionMut, Iterable,
+};
+use orx_self_or::SoM;
+use std::boxed::Box;
+
+impl<I> IterableObj for Instrumented<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'a, I, E> IterableObj for &'a InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(Iterable::iter(self))
+    }
+}
+
+impl<I, E> CollectionObj for InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E> CollectionMutObj for InstrumentedCol<I, E>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+{
+    fn boxed_iter_mut(&mut self) -> Box<dyn Iterator<Item = &mut Self::Item> + '_> {
+        Box::new(self.iter_mut())
+    }
+}
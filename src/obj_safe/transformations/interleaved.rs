@@ -0,0 +1,30 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{Interleaved, InterleavedShortest},
+    Iterable,
+};
+use std::boxed::Box;
+
+impl<I1, I2> IterableObj for Interleaved<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I1, I2> IterableObj for InterleavedShortest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
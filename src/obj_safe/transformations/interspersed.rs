@@ -0,0 +1,58 @@
+use orx_self_or::SoM;
+
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{Interspersed, InterspersedCol, InterspersedWith},
+    Collection, Iterable,
+};
+use std::boxed::Box;
+
+impl<I> IterableObj for Interspersed<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, F> IterableObj for InterspersedWith<I, F>
+where
+    I: Iterable,
+    F: Fn() -> I::Item + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'a, I, E> IterableObj for &'a InterspersedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E> CollectionObj for InterspersedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
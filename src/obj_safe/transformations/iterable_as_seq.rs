@@ -0,0 +1,13 @@
+use crate::{obj_safe::IterableObj, transformations::IterableAsSeq, Iterable};
+use std::boxed::Box;
+
+impl<I> IterableObj for IterableAsSeq<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.it.iter())
+    }
+}
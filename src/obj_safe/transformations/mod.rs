@@ -1,19 +1,52 @@
+mod cached;
 mod chained;
+mod circular_windows;
+mod checked_multipass;
 mod cloned;
 mod cloning_iterable;
 mod copied;
+mod deduped;
+mod deduped_by_key;
+mod deref_col;
 mod enumerated;
+mod errors;
+mod fallible_results;
 mod filter_mapped;
 mod filtered;
 mod flat_mapped;
+mod flat_mapped_iterable;
 mod flattened;
+mod flattened_iterables;
 mod fused;
+mod grouped_by_key;
+mod grouped_consecutive_by;
+mod inspected;
+mod instrumented;
+mod interleaved;
+mod interspersed;
+mod iterable_as_seq;
 mod mapped;
 mod mapped_while;
+mod multi_zipped;
+mod ok_values;
+mod padded_to;
+mod paged;
+mod panicking;
+mod partitioned;
 mod reversed;
+mod round_robin;
+mod scanned;
 mod skipped;
 mod skipped_while;
 mod stepped_by;
 mod taken;
 mod taken_while;
+mod try_filtered;
+mod try_mapped;
+mod unique;
+mod unzipped;
+mod with_position;
 mod zipped;
+mod zipped_exact;
+mod zipped_longest;
+mod zipped_with;
@@ -0,0 +1,37 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{MultiZipped, ZippedArray},
+    Iterable,
+};
+use std::boxed::Box;
+
+macro_rules! impl_multi_zipped_obj {
+    ($($I:ident),+) => {
+        impl<$($I),+> IterableObj for MultiZipped<($($I,)+)>
+        where
+            $($I: Iterable),+
+        {
+            type Item = ($($I::Item,)+);
+
+            fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+                Box::new(self.iter())
+            }
+        }
+    };
+}
+
+impl_multi_zipped_obj!(I1, I2, I3);
+impl_multi_zipped_obj!(I1, I2, I3, I4);
+impl_multi_zipped_obj!(I1, I2, I3, I4, I5);
+impl_multi_zipped_obj!(I1, I2, I3, I4, I5, I6);
+
+impl<I, const N: usize> IterableObj for ZippedArray<I, N>
+where
+    I: Iterable,
+{
+    type Item = [I::Item; N];
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{obj_safe::IterableObj, transformations::OkValues, Iterable, TryIterable};
+use std::boxed::Box;
+
+impl<I> IterableObj for OkValues<I>
+where
+    I: TryIterable,
+{
+    type Item = I::Value;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,46 @@
+use orx_self_or::SoM;
+
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{PaddedTo, PaddedToCol},
+    Collection, Iterable,
+};
+use std::boxed::Box;
+
+impl<I> IterableObj for PaddedTo<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'a, I, E> IterableObj for &'a PaddedToCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I, E> CollectionObj for PaddedToCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
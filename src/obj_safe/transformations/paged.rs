@@ -0,0 +1,28 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{Page, Paged},
+    Iterable,
+};
+use std::boxed::Box;
+
+impl<'a, I> IterableObj for Paged<'a, I>
+where
+    I: Iterable,
+{
+    type Item = Page<'a, I>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I> IterableObj for Page<'_, I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{obj_safe::IterableObj, transformations::Panicking, FallibleIterable, Iterable};
+use core::fmt::Debug;
+use std::boxed::Box;
+
+impl<I> IterableObj for Panicking<I>
+where
+    I: FallibleIterable,
+    I::Error: Debug,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
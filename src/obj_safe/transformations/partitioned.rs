@@ -0,0 +1,44 @@
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{Partition, PartitionCol},
+    Collection, Iterable,
+};
+use std::boxed::Box;
+
+impl<I, P> IterableObj for Partition<'_, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'i, C, P> IterableObj for &'i PartitionCol<'_, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    type Item = &'i C::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<C, P> CollectionObj for PartitionCol<'_, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    type Item = C::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
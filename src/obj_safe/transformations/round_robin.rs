@@ -0,0 +1,14 @@
+use crate::{obj_safe::IterableObj, transformations::RoundRobin, Collection, Iterable};
+use std::boxed::Box;
+
+impl<C> IterableObj for RoundRobin<C>
+where
+    C: Collection,
+    C::Item: Iterable,
+{
+    type Item = <C::Item as Iterable>::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
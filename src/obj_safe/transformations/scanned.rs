@@ -0,0 +1,15 @@
+use crate::{obj_safe::IterableObj, transformations::Scanned, Iterable};
+use std::boxed::Box;
+
+impl<I, S, F, U> IterableObj for Scanned<I, S, F, U>
+where
+    I: Iterable,
+    S: Clone,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+    type Item = U;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.it.iter().scan(self.init.clone(), self.scan))
+    }
+}
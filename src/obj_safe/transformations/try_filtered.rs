@@ -0,0 +1,14 @@
+use crate::{obj_safe::IterableObj, transformations::TryFiltered, Iterable, TryIterable};
+use std::boxed::Box;
+
+impl<I, P> IterableObj for TryFiltered<I, P>
+where
+    I: TryIterable,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+    type Item = Result<I::Value, I::Error>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::{obj_safe::IterableObj, transformations::TryMapped, Iterable, TryIterable};
+use std::boxed::Box;
+
+impl<I, M, U> IterableObj for TryMapped<I, M, U>
+where
+    I: TryIterable,
+    M: Fn(I::Value) -> U + Copy,
+{
+    type Item = Result<U, I::Error>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
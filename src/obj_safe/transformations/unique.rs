@@ -0,0 +1,16 @@
+use crate::{obj_safe::IterableObj, transformations::UniqueByKey, Iterable};
+use core::hash::Hash;
+use std::boxed::Box;
+
+impl<I, F, K> IterableObj for UniqueByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
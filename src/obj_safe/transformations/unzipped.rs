@@ -0,0 +1,80 @@
+use crate::{
+    obj_safe::{CollectionObj, IterableObj},
+    transformations::{Pair, UnzippedLeft, UnzippedLeftCol, UnzippedRight, UnzippedRightCol},
+    Collection, Iterable,
+};
+use std::boxed::Box;
+
+impl<I> IterableObj for UnzippedLeft<'_, I>
+where
+    I: Iterable,
+    I::Item: Pair,
+{
+    type Item = <I::Item as Pair>::Left;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<I> IterableObj for UnzippedRight<'_, I>
+where
+    I: Iterable,
+    I::Item: Pair,
+{
+    type Item = <I::Item as Pair>::Right;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+// col
+
+impl<'i, C> IterableObj for &'i UnzippedLeftCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = &'i <C::Item as Pair>::Left;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<C> CollectionObj for UnzippedLeftCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = <C::Item as Pair>::Left;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<'i, C> IterableObj for &'i UnzippedRightCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = &'i <C::Item as Pair>::Right;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
+
+impl<C> CollectionObj for UnzippedRightCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = <C::Item as Pair>::Right;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = &Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{Position, WithPosition},
+    Iterable,
+};
+use std::boxed::Box;
+
+impl<I> IterableObj for WithPosition<I>
+where
+    I: Iterable,
+{
+    type Item = (Position, I::Item);
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{LengthMismatch, ZippedExact},
+    Iterable,
+};
+use std::boxed::Box;
+
+impl<I1, I2> IterableObj for ZippedExact<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    type Item = Result<(I1::Item, I2::Item), LengthMismatch>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
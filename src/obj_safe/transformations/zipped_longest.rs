@@ -0,0 +1,18 @@
+use crate::{
+    obj_safe::IterableObj,
+    transformations::{EitherOrBoth, ZippedLongest},
+    Iterable,
+};
+use std::boxed::Box;
+
+impl<I1, I2> IterableObj for ZippedLongest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    type Item = EitherOrBoth<I1::Item, I2::Item>;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{obj_safe::IterableObj, transformations::ZippedWith, Iterable};
+use std::boxed::Box;
+
+impl<I1, I2, M, U> IterableObj for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable,
+    I2: Iterable,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+    type Item = U;
+
+    fn boxed_iter(&self) -> Box<dyn Iterator<Item = Self::Item> + '_> {
+        Box::new(self.iter())
+    }
+}
@@ -0,0 +1,71 @@
+use std::{num::NonZeroUsize, panic, thread, vec::Vec};
+
+/// Returns the number of threads available to the program, or 1 if it cannot be determined.
+pub(crate) fn default_num_threads() -> usize {
+    thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// Length of the contiguous chunks when `len` elements are split among `num_threads` workers.
+fn chunk_len(len: usize, num_threads: usize) -> usize {
+    len.div_ceil(num_threads).max(1)
+}
+
+/// Splits the `slice` into at most `num_threads` contiguous chunks, applies `f` on each chunk on a
+/// scoped thread and returns the results in the order of the chunks.
+///
+/// The chunk is processed on the calling thread when there is only one chunk.
+/// A panic in any of the workers is propagated to the caller.
+pub(crate) fn run_chunks<T, R, F>(slice: &[T], num_threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> R + Sync,
+{
+    let chunk_len = chunk_len(slice.len(), num_threads);
+    if slice.len() <= chunk_len {
+        return slice.chunks(chunk_len).map(f).collect();
+    }
+
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = slice
+            .chunks(chunk_len)
+            .map(|chunk| s.spawn(move || f(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
+
+/// Splits the `slice` into at most `num_threads` contiguous mutable chunks, applies `f` on each chunk
+/// on a scoped thread and returns the results in the order of the chunks.
+///
+/// The chunk is processed on the calling thread when there is only one chunk.
+/// A panic in any of the workers is propagated to the caller.
+pub(crate) fn run_chunks_mut<T, R, F>(slice: &mut [T], num_threads: usize, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(&mut [T]) -> R + Sync,
+{
+    let chunk_len = chunk_len(slice.len(), num_threads);
+    if slice.len() <= chunk_len {
+        return slice.chunks_mut(chunk_len).map(f).collect();
+    }
+
+    let f = &f;
+    thread::scope(|s| {
+        let handles: Vec<_> = slice
+            .chunks_mut(chunk_len)
+            .map(|chunk| s.spawn(move || f(chunk)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    })
+}
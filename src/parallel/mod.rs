@@ -0,0 +1,10 @@
+mod chunks;
+mod par;
+mod par_collection;
+mod par_collection_mut;
+mod par_mut;
+
+pub use par::Par;
+pub use par_collection::ParCollection;
+pub use par_collection_mut::ParCollectionMut;
+pub use par_mut::ParMut;
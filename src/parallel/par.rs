@@ -0,0 +1,179 @@
+use super::chunks::{default_num_threads, run_chunks};
+use core::iter::Sum;
+use std::vec::Vec;
+
+/// A parallel view over the elements of an index-addressable collection.
+///
+/// Work is split into contiguous chunks of the underlying slice, one chunk per thread, and each
+/// chunk is processed on a scoped thread. Results are always combined in the order of the elements;
+/// therefore, the outcome of each operation is deterministic for a given number of threads.
+///
+/// Created by the [`par`] method of `ParCollection`.
+///
+/// [`par`]: crate::parallel::ParCollection::par
+#[derive(Debug, PartialEq)]
+pub struct Par<'a, T> {
+    pub(crate) slice: &'a [T],
+    pub(crate) num_threads: usize,
+}
+
+impl<T> Clone for Par<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Par<'_, T> {}
+
+impl<'a, T> Par<'a, T> {
+    pub(crate) fn new(slice: &'a [T]) -> Self {
+        Self {
+            slice,
+            num_threads: default_num_threads(),
+        }
+    }
+
+    /// Returns the maximum number of threads that the work is split into.
+    ///
+    /// Defaults to the available parallelism of the program.
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Sets the maximum number of threads that the work is split into.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a: Vec<_> = (0..100).collect();
+    /// let par = a.par().with_num_threads(4);
+    ///
+    /// assert_eq!(par.num_threads(), 4);
+    /// assert_eq!(par.sum::<i32>(), 4950);
+    /// ```
+    pub fn with_num_threads(self, num_threads: usize) -> Self {
+        assert!(num_threads > 0, "number of threads must be positive");
+        Self {
+            slice: self.slice,
+            num_threads,
+        }
+    }
+
+    /// Calls the closure `f` on each element in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let a: Vec<_> = (0..100).collect();
+    /// let num_odds = AtomicUsize::new(0);
+    ///
+    /// a.par().with_num_threads(3).for_each(|x| {
+    ///     if x % 2 == 1 {
+    ///         num_odds.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// assert_eq!(num_odds.into_inner(), 50);
+    /// ```
+    pub fn for_each<F>(&self, f: F)
+    where
+        T: Sync,
+        F: Fn(&T) + Sync,
+    {
+        run_chunks(self.slice, self.num_threads, |chunk| {
+            chunk.iter().for_each(&f)
+        });
+    }
+
+    /// Maps each element with `map` in parallel and collects the results into a vector
+    /// in the order of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a = vec![1, 2, 3, 4, 5];
+    /// let b = a.par().with_num_threads(2).map_collect(|x| x * 10);
+    ///
+    /// assert_eq!(b, [10, 20, 30, 40, 50]);
+    /// ```
+    pub fn map_collect<O, M>(&self, map: M) -> Vec<O>
+    where
+        T: Sync,
+        O: Send,
+        M: Fn(&T) -> O + Sync,
+    {
+        let chunks = run_chunks(self.slice, self.num_threads, |chunk| {
+            chunk.iter().map(&map).collect::<Vec<_>>()
+        });
+        let mut mapped = Vec::with_capacity(self.slice.len());
+        for chunk in chunks {
+            mapped.extend(chunk);
+        }
+        mapped
+    }
+
+    /// Reduces the elements to a single one by repeatedly applying the `reduce` function;
+    /// returns None if the collection is empty.
+    ///
+    /// Each thread reduces a contiguous chunk of elements, and then the chunk results are reduced
+    /// in order. Therefore, `reduce` is expected to be associative, but not necessarily commutative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a: Vec<_> = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+    /// let joined = a.par().with_num_threads(2).reduce(|x, y| x + &y);
+    /// assert_eq!(joined.as_deref(), Some("abcde"));
+    ///
+    /// let b: Vec<String> = vec![];
+    /// assert_eq!(b.par().reduce(|x, y| x + &y), None);
+    /// ```
+    pub fn reduce<F>(&self, reduce: F) -> Option<T>
+    where
+        T: Clone + Sync + Send,
+        F: Fn(T, T) -> T + Sync,
+    {
+        run_chunks(self.slice, self.num_threads, |chunk| {
+            chunk.iter().cloned().reduce(&reduce)
+        })
+        .into_iter()
+        .flatten()
+        .reduce(&reduce)
+    }
+
+    /// Sums up the elements in parallel.
+    ///
+    /// Each thread sums up a contiguous chunk of elements, and then the chunk sums are added up in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a: Vec<u64> = (1..=1000).collect();
+    /// assert_eq!(a.par().with_num_threads(8).sum::<u64>(), 500500);
+    /// ```
+    pub fn sum<S>(&self) -> S
+    where
+        T: Sync,
+        S: for<'b> Sum<&'b T> + Sum<S> + Send,
+    {
+        run_chunks(self.slice, self.num_threads, |chunk| {
+            chunk.iter().sum::<S>()
+        })
+        .into_iter()
+        .sum()
+    }
+}
@@ -0,0 +1,151 @@
+use super::Par;
+use crate::Collection;
+use core::iter::Sum;
+use std::vec::Vec;
+
+/// An index-addressable [`Collection`], i.e., a collection whose elements can be viewed as a contiguous slice,
+/// which can be processed in parallel.
+///
+/// The work is split into contiguous chunks of the slice which are processed on scoped threads created by
+/// [`std::thread::scope`]; hence, closures may freely borrow from the enclosing scope. Results are combined
+/// in the order of the elements which makes the outcome deterministic for a given number of threads.
+///
+/// The `par_` methods use the available parallelism of the program as the number of threads. The [`par`]
+/// method, on the other hand, creates a [`Par`] view which allows to configure the number of threads.
+///
+/// Note that `ParCollection` is automatically implemented for all collections implementing `AsRef<[Item]>`,
+/// such as vectors, arrays or boxed slices.
+///
+/// In order to use parallel collections please add `--features std` if default features are not used,
+/// and use `use orx_iterable::{*, parallel::*}` to import dependencies.
+///
+/// [`par`]: crate::parallel::ParCollection::par
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::parallel::*;
+///
+/// let a: Vec<u64> = (1..=100).collect();
+///
+/// assert_eq!(a.par_sum::<u64>(), 5050);
+/// assert_eq!(a.par_reduce(|x, y| x.max(y)), Some(100));
+///
+/// let squares = a.par_map_collect(|x| x * x);
+/// assert_eq!(&squares[..3], [1, 4, 9]);
+///
+/// let sum_of_squares = a.par().with_num_threads(2).map_collect(|x| x * x);
+/// assert_eq!(squares, sum_of_squares);
+/// ```
+pub trait ParCollection: Collection + AsRef<[<Self as Collection>::Item]> {
+    /// Creates a parallel view over the elements of the collection with a configurable number of threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a = [1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let par = a.par().with_num_threads(3);
+    /// assert_eq!(par.map_collect(|x| x + 1), [2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(par.sum::<i32>(), 28);
+    /// ```
+    fn par(&self) -> Par<'_, Self::Item> {
+        Par::new(self.as_ref())
+    }
+
+    /// Calls the closure `f` on each element of the collection in parallel.
+    ///
+    /// Shorthand for `self.par().for_each(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    ///
+    /// let a: Vec<u64> = (1..=10).collect();
+    /// let total = AtomicU64::new(0);
+    ///
+    /// a.par_for_each(|x| _ = total.fetch_add(*x, Ordering::Relaxed));
+    /// assert_eq!(total.into_inner(), 55);
+    /// ```
+    fn par_for_each<F>(&self, f: F)
+    where
+        Self::Item: Sync,
+        F: Fn(&Self::Item) + Sync,
+    {
+        self.par().for_each(f)
+    }
+
+    /// Maps each element of the collection with `map` in parallel and collects the results into a vector
+    /// in the order of the elements.
+    ///
+    /// Shorthand for `self.par().map_collect(map)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let words = vec!["parallel", "map", "collect"];
+    /// assert_eq!(words.par_map_collect(|x| x.len()), [8, 3, 7]);
+    /// ```
+    fn par_map_collect<O, M>(&self, map: M) -> Vec<O>
+    where
+        Self::Item: Sync,
+        O: Send,
+        M: Fn(&Self::Item) -> O + Sync,
+    {
+        self.par().map_collect(map)
+    }
+
+    /// Reduces the elements of the collection to a single one by repeatedly applying the `reduce` function
+    /// in parallel; returns None if the collection is empty.
+    ///
+    /// The `reduce` function is expected to be associative, but not necessarily commutative.
+    ///
+    /// Shorthand for `self.par().reduce(reduce)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a = vec![3, 9, 2, 7];
+    /// assert_eq!(a.par_reduce(|x, y| x.max(y)), Some(9));
+    ///
+    /// let b: Vec<i32> = vec![];
+    /// assert_eq!(b.par_reduce(|x, y| x.max(y)), None);
+    /// ```
+    fn par_reduce<F>(&self, reduce: F) -> Option<Self::Item>
+    where
+        Self::Item: Clone + Sync + Send,
+        F: Fn(Self::Item, Self::Item) -> Self::Item + Sync,
+    {
+        self.par().reduce(reduce)
+    }
+
+    /// Sums up the elements of the collection in parallel.
+    ///
+    /// Shorthand for `self.par().sum()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let a = vec![1.5, 2.5, 3.0];
+    /// assert_eq!(a.par_sum::<f64>(), 7.0);
+    /// ```
+    fn par_sum<S>(&self) -> S
+    where
+        Self::Item: Sync,
+        S: for<'a> Sum<&'a Self::Item> + Sum<S> + Send,
+    {
+        self.par().sum()
+    }
+}
+
+impl<X> ParCollection for X where X: Collection + AsRef<[<X as Collection>::Item]> {}
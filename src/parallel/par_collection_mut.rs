@@ -0,0 +1,62 @@
+use super::ParMut;
+use crate::{Collection, CollectionMut};
+
+/// An index-addressable [`CollectionMut`], i.e., a collection whose elements can be viewed as a contiguous
+/// mutable slice, which can be mutated in parallel.
+///
+/// The work is split into contiguous and disjoint chunks of the slice which are processed on scoped threads
+/// created by [`std::thread::scope`].
+///
+/// The [`par_for_each_mut`] method uses the available parallelism of the program as the number of threads.
+/// The [`par_mut`] method, on the other hand, creates a [`ParMut`] view which allows to configure the number
+/// of threads.
+///
+/// Note that `ParCollectionMut` is automatically implemented for all mutable collections implementing
+/// `AsMut<[Item]>`, such as vectors, arrays or boxed slices.
+///
+/// [`par_for_each_mut`]: crate::parallel::ParCollectionMut::par_for_each_mut
+/// [`par_mut`]: crate::parallel::ParCollectionMut::par_mut
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::parallel::*;
+///
+/// let mut a: Vec<_> = (0..8).collect();
+///
+/// a.par_for_each_mut(|x| *x += 1);
+/// assert_eq!(a, [1, 2, 3, 4, 5, 6, 7, 8]);
+///
+/// a.par_mut().with_num_threads(3).for_each(|x| *x *= *x);
+/// assert_eq!(a, [1, 4, 9, 16, 25, 36, 49, 64]);
+/// ```
+pub trait ParCollectionMut: CollectionMut + AsMut<[<Self as Collection>::Item]> {
+    /// Creates a parallel view over mutable references to the elements of the collection with a configurable
+    /// number of threads.
+    fn par_mut(&mut self) -> ParMut<'_, Self::Item> {
+        ParMut::new(self.as_mut())
+    }
+
+    /// Calls the closure `f` on a mutable reference to each element of the collection in parallel.
+    ///
+    /// Shorthand for `self.par_mut().for_each(f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let mut names = vec![String::from("a"), String::from("b")];
+    /// names.par_for_each_mut(|x| x.push('!'));
+    /// assert_eq!(names, ["a!", "b!"]);
+    /// ```
+    fn par_for_each_mut<F>(&mut self, f: F)
+    where
+        Self::Item: Send,
+        F: Fn(&mut Self::Item) + Sync,
+    {
+        self.par_mut().for_each(f)
+    }
+}
+
+impl<X> ParCollectionMut for X where X: CollectionMut + AsMut<[<X as Collection>::Item]> {}
@@ -0,0 +1,66 @@
+use super::chunks::{default_num_threads, run_chunks_mut};
+
+/// A parallel view over mutable references to the elements of an index-addressable collection.
+///
+/// Work is split into contiguous and disjoint chunks of the underlying slice, one chunk per thread,
+/// and each chunk is processed on a scoped thread.
+///
+/// Created by the [`par_mut`] method of `ParCollectionMut`.
+///
+/// [`par_mut`]: crate::parallel::ParCollectionMut::par_mut
+#[derive(Debug, PartialEq)]
+pub struct ParMut<'a, T> {
+    pub(crate) slice: &'a mut [T],
+    pub(crate) num_threads: usize,
+}
+
+impl<'a, T> ParMut<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            slice,
+            num_threads: default_num_threads(),
+        }
+    }
+
+    /// Returns the maximum number of threads that the work is split into.
+    ///
+    /// Defaults to the available parallelism of the program.
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Sets the maximum number of threads that the work is split into.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_threads` is zero.
+    pub fn with_num_threads(self, num_threads: usize) -> Self {
+        assert!(num_threads > 0, "number of threads must be positive");
+        Self {
+            slice: self.slice,
+            num_threads,
+        }
+    }
+
+    /// Calls the closure `f` on a mutable reference to each element in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::parallel::*;
+    ///
+    /// let mut a: Vec<_> = (0..10).collect();
+    /// a.par_mut().with_num_threads(3).for_each(|x| *x *= 2);
+    ///
+    /// assert_eq!(a, [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// ```
+    pub fn for_each<F>(self, f: F)
+    where
+        T: Send,
+        F: Fn(&mut T) + Sync,
+    {
+        run_chunks_mut(self.slice, self.num_threads, |chunk| {
+            chunk.iter_mut().for_each(&f)
+        });
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{debug_fn::DebugFn, Iterable};
+use core::hash::Hash;
+use std::collections::{HashSet, VecDeque};
+
+/// An iterable which traverses the nodes of a graph reachable from a root node in breadth-first order.
+///
+/// Neighbors of a node are the elements of the iterable returned by the `neighbors` function.
+/// Every node is yielded at most once; the set of visited nodes is created from scratch every time
+/// the `iter` method is called.
+pub struct Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    pub(crate) root: T,
+    pub(crate) neighbors: F,
+}
+
+impl<T, F, N> Clone for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            neighbors: self.neighbors,
+        }
+    }
+}
+
+impl<T, F, N> Copy for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone + Copy,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+}
+
+impl<T, F, N> core::fmt::Debug for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone + core::fmt::Debug,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Bfs")
+            .field("root", &self.root)
+            .field("neighbors", &DebugFn)
+            .finish()
+    }
+}
+
+impl<T, F, N> PartialEq for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone + PartialEq,
+    F: Fn(&T) -> N + Copy + PartialEq,
+    N: Iterable<Item = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.neighbors == other.neighbors
+    }
+}
+
+impl<T, F, N> Iterable for Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    type Iter = BfsIter<T, F, N>;
+
+    fn iter(&self) -> Self::Iter {
+        let mut visited = HashSet::new();
+        visited.insert(self.root.clone());
+        let mut queue = VecDeque::new();
+        queue.push_back(self.root.clone());
+        BfsIter {
+            queue,
+            visited,
+            neighbors: self.neighbors,
+        }
+    }
+}
+
+/// Iterator traversing the nodes of a graph in breadth-first order.
+pub struct BfsIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    pub(crate) queue: VecDeque<T>,
+    pub(crate) visited: HashSet<T>,
+    pub(crate) neighbors: F,
+}
+
+impl<T, F, N> Iterator for BfsIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        for x in (self.neighbors)(&node).iter() {
+            if self.visited.insert(x.clone()) {
+                self.queue.push_back(x);
+            }
+        }
+        Some(node)
+    }
+}
+
+/// Creates an iterable which traverses the nodes reachable from the `root` in breadth-first order,
+/// starting with the `root` itself.
+///
+/// Neighbors of a node are the elements of the iterable returned by the `neighbors` function.
+/// Since the visited nodes are tracked, every reachable node is yielded exactly once even when the
+/// graph contains cycles.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// // edges: 0->1, 0->2, 1->3, 2->1, 2->4, 4->0
+/// let adjacency: Vec<Vec<usize>> = vec![vec![1, 2], vec![3], vec![1, 4], vec![], vec![0]];
+///
+/// let it = bfs(0, |x: &usize| adjacency[*x].copied());
+///
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+/// assert_eq!(it.iter().count(), 5);
+///
+/// let it = bfs(2, |x: &usize| adjacency[*x].copied());
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [2, 1, 4, 3, 0]);
+/// ```
+pub fn bfs<T, F, N>(root: T, neighbors: F) -> Bfs<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    Bfs { root, neighbors }
+}
@@ -0,0 +1,359 @@
+use crate::{debug_fn::DebugFn, transformations::Skipped, Iterable};
+use core::hash::Hash;
+use std::{collections::HashSet, vec::Vec};
+
+/// An iterable which traverses the nodes of a graph reachable from a root node in depth-first pre-order;
+/// i.e., every node is yielded before its children.
+///
+/// Children of a node are the elements of the iterable returned by the `children` function.
+/// Every node is yielded at most once; the set of visited nodes is created from scratch every time
+/// the `iter` method is called.
+pub struct DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    pub(crate) root: T,
+    pub(crate) children: F,
+}
+
+impl<T, F, N> Clone for DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            children: self.children,
+        }
+    }
+}
+
+impl<T, F, N> Copy for DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone + Copy,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+}
+
+impl<T, F, N> core::fmt::Debug for DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone + core::fmt::Debug,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DfsPreorder")
+            .field("root", &self.root)
+            .field("children", &DebugFn)
+            .finish()
+    }
+}
+
+impl<T, F, N> PartialEq for DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone + PartialEq,
+    F: Fn(&T) -> N + Copy + PartialEq,
+    N: Iterable<Item = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.children == other.children
+    }
+}
+
+impl<T, F, N> Iterable for DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    type Iter = DfsPreorderIter<T, F, N>;
+
+    fn iter(&self) -> Self::Iter {
+        let mut visited = HashSet::new();
+        visited.insert(self.root.clone());
+        DfsPreorderIter {
+            root: Some(self.root.clone()),
+            stack: Vec::new(),
+            visited,
+            children: self.children,
+        }
+    }
+}
+
+/// Iterator traversing the nodes of a graph in depth-first pre-order.
+pub struct DfsPreorderIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    pub(crate) root: Option<T>,
+    pub(crate) stack: Vec<N::Iter>,
+    pub(crate) visited: HashSet<T>,
+    pub(crate) children: F,
+}
+
+impl<T, F, N> Iterator for DfsPreorderIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(root) = self.root.take() {
+            self.stack.push((self.children)(&root).iter());
+            return Some(root);
+        }
+
+        loop {
+            let top = self.stack.last_mut()?;
+            match top.next() {
+                Some(x) => {
+                    if self.visited.insert(x.clone()) {
+                        self.stack.push((self.children)(&x).iter());
+                        return Some(x);
+                    }
+                }
+                None => _ = self.stack.pop(),
+            }
+        }
+    }
+}
+
+/// An iterable which traverses the nodes of a graph reachable from a root node in depth-first post-order;
+/// i.e., every node is yielded after its children.
+///
+/// Children of a node are the elements of the iterable returned by the `children` function.
+/// Every node is yielded at most once; the set of visited nodes is created from scratch every time
+/// the `iter` method is called.
+pub struct DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    pub(crate) root: T,
+    pub(crate) children: F,
+}
+
+impl<T, F, N> Clone for DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            children: self.children,
+        }
+    }
+}
+
+impl<T, F, N> Copy for DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone + Copy,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+}
+
+impl<T, F, N> core::fmt::Debug for DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone + core::fmt::Debug,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DfsPostorder")
+            .field("root", &self.root)
+            .field("children", &DebugFn)
+            .finish()
+    }
+}
+
+impl<T, F, N> PartialEq for DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone + PartialEq,
+    F: Fn(&T) -> N + Copy + PartialEq,
+    N: Iterable<Item = T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root && self.children == other.children
+    }
+}
+
+impl<T, F, N> Iterable for DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    type Iter = DfsPostorderIter<T, F, N>;
+
+    fn iter(&self) -> Self::Iter {
+        let mut visited = HashSet::new();
+        visited.insert(self.root.clone());
+        let root_children = (self.children)(&self.root).iter();
+        DfsPostorderIter {
+            stack: std::vec![(self.root.clone(), root_children)],
+            visited,
+            children: self.children,
+        }
+    }
+}
+
+/// Iterator traversing the nodes of a graph in depth-first post-order.
+pub struct DfsPostorderIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    pub(crate) stack: Vec<(T, N::Iter)>,
+    pub(crate) visited: HashSet<T>,
+    pub(crate) children: F,
+}
+
+impl<T, F, N> Iterator for DfsPostorderIter<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N,
+    N: Iterable<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (_, top) = self.stack.last_mut()?;
+            match top.next() {
+                Some(x) => {
+                    if self.visited.insert(x.clone()) {
+                        let children = (self.children)(&x).iter();
+                        self.stack.push((x, children));
+                    }
+                }
+                None => return self.stack.pop().map(|(node, _)| node),
+            }
+        }
+    }
+}
+
+/// An iterable which traverses the nodes reachable from a root node, excluding the root, in
+/// depth-first pre-order.
+pub type Descendants<T, F, N> = Skipped<DfsPreorder<T, F, N>>;
+
+/// Creates an iterable which traverses the nodes reachable from the `root` in depth-first pre-order,
+/// starting with the `root` itself.
+///
+/// Children of a node are the elements of the iterable returned by the `children` function.
+/// Since the visited nodes are tracked, every reachable node is yielded exactly once even when the
+/// graph contains cycles.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// //       0
+/// //     /   \
+/// //    1     2
+/// //   / \     \
+/// //  3   4     5
+/// let children: Vec<Vec<usize>> = vec![vec![1, 2], vec![3, 4], vec![5], vec![], vec![], vec![]];
+///
+/// let it = dfs_preorder(0, |x: &usize| children[*x].copied());
+///
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 3, 4, 2, 5]);
+/// assert_eq!(it.iter().count(), 6);
+/// ```
+pub fn dfs_preorder<T, F, N>(root: T, children: F) -> DfsPreorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    DfsPreorder { root, children }
+}
+
+/// Creates an iterable which traverses the nodes reachable from the `root` in depth-first post-order,
+/// ending with the `root` itself.
+///
+/// Children of a node are the elements of the iterable returned by the `children` function.
+/// Since the visited nodes are tracked, every reachable node is yielded exactly once even when the
+/// graph contains cycles.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// //       0
+/// //     /   \
+/// //    1     2
+/// //   / \     \
+/// //  3   4     5
+/// let children: Vec<Vec<usize>> = vec![vec![1, 2], vec![3, 4], vec![5], vec![], vec![], vec![]];
+///
+/// let it = dfs_postorder(0, |x: &usize| children[*x].copied());
+///
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [3, 4, 1, 5, 2, 0]);
+/// assert_eq!(it.iter().count(), 6);
+/// ```
+pub fn dfs_postorder<T, F, N>(root: T, children: F) -> DfsPostorder<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    DfsPostorder { root, children }
+}
+
+/// Creates an iterable which traverses the nodes reachable from the `root`, excluding the `root`
+/// itself, in depth-first pre-order.
+///
+/// Children of a node are the elements of the iterable returned by the `children` function.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// //       0
+/// //     /   \
+/// //    1     2
+/// //   / \     \
+/// //  3   4     5
+/// let children: Vec<Vec<usize>> = vec![vec![1, 2], vec![3, 4], vec![5], vec![], vec![], vec![]];
+///
+/// let it = descendants(1, |x: &usize| children[*x].copied());
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [3, 4]);
+///
+/// let it = descendants(0, |x: &usize| children[*x].copied());
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 3, 4, 2, 5]);
+///
+/// let it = descendants(5, |x: &usize| children[*x].copied());
+/// assert_eq!(it.iter().count(), 0);
+/// ```
+pub fn descendants<T, F, N>(root: T, children: F) -> Descendants<T, F, N>
+where
+    T: Eq + Hash + Clone,
+    F: Fn(&T) -> N + Copy,
+    N: Iterable<Item = T>,
+{
+    Skipped {
+        it: dfs_preorder(root, children),
+        n: 1,
+    }
+}
@@ -6,6 +6,26 @@ pub struct Empty<T> {
     phantom: PhantomData<T>,
 }
 
+impl<T> Clone for Empty<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Empty<T> {}
+
+impl<T> core::fmt::Debug for Empty<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Empty").finish()
+    }
+}
+
+impl<T> PartialEq for Empty<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl<T> Iterable for Empty<T> {
     type Item = T;
 
@@ -33,6 +53,26 @@ pub struct EmptyCol<T> {
     phantom: PhantomData<T>,
 }
 
+impl<T> Clone for EmptyCol<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EmptyCol<T> {}
+
+impl<T> core::fmt::Debug for EmptyCol<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EmptyCol").finish()
+    }
+}
+
+impl<T> PartialEq for EmptyCol<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
 impl<'a, T> Iterable for &'a EmptyCol<T> {
     type Item = &'a T;
 
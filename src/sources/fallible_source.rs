@@ -0,0 +1,75 @@
+use crate::{debug_fn::DebugFn, FallibleIterable};
+
+/// A fallible iterable which calls a closure to open its source every time `try_iter` is called.
+///
+/// Created by the [`fallible_source`] function.
+pub struct FallibleSourceIterable<F> {
+    pub(crate) open: F,
+}
+
+impl<F> Clone for FallibleSourceIterable<F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            open: self.open.clone(),
+        }
+    }
+}
+
+impl<F> Copy for FallibleSourceIterable<F> where F: Copy {}
+
+impl<F> core::fmt::Debug for FallibleSourceIterable<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleSourceIterable")
+            .field("open", &DebugFn)
+            .finish()
+    }
+}
+
+impl<F, C, E> FallibleIterable for FallibleSourceIterable<F>
+where
+    F: Fn() -> Result<C, E>,
+    C: IntoIterator,
+{
+    type Item = C::Item;
+
+    type Error = E;
+
+    type Iter = C::IntoIter;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        (self.open)().map(|x| x.into_iter())
+    }
+}
+
+/// Creates a fallible iterable which calls the `open` closure every time `try_iter` is called;
+/// and iterates over the returned source if it succeeds.
+///
+/// This is useful for sources which need to be re-opened on every pass, such as files or readers.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let text = Some("a,b,c");
+/// let source = fallible_source(|| text.map(|x| x.split(',')).ok_or("no text"));
+///
+/// assert_eq!(source.try_iter().map(|x| x.count()), Ok(3));
+/// assert_eq!(source.panicking().iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+///
+/// let text: Option<&str> = None;
+/// let source = fallible_source(|| text.map(|x| x.split(',')).ok_or("no text"));
+///
+/// assert!(source.try_iter().is_err());
+/// assert_eq!(source.results().iter().collect::<Vec<_>>(), [Err("no text")]);
+/// ```
+pub fn fallible_source<F, C, E>(open: F) -> FallibleSourceIterable<F>
+where
+    F: Fn() -> Result<C, E>,
+    C: IntoIterator,
+{
+    FallibleSourceIterable { open }
+}
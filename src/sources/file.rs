@@ -0,0 +1,234 @@
+use crate::Iterable;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Lines, Read},
+    path::{Path, PathBuf},
+    string::String,
+    vec,
+    vec::Vec,
+};
+
+/// An iterable which yields the lines of a file.
+///
+/// The file is re-opened and a new `BufReader` is created every time the `iter` method is called;
+/// therefore, the file can be iterated over multiple times without loading it into memory.
+///
+/// Elements are of type `io::Result<String>`:
+/// * if the file cannot be opened, the iterator yields a single `Err` element;
+/// * if reading a line fails, the iterator yields the `Err` element and ends.
+///
+/// Since the elements are results, the iterable is a [`TryIterable`].
+///
+/// [`TryIterable`]: crate::TryIterable
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileLines {
+    pub(crate) path: PathBuf,
+}
+
+impl FileLines {
+    /// Path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Iterable for FileLines {
+    type Item = io::Result<String>;
+
+    type Iter = FileLinesIter;
+
+    fn iter(&self) -> Self::Iter {
+        match File::open(&self.path) {
+            Ok(file) => FileLinesIter {
+                lines: Some(BufReader::new(file).lines()),
+                error: None,
+            },
+            Err(e) => FileLinesIter {
+                lines: None,
+                error: Some(e),
+            },
+        }
+    }
+}
+
+/// Iterator over the lines of a file, which ends right after yielding the first error.
+pub struct FileLinesIter {
+    lines: Option<Lines<BufReader<File>>>,
+    error: Option<io::Error>,
+}
+
+impl Iterator for FileLinesIter {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        let line = self.lines.as_mut()?.next();
+        if let Some(Err(_)) | None = &line {
+            self.lines = None;
+        }
+        line
+    }
+}
+
+/// Creates an iterable which yields the lines of the file at the given `path`.
+///
+/// The file is re-opened every time the `iter` method is called, see [`FileLines`].
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let path = std::env::temp_dir().join("orx_iterable_doc_file_lines.txt");
+/// std::fs::write(&path, "1\n2\n3\n").unwrap();
+///
+/// let lines = file_lines(&path);
+///
+/// assert_eq!(lines.iter().count(), 3);
+/// assert_eq!(lines.try_collect::<Vec<_>>().unwrap(), ["1", "2", "3"]);
+///
+/// std::fs::remove_file(&path).unwrap();
+///
+/// assert!(lines.iter().next().unwrap().is_err());
+/// assert!(lines.try_collect::<Vec<_>>().is_err());
+/// ```
+pub fn file_lines(path: impl AsRef<Path>) -> FileLines {
+    FileLines {
+        path: path.as_ref().to_path_buf(),
+    }
+}
+
+// chunks
+
+/// An iterable which yields the bytes of a file in chunks of a fixed size.
+///
+/// The file is re-opened and a new `BufReader` is created every time the `iter` method is called;
+/// therefore, the file can be iterated over multiple times without loading it into memory.
+///
+/// All chunks have exactly `chunk_size` bytes, except for the last one which might be shorter.
+///
+/// Elements are of type `io::Result<Vec<u8>>`:
+/// * if the file cannot be opened, the iterator yields a single `Err` element;
+/// * if reading a chunk fails, the iterator yields the `Err` element and ends.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileChunks {
+    pub(crate) path: PathBuf,
+    pub(crate) chunk_size: usize,
+}
+
+impl FileChunks {
+    /// Path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of bytes in each chunk, except possibly for the last one.
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+}
+
+impl Iterable for FileChunks {
+    type Item = io::Result<Vec<u8>>;
+
+    type Iter = FileChunksIter;
+
+    fn iter(&self) -> Self::Iter {
+        match File::open(&self.path) {
+            Ok(file) => FileChunksIter {
+                reader: Some(BufReader::new(file)),
+                error: None,
+                chunk_size: self.chunk_size,
+            },
+            Err(e) => FileChunksIter {
+                reader: None,
+                error: Some(e),
+                chunk_size: self.chunk_size,
+            },
+        }
+    }
+}
+
+/// Iterator over fixed-size byte chunks of a file, which ends right after yielding the first error.
+pub struct FileChunksIter {
+    reader: Option<BufReader<File>>,
+    error: Option<io::Error>,
+    chunk_size: usize,
+}
+
+impl FileChunksIter {
+    fn read_chunk(reader: &mut BufReader<File>, chunk_size: usize) -> io::Result<Vec<u8>> {
+        let mut chunk = vec![0u8; chunk_size];
+        let mut len = 0;
+        while len < chunk_size {
+            match reader.read(&mut chunk[len..]) {
+                Ok(0) => break,
+                Ok(n) => len += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        chunk.truncate(len);
+        Ok(chunk)
+    }
+}
+
+impl Iterator for FileChunksIter {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.error.take() {
+            return Some(Err(e));
+        }
+
+        let reader = self.reader.as_mut()?;
+        match Self::read_chunk(reader, self.chunk_size) {
+            Ok(chunk) if chunk.is_empty() => {
+                self.reader = None;
+                None
+            }
+            Ok(chunk) => Some(Ok(chunk)),
+            Err(e) => {
+                self.reader = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Creates an iterable which yields the bytes of the file at the given `path` in chunks of `chunk_size` bytes.
+///
+/// The file is re-opened every time the `iter` method is called, see [`FileChunks`].
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let path = std::env::temp_dir().join("orx_iterable_doc_file_chunks.txt");
+/// std::fs::write(&path, "abcdefg").unwrap();
+///
+/// let chunks = file_chunks(&path, 3);
+///
+/// assert_eq!(chunks.iter().count(), 3);
+/// assert_eq!(
+///     chunks.try_collect::<Vec<_>>().unwrap(),
+///     [b"abc".to_vec(), b"def".to_vec(), b"g".to_vec()]
+/// );
+///
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn file_chunks(path: impl AsRef<Path>, chunk_size: usize) -> FileChunks {
+    assert!(chunk_size > 0, "chunk_size must be positive");
+    FileChunks {
+        path: path.as_ref().to_path_buf(),
+        chunk_size,
+    }
+}
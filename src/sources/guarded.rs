@@ -0,0 +1,376 @@
+use crate::{Collection, Iterable};
+use core::{
+    cell::{Ref, RefCell},
+    ops::{Deref, Index},
+};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+/// Trait to create guard-holding iterables over collections behind interior mutability,
+/// such as `RefCell<C>`, `Mutex<C>` or `RwLock<C>`.
+///
+/// Every time the `iter` method of the created iterable is called, the borrow or the lock is acquired
+/// and held by the iterator until it is dropped. Elements are yielded by cloning.
+///
+/// The collection is required to be index-addressable, such as `Vec<T>`, `[T; N]` or `VecDeque<T>`,
+/// so that each element is accessed in constant time while the guard is held.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// let numbers = Rc::new(RefCell::new(vec![1, 2, 3]));
+///
+/// let it = numbers.guarded();
+/// assert_eq!(it.iter().sum::<i32>(), 6);
+///
+/// numbers.borrow_mut().push(4);
+/// assert_eq!(it.iter().sum::<i32>(), 10);
+///
+/// it.write(|x| x.retain(|x| x % 2 == 0));
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [2, 4]);
+/// assert_eq!(it.read(|x| x.len()), 2);
+/// ```
+pub trait AsGuardedIterable {
+    /// Type of the guard-holding iterable.
+    type Guarded<'a>: Iterable
+    where
+        Self: 'a;
+
+    /// Creates a guard-holding iterable which acquires the borrow or the lock every time its `iter` method is called.
+    fn guarded(&self) -> Self::Guarded<'_>;
+}
+
+/// Iterator holding a borrow or lock guard of a collection and yielding clones of its elements.
+///
+/// The guard is released when the iterator is dropped.
+///
+/// Since the iterator cannot borrow from the guard it holds, it accesses its next element by index.
+/// Therefore, the collection is required to be index-addressable, such as `Vec<T>`, `[T; N]` or
+/// `VecDeque<T>`, so that a complete pass is a linear time operation.
+pub struct GuardedIter<G>
+where
+    G: Deref,
+    G::Target: Collection,
+{
+    pub(crate) guard: G,
+    pub(crate) position: usize,
+    pub(crate) len: usize,
+}
+
+impl<G> GuardedIter<G>
+where
+    G: Deref,
+    G::Target: Collection,
+{
+    pub(crate) fn new(guard: G) -> Self {
+        let len = guard.iter().count();
+        Self {
+            guard,
+            position: 0,
+            len,
+        }
+    }
+}
+
+impl<G> Iterator for GuardedIter<G>
+where
+    G: Deref,
+    G::Target: Collection + Index<usize, Output = <G::Target as Collection>::Item>,
+    <G::Target as Collection>::Item: Clone,
+{
+    type Item = <G::Target as Collection>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.position < self.len {
+            true => {
+                let x = self.guard[self.position].clone();
+                self.position += 1;
+                Some(x)
+            }
+            false => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.position;
+        (remaining, Some(remaining))
+    }
+}
+
+// ref-cell
+
+/// An iterable over the elements of a collection behind a `RefCell`.
+///
+/// Every time the `iter` method is called, the collection is immutably borrowed;
+/// the borrow is released when the created iterator is dropped.
+pub struct RefCellIterable<'a, C>
+where
+    C: Collection,
+{
+    pub(crate) cell: &'a RefCell<C>,
+}
+
+impl<'a, C> core::fmt::Debug for RefCellIterable<'a, C>
+where
+    C: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RefCellIterable")
+            .field("cell", &self.cell)
+            .finish()
+    }
+}
+
+impl<C> Clone for RefCellIterable<'_, C>
+where
+    C: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for RefCellIterable<'_, C> where C: Collection {}
+
+impl<C> RefCellIterable<'_, C>
+where
+    C: Collection,
+{
+    /// Immutably borrows the collection and calls `f` with a reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collection is currently mutably borrowed.
+    pub fn read<R>(&self, f: impl FnOnce(&C) -> R) -> R {
+        f(&self.cell.borrow())
+    }
+
+    /// Mutably borrows the collection and calls `f` with a mutable reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collection is currently borrowed, such as by an alive iterator of this iterable.
+    pub fn write<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        f(&mut self.cell.borrow_mut())
+    }
+}
+
+impl<'a, C> Iterable for RefCellIterable<'a, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    type Iter = GuardedIter<Ref<'a, C>>;
+
+    /// Immutably borrows the collection and returns an iterator holding the borrow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the collection is currently mutably borrowed.
+    fn iter(&self) -> Self::Iter {
+        GuardedIter::new(self.cell.borrow())
+    }
+}
+
+impl<C> AsGuardedIterable for RefCell<C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Guarded<'a>
+        = RefCellIterable<'a, C>
+    where
+        Self: 'a;
+
+    fn guarded(&self) -> Self::Guarded<'_> {
+        RefCellIterable { cell: self }
+    }
+}
+
+// mutex
+
+/// An iterable over the elements of a collection behind a `Mutex`.
+///
+/// Every time the `iter` method is called, the lock is acquired; it is released when the created
+/// iterator is dropped. Note that a second iterator created on the same thread while the first one
+/// is alive deadlocks; prefer [`RwLockIterable`] when multiple simultaneous iterators are required.
+///
+/// A poisoned lock is recovered and the collection is accessed as is.
+#[cfg(feature = "std")]
+pub struct MutexIterable<'a, C>
+where
+    C: Collection,
+{
+    pub(crate) mutex: &'a Mutex<C>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, C> core::fmt::Debug for MutexIterable<'a, C>
+where
+    C: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MutexIterable")
+            .field("mutex", &self.mutex)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Clone for MutexIterable<'_, C>
+where
+    C: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Copy for MutexIterable<'_, C> where C: Collection {}
+
+#[cfg(feature = "std")]
+impl<'a, C> MutexIterable<'a, C>
+where
+    C: Collection,
+{
+    fn lock(&self) -> MutexGuard<'a, C> {
+        self.mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Acquires the lock and calls `f` with a reference to the collection.
+    pub fn read<R>(&self, f: impl FnOnce(&C) -> R) -> R {
+        f(&self.lock())
+    }
+
+    /// Acquires the lock and calls `f` with a mutable reference to the collection.
+    pub fn write<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        f(&mut self.lock())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, C> Iterable for MutexIterable<'a, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    type Iter = GuardedIter<MutexGuard<'a, C>>;
+
+    fn iter(&self) -> Self::Iter {
+        GuardedIter::new(self.lock())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> AsGuardedIterable for Mutex<C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Guarded<'a>
+        = MutexIterable<'a, C>
+    where
+        Self: 'a;
+
+    fn guarded(&self) -> Self::Guarded<'_> {
+        MutexIterable { mutex: self }
+    }
+}
+
+// rw-lock
+
+/// An iterable over the elements of a collection behind a `RwLock`.
+///
+/// Every time the `iter` method is called, a read lock is acquired; it is released when the created
+/// iterator is dropped.
+///
+/// A poisoned lock is recovered and the collection is accessed as is.
+#[cfg(feature = "std")]
+pub struct RwLockIterable<'a, C>
+where
+    C: Collection,
+{
+    pub(crate) lock: &'a RwLock<C>,
+}
+
+#[cfg(feature = "std")]
+impl<'a, C> core::fmt::Debug for RwLockIterable<'a, C>
+where
+    C: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RwLockIterable")
+            .field("lock", &self.lock)
+            .finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Clone for RwLockIterable<'_, C>
+where
+    C: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> Copy for RwLockIterable<'_, C> where C: Collection {}
+
+#[cfg(feature = "std")]
+impl<C> RwLockIterable<'_, C>
+where
+    C: Collection,
+{
+    /// Acquires a read lock and calls `f` with a reference to the collection.
+    pub fn read<R>(&self, f: impl FnOnce(&C) -> R) -> R {
+        f(&self.lock.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Acquires the write lock and calls `f` with a mutable reference to the collection.
+    pub fn write<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        f(&mut self.lock.write().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, C> Iterable for RwLockIterable<'a, C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Item = C::Item;
+
+    type Iter = GuardedIter<RwLockReadGuard<'a, C>>;
+
+    fn iter(&self) -> Self::Iter {
+        let guard: RwLockReadGuard<'a, C> =
+            self.lock.read().unwrap_or_else(PoisonError::into_inner);
+        GuardedIter::new(guard)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> AsGuardedIterable for RwLock<C>
+where
+    C: Collection + Index<usize, Output = <C as Collection>::Item>,
+    C::Item: Clone,
+{
+    type Guarded<'a>
+        = RwLockIterable<'a, C>
+    where
+        Self: 'a;
+
+    fn guarded(&self) -> Self::Guarded<'_> {
+        RwLockIterable { lock: self }
+    }
+}
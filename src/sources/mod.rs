@@ -1,9 +1,30 @@
+#[cfg(feature = "std")]
+mod bfs;
+#[cfg(feature = "std")]
+mod dfs;
 mod empty;
+mod fallible_source;
+#[cfg(feature = "std")]
+mod file;
+mod guarded;
 mod once;
 mod repeat;
 mod repeat_n;
 
+#[cfg(feature = "std")]
+pub use bfs::{bfs, Bfs, BfsIter};
+#[cfg(feature = "std")]
+pub use dfs::{
+    descendants, dfs_postorder, dfs_preorder, Descendants, DfsPostorder, DfsPostorderIter,
+    DfsPreorder, DfsPreorderIter,
+};
 pub use empty::{empty, empty_col, Empty, EmptyCol};
+pub use fallible_source::{fallible_source, FallibleSourceIterable};
+#[cfg(feature = "std")]
+pub use file::{file_chunks, file_lines, FileChunks, FileChunksIter, FileLines, FileLinesIter};
+pub use guarded::{AsGuardedIterable, GuardedIter, RefCellIterable};
+#[cfg(feature = "std")]
+pub use guarded::{MutexIterable, RwLockIterable};
 pub use once::{once, once_col, Once, OnceCol};
 pub use repeat::{repeat, Repeat};
 pub use repeat_n::{repeat_n, RepeatN};
@@ -1,6 +1,7 @@
 use crate::{Collection, CollectionMut, Iterable};
 
 /// An iterable which yields a wrapped value only once.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Once<T>
 where
     T: Clone,
@@ -37,6 +38,7 @@ where
 // col
 
 /// An iterable collection having only one item.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct OnceCol<T> {
     pub(crate) value: T,
 }
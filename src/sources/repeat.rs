@@ -1,6 +1,7 @@
 use crate::Iterable;
 
 /// An iterable which yields the same value infinitely many times.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Repeat<T>
 where
     T: Clone,
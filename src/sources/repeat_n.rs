@@ -1,6 +1,7 @@
 use crate::Iterable;
 
 /// An iterable which yields the same value `n` times.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct RepeatN<T>
 where
     T: Clone,
@@ -0,0 +1,98 @@
+use crate::{Collection, CollectionMut, Iterable};
+use core::cell::OnceCell;
+use std::vec::Vec;
+
+/// Wraps an `Iterable` and creates a `Collection` which materializes the elements of the original iterable
+/// the first time it is iterated over, and replays the stored elements on every later pass.
+///
+/// The original iterable is iterated over again only after the cache is cleared by the [`invalidate`] method.
+///
+/// Since the elements are stored, the cached iterable is a [`Collection`] yielding references to its elements,
+/// and hence, the collection transformations are available on it. Further, it is a [`CollectionMut`];
+/// mutations are applied on the cached elements and they are kept until the cache is invalidated.
+///
+/// [`invalidate`]: crate::transformations::Cached::invalidate
+#[derive(Clone, Debug)]
+pub struct Cached<I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+    pub(crate) cache: OnceCell<Vec<I::Item>>,
+}
+
+impl<I> Cached<I>
+where
+    I: Iterable,
+{
+    /// Returns the cached elements, iterating over the original iterable to materialize them if
+    /// they are not cached yet.
+    ///
+    /// This method can be called to eagerly fill the cache.
+    pub fn materialize(&self) -> &[I::Item] {
+        self.cache.get_or_init(|| self.it.iter().collect())
+    }
+
+    /// Returns true if the elements are currently cached; false if the original iterable will be
+    /// iterated over on the next pass.
+    pub fn is_cached(&self) -> bool {
+        self.cache.get().is_some()
+    }
+
+    /// Clears the cached elements so that the next pass iterates over the original iterable again.
+    pub fn invalidate(&mut self) {
+        self.cache.take();
+    }
+
+    /// Consumes the cached iterable and returns the original iterable.
+    pub fn into_inner(self) -> I {
+        self.it
+    }
+}
+
+impl<'a, I> Iterable for &'a Cached<I>
+where
+    I: Iterable,
+{
+    type Item = &'a I::Item;
+
+    type Iter = core::slice::Iter<'a, I::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        self.materialize().iter()
+    }
+}
+
+impl<I> Collection for Cached<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<I> CollectionMut for Cached<I>
+where
+    I: Iterable,
+{
+    type IterMut<'i>
+        = core::slice::IterMut<'i, I::Item>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.materialize();
+        match self.cache.get_mut() {
+            Some(cache) => cache.iter_mut(),
+            None => [].iter_mut(),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 use orx_self_or::SoM;
 
 /// An iterable created by chaining two iterables.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Chained<I1, I2>
 where
     I1: Iterable,
@@ -41,6 +42,58 @@ where
     pub(crate) phantom: PhantomData<(I1, I2)>,
 }
 
+impl<I1, I2, E1, E2> Clone for ChainedCol<I1, I2, E1, E2>
+where
+    I1: Collection,
+    I2: Collection<Item = I1::Item>,
+    E1: SoM<I1> + Clone,
+    E2: SoM<I2> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it1: self.it1.clone(),
+            it2: self.it2.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I1, I2, E1, E2> Copy for ChainedCol<I1, I2, E1, E2>
+where
+    I1: Collection,
+    I2: Collection<Item = I1::Item>,
+    E1: SoM<I1> + Copy,
+    E2: SoM<I2> + Copy,
+{
+}
+
+impl<I1, I2, E1, E2> core::fmt::Debug for ChainedCol<I1, I2, E1, E2>
+where
+    I1: Collection,
+    I2: Collection<Item = I1::Item>,
+    E1: SoM<I1> + core::fmt::Debug,
+    E2: SoM<I2> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ChainedCol")
+            .field("it1", &self.it1)
+            .field("it2", &self.it2)
+            .finish()
+    }
+}
+
+impl<I1, I2, E1, E2> PartialEq for ChainedCol<I1, I2, E1, E2>
+where
+    I1: Collection,
+    I2: Collection<Item = I1::Item>,
+    E1: SoM<I1> + PartialEq,
+    E2: SoM<I2> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it1 == other.it1 && self.it2 == other.it2
+    }
+}
+
 impl<'a, I1, I2, E1, E2> Iterable for &'a ChainedCol<I1, I2, E1, E2>
 where
     I1: Collection,
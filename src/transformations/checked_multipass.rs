@@ -0,0 +1,223 @@
+use crate::{debug_fn::DebugFn, Iterable};
+use core::{
+    cell::RefCell,
+    hash::{Hash, Hasher},
+};
+use std::{collections::hash_map::DefaultHasher, rc::Rc};
+
+/// Fingerprint of a complete pass over an iterable, consisting of the number of yielded elements and
+/// a hash of the yielded sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fingerprint {
+    /// Number of elements yielded in the pass.
+    pub len: usize,
+    /// Hash of the sequence of elements yielded in the pass.
+    pub hash: u64,
+}
+
+/// Report of a pass of a [`CheckedMultipass`] iterable yielding a different sequence than the first pass.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MultipassMismatch {
+    /// Zero-based index of the complete pass which yielded the different sequence; always positive.
+    pub pass: usize,
+    /// Fingerprint of the first complete pass.
+    pub expected: Fingerprint,
+    /// Fingerprint of the mismatching pass.
+    pub actual: Fingerprint,
+}
+
+impl core::fmt::Display for MultipassMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "pass {} of the iterable yielded a different sequence than the first pass: expected {} elements with hash {}, found {} elements with hash {}",
+            self.pass, self.expected.len, self.expected.hash, self.actual.len, self.actual.hash
+        )
+    }
+}
+
+impl std::error::Error for MultipassMismatch {}
+
+/// Default mismatch handler of [`CheckedMultipass`] which panics with the mismatch report.
+#[allow(clippy::panic)]
+pub(crate) fn panic_on_mismatch(mismatch: &MultipassMismatch) {
+    panic!("{}", mismatch)
+}
+
+#[derive(Default)]
+struct MultipassState {
+    first: Option<Fingerprint>,
+    completed_passes: usize,
+}
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields the same elements, while verifying that
+/// every complete pass yields the same sequence as the first complete pass.
+///
+/// The first complete pass records a [`Fingerprint`] of the sequence. Every later complete pass computes its
+/// own fingerprint and calls the `on_mismatch` handler with a [`MultipassMismatch`] report if they differ.
+/// Passes which are not iterated until the end are not checked.
+///
+/// A clone starts with a fresh state; i.e., it records its own first fingerprint and completed passes
+/// independently of the original.
+pub struct CheckedMultipass<I, F>
+where
+    I: Iterable,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) on_mismatch: F,
+    state: Rc<RefCell<MultipassState>>,
+}
+
+impl<I, F> Clone for CheckedMultipass<I, F>
+where
+    I: Iterable + Clone,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            on_mismatch: self.on_mismatch,
+            state: Default::default(),
+        }
+    }
+}
+
+impl<I, F> core::fmt::Debug for CheckedMultipass<I, F>
+where
+    I: Iterable + core::fmt::Debug,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheckedMultipass")
+            .field("it", &self.it)
+            .field("on_mismatch", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, F> CheckedMultipass<I, F>
+where
+    I: Iterable,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    pub(crate) fn new(it: I, on_mismatch: F) -> Self {
+        Self {
+            it,
+            on_mismatch,
+            state: Default::default(),
+        }
+    }
+
+    /// Returns the fingerprint of the first complete pass; None if no pass has been completed yet.
+    pub fn fingerprint(&self) -> Option<Fingerprint> {
+        self.state.borrow().first
+    }
+
+    /// Returns the number of passes which have been iterated until the end.
+    pub fn completed_passes(&self) -> usize {
+        self.state.borrow().completed_passes
+    }
+}
+
+impl<I, F> Iterable for CheckedMultipass<I, F>
+where
+    I: Iterable,
+    I::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    type Item = I::Item;
+
+    type Iter = CheckedMultipassIter<I::Iter, F>;
+
+    fn iter(&self) -> Self::Iter {
+        CheckedMultipassIter {
+            iter: self.it.iter(),
+            on_mismatch: self.on_mismatch,
+            state: Some(self.state.clone()),
+            hasher: DefaultHasher::new(),
+            len: 0,
+        }
+    }
+}
+
+/// Iterator computing the fingerprint of the sequence it yields and checking it against the first
+/// complete pass once it is exhausted.
+pub struct CheckedMultipassIter<It, F>
+where
+    It: Iterator,
+    It::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    iter: It,
+    on_mismatch: F,
+    state: Option<Rc<RefCell<MultipassState>>>,
+    hasher: DefaultHasher,
+    len: usize,
+}
+
+impl<It, F> CheckedMultipassIter<It, F>
+where
+    It: Iterator,
+    It::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    fn complete_pass(&mut self) {
+        if let Some(state) = self.state.take() {
+            let actual = Fingerprint {
+                len: self.len,
+                hash: self.hasher.finish(),
+            };
+
+            let mismatch = {
+                let mut state = state.borrow_mut();
+                let pass = state.completed_passes;
+                state.completed_passes += 1;
+                match state.first {
+                    None => {
+                        state.first = Some(actual);
+                        None
+                    }
+                    Some(expected) => (expected != actual).then_some(MultipassMismatch {
+                        pass,
+                        expected,
+                        actual,
+                    }),
+                }
+            };
+
+            if let Some(mismatch) = mismatch {
+                (self.on_mismatch)(&mismatch);
+            }
+        }
+    }
+}
+
+impl<It, F> Iterator for CheckedMultipassIter<It, F>
+where
+    It: Iterator,
+    It::Item: Hash,
+    F: Fn(&MultipassMismatch) + Copy,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            Some(x) => {
+                if self.state.is_some() {
+                    x.hash(&mut self.hasher);
+                    self.len += 1;
+                }
+                Some(x)
+            }
+            None => {
+                self.complete_pass();
+                None
+            }
+        }
+    }
+}
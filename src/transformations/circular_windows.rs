@@ -0,0 +1,165 @@
+use crate::Iterable;
+
+/// An iterable borrowing an `Iterable` as its source and yielding one [`CircularWindow`] starting at
+/// each element of the source; windows wrap around to the beginning of the source when they reach its end.
+///
+/// Created by the [`circular_windows`] method of `Iterable`.
+///
+/// [`circular_windows`]: crate::Iterable::circular_windows
+#[derive(Debug, PartialEq)]
+pub struct CircularWindows<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) size: usize,
+}
+
+impl<I> Clone for CircularWindows<'_, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for CircularWindows<'_, I> where I: Iterable {}
+
+impl<'a, I> Iterable for CircularWindows<'a, I>
+where
+    I: Iterable,
+{
+    type Item = CircularWindow<'a, I>;
+
+    type Iter = CircularWindowsIter<'a, I>;
+
+    fn iter(&self) -> Self::Iter {
+        CircularWindowsIter {
+            it: self.it,
+            iter: self.it.iter(),
+            size: self.size,
+            start: 0,
+        }
+    }
+}
+
+/// Iterator yielding the windows of a [`CircularWindows`] iterable.
+pub struct CircularWindowsIter<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) iter: I::Iter,
+    pub(crate) size: usize,
+    pub(crate) start: usize,
+}
+
+impl<'a, I> Iterator for CircularWindowsIter<'a, I>
+where
+    I: Iterable,
+{
+    type Item = CircularWindow<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()?;
+        let window = CircularWindow {
+            it: self.it,
+            start: self.start,
+            size: self.size,
+        };
+        self.start += 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A window of a [`CircularWindows`] iterable which yields `size` consecutive elements of the source
+/// starting at position `start`, wrapping around to the beginning of the source when its end is reached.
+#[derive(Debug, PartialEq)]
+pub struct CircularWindow<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) start: usize,
+    pub(crate) size: usize,
+}
+
+impl<I> Clone for CircularWindow<'_, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for CircularWindow<'_, I> where I: Iterable {}
+
+impl<I> CircularWindow<'_, I>
+where
+    I: Iterable,
+{
+    /// Returns the position of the first element of the window in the source.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+}
+
+impl<'a, I> Iterable for CircularWindow<'a, I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    type Iter = CircularWindowIter<'a, I>;
+
+    fn iter(&self) -> Self::Iter {
+        let mut iter = self.it.iter();
+        if self.start > 0 {
+            _ = iter.nth(self.start - 1);
+        }
+        CircularWindowIter {
+            it: self.it,
+            iter,
+            remaining: self.size,
+        }
+    }
+}
+
+/// Iterator over the elements of a [`CircularWindow`].
+pub struct CircularWindowIter<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) iter: I::Iter,
+    pub(crate) remaining: usize,
+}
+
+impl<I> Iterator for CircularWindowIter<'_, I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let next = match self.iter.next() {
+            Some(x) => x,
+            None => {
+                self.iter = self.it.iter();
+                self.iter.next()?
+            }
+        };
+        self.remaining -= 1;
+        Some(next)
+    }
+}
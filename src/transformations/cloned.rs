@@ -10,6 +10,45 @@ where
     pub(crate) it: I,
 }
 
+impl<'a, T, I> Clone for Cloned<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + Clone,
+    T: Clone + 'a,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+        }
+    }
+}
+
+impl<'a, T, I> Copy for Cloned<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + Copy,
+    T: Clone + 'a,
+{
+}
+
+impl<'a, T, I> core::fmt::Debug for Cloned<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + core::fmt::Debug,
+    T: Clone + 'a,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Cloned").field("it", &self.it).finish()
+    }
+}
+
+impl<'a, T, I> PartialEq for Cloned<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + PartialEq,
+    T: Clone + 'a,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
 impl<'a, T, I> Iterable for Cloned<'a, T, I>
 where
     I: Iterable<Item = &'a T>,
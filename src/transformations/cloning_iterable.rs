@@ -23,6 +23,7 @@ use crate::Iterable;
 /// assert_eq!(Some(&2), evens.iter().min());
 /// assert_eq!(Some(&10), evens.iter().max());
 /// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct CloningIterable<I>(I)
 where
     I: Iterator + Clone;
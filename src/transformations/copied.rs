@@ -10,6 +10,45 @@ where
     pub(crate) it: I,
 }
 
+impl<'a, T, I> Clone for Copied<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + Clone,
+    T: Copy + 'a,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+        }
+    }
+}
+
+impl<'a, T, I> Copy for Copied<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + Copy,
+    T: Copy + 'a,
+{
+}
+
+impl<'a, T, I> core::fmt::Debug for Copied<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + core::fmt::Debug,
+    T: Copy + 'a,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Copied").field("it", &self.it).finish()
+    }
+}
+
+impl<'a, T, I> PartialEq for Copied<'a, T, I>
+where
+    I: Iterable<Item = &'a T> + PartialEq,
+    T: Copy + 'a,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
 impl<'a, T, I> Iterable for Copied<'a, T, I>
 where
     I: Iterable<Item = &'a T>,
@@ -0,0 +1,250 @@
+use crate::{debug_fn::DebugFn, Collection, CollectionMut, Iterable};
+use core::{borrow::Borrow, marker::PhantomData};
+use orx_self_or::SoM;
+
+/// Wraps an `Iterable` and creates a new `Iterable` which skips consecutive elements of the
+/// original iterable that are equal to each other with respect to an equality function.
+pub struct DedupedBy<I, E>
+where
+    I: Iterable,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) eq: E,
+}
+
+impl<I, E> Clone for DedupedBy<I, E>
+where
+    I: Iterable + Clone,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            eq: self.eq,
+        }
+    }
+}
+
+impl<I, E> Copy for DedupedBy<I, E>
+where
+    I: Iterable + Copy,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for DedupedBy<I, E>
+where
+    I: Iterable + core::fmt::Debug,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DedupedBy")
+            .field("it", &self.it)
+            .field("eq", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for DedupedBy<I, E>
+where
+    I: Iterable + PartialEq,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.eq == other.eq
+    }
+}
+
+impl<I, E> Iterable for DedupedBy<I, E>
+where
+    I: Iterable,
+    E: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    type Iter = DedupedByIter<I::Item, I::Iter, E>;
+
+    fn iter(&self) -> Self::Iter {
+        DedupedByIter::new(self.it.iter(), self.eq)
+    }
+}
+
+/// Wraps an `Iterable` and creates a new `Iterable` which skips consecutive duplicate elements
+/// of the original iterable.
+pub type Deduped<I> = DedupedBy<I, fn(&<I as Iterable>::Item, &<I as Iterable>::Item) -> bool>;
+
+// col
+
+/// Wraps an `Collection` and creates a new `Collection` which skips consecutive elements of the
+/// original collection that are equal to each other with respect to an equality function.
+pub struct DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    pub(crate) it: E,
+    pub(crate) eq: Q,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E, Q> Clone for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            eq: self.eq,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E, Q> Copy for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+}
+
+impl<I, E, Q> core::fmt::Debug for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DedupedByCol")
+            .field("it", &self.it)
+            .field("eq", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E, Q> PartialEq for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.eq == other.eq
+    }
+}
+
+impl<'a, I, E, Q> Iterable for &'a DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = &'a I::Item;
+
+    type Iter = DedupedByIter<I::Item, <I::Iterable<'a> as Iterable>::Iter, Q>;
+
+    fn iter(&self) -> Self::Iter {
+        DedupedByIter::new(self.it.get_ref().iter(), self.eq)
+    }
+}
+
+impl<I, E, Q> Collection for DedupedByCol<I, E, Q>
+where
+    I: Collection,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<I, E, Q> CollectionMut for DedupedByCol<I, E, Q>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+    Q: Fn(&I::Item, &I::Item) -> bool + Copy,
+{
+    type IterMut<'i>
+        = DedupedByIter<I::Item, I::IterMut<'i>, Q>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        DedupedByIter::new(self.it.get_mut().iter_mut(), self.eq)
+    }
+}
+
+/// Wraps an `Collection` and creates a new `Collection` which skips consecutive duplicate elements
+/// of the original collection.
+pub type DedupedCol<I, E> =
+    DedupedByCol<I, E, fn(&<I as Collection>::Item, &<I as Collection>::Item) -> bool>;
+
+// iter
+
+/// Iterator skipping consecutive elements which are equal to each other with respect to an equality function.
+///
+/// It yields the first element of every run of equal elements.
+pub struct DedupedByIter<T, It, E>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    E: Fn(&T, &T) -> bool,
+{
+    pub(crate) iter: core::iter::Fuse<It>,
+    pub(crate) next: Option<It::Item>,
+    pub(crate) eq: E,
+    pub(crate) phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, It, E> DedupedByIter<T, It, E>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    E: Fn(&T, &T) -> bool,
+{
+    pub(crate) fn new(iter: It, eq: E) -> Self {
+        Self {
+            iter: iter.fuse(),
+            next: None,
+            eq,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, It, E> Iterator for DedupedByIter<T, It, E>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    E: Fn(&T, &T) -> bool,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = match self.next.take() {
+            Some(x) => x,
+            None => self.iter.next()?,
+        };
+
+        for x in self.iter.by_ref() {
+            if !(self.eq)(current.borrow(), x.borrow()) {
+                self.next = Some(x);
+                break;
+            }
+        }
+
+        Some(current)
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{debug_fn::DebugFn, Collection, CollectionMut, Iterable};
+use core::{borrow::Borrow, marker::PhantomData};
+use orx_self_or::SoM;
+
+/// Wraps an `Iterable` and creates a new `Iterable` which skips consecutive elements of the
+/// original iterable that map to the same key.
+pub struct DedupedByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    pub(crate) it: I,
+    pub(crate) key: F,
+}
+
+impl<I, F, K> Clone for DedupedByKey<I, F, K>
+where
+    I: Iterable + Clone,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            key: self.key,
+        }
+    }
+}
+
+impl<I, F, K> Copy for DedupedByKey<I, F, K>
+where
+    I: Iterable + Copy,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+}
+
+impl<I, F, K> core::fmt::Debug for DedupedByKey<I, F, K>
+where
+    I: Iterable + core::fmt::Debug,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DedupedByKey")
+            .field("it", &self.it)
+            .field("key", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, F, K> PartialEq for DedupedByKey<I, F, K>
+where
+    I: Iterable + PartialEq,
+    F: Fn(&I::Item) -> K + Copy + PartialEq,
+    K: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.key == other.key
+    }
+}
+
+impl<I, F, K> Iterable for DedupedByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    type Iter = DedupedByKeyIter<I::Item, I::Iter, F, K>;
+
+    fn iter(&self) -> Self::Iter {
+        DedupedByKeyIter::new(self.it.iter(), self.key)
+    }
+}
+
+// col
+
+/// Wraps an `Collection` and creates a new `Collection` which skips consecutive elements of the
+/// original collection that map to the same key.
+pub struct DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    pub(crate) it: E,
+    pub(crate) key: F,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E, F, K> Clone for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            key: self.key,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E, F, K> Copy for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+}
+
+impl<I, E, F, K> core::fmt::Debug for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DedupedByKeyCol")
+            .field("it", &self.it)
+            .field("key", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E, F, K> PartialEq for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    F: Fn(&I::Item) -> K + Copy + PartialEq,
+    K: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.key == other.key
+    }
+}
+
+impl<'a, I, E, F, K> Iterable for &'a DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = &'a I::Item;
+
+    type Iter = DedupedByKeyIter<I::Item, <I::Iterable<'a> as Iterable>::Iter, F, K>;
+
+    fn iter(&self) -> Self::Iter {
+        DedupedByKeyIter::new(self.it.get_ref().iter(), self.key)
+    }
+}
+
+impl<I, E, F, K> Collection for DedupedByKeyCol<I, E, F, K>
+where
+    I: Collection,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<I, E, F, K> CollectionMut for DedupedByKeyCol<I, E, F, K>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+    F: Fn(&I::Item) -> K + Copy,
+    K: PartialEq,
+{
+    type IterMut<'i>
+        = DedupedByKeyIter<I::Item, I::IterMut<'i>, F, K>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        DedupedByKeyIter::new(self.it.get_mut().iter_mut(), self.key)
+    }
+}
+
+// iter
+
+/// Iterator skipping consecutive elements which map to the same key.
+///
+/// It yields the first element of every run of elements with equal keys.
+pub struct DedupedByKeyIter<T, It, F, K>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    F: Fn(&T) -> K,
+    K: PartialEq,
+{
+    pub(crate) iter: core::iter::Fuse<It>,
+    pub(crate) next: Option<(It::Item, K)>,
+    pub(crate) key: F,
+    pub(crate) phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, It, F, K> DedupedByKeyIter<T, It, F, K>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    F: Fn(&T) -> K,
+    K: PartialEq,
+{
+    pub(crate) fn new(iter: It, key: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            next: None,
+            key,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, It, F, K> Iterator for DedupedByKeyIter<T, It, F, K>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    F: Fn(&T) -> K,
+    K: PartialEq,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (current, key) = match self.next.take() {
+            Some(x) => x,
+            None => {
+                let x = self.iter.next()?;
+                let key = (self.key)(x.borrow());
+                (x, key)
+            }
+        };
+
+        for x in self.iter.by_ref() {
+            let next_key = (self.key)(x.borrow());
+            if next_key != key {
+                self.next = Some((x, next_key));
+                break;
+            }
+        }
+
+        Some(current)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{Collection, CollectionMut, Iterable};
+use core::ops::{Deref, DerefMut};
+use std::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    rc::Rc,
+    sync::Arc,
+};
+
+/// A collection behind a smart pointer such as `Rc<X>`, `Arc<X>`, `Box<X>` or `Cow<'_, X>`.
+///
+/// `DerefCol<P>` is a `Collection` whenever the pointee `X` is a `Collection` such as `Vec<T>` or `VecDeque<T>`,
+/// or a slice `[T]`. Further, `DerefCol<Box<X>>` is a `CollectionMut` whenever `X` is a `CollectionMut` or a slice.
+///
+/// `DerefCol` implements `Collection`, and `&DerefCol` implements `Iterable`; therefore, shared immutable data
+/// can be iterated over and transformed without cloning the underlying buffer. Further, since `DerefCol` owns
+/// the pointer, consuming collection transformations such as [`into_filtered`] can hold a shared source.
+///
+/// It is created by the [`into_col`] method.
+///
+/// # Why a wrapper
+///
+/// `Collection` and `CollectionMut` are implemented for every `X` such that `X: IntoIterator` and
+/// `&X: IntoIterator` yield owned and referenced elements, respectively. A direct implementation such as
+/// `impl<T> Collection for Rc<Vec<T>>` would overlap with these blanket implementations: the standard library
+/// may add `IntoIterator` implementations for `Rc<Vec<T>>` or `&Rc<Vec<T>>` in the future, and hence, the
+/// compiler rejects it due to coherence rules (E0119). This holds for `Arc`, `Box` and `Cow` as well.
+/// The local `DerefCol` wrapper avoids the overlap, which is why the pointer must be wrapped by [`into_col`].
+///
+/// Note that pointers which already satisfy the blanket implementations do not need the wrapper; for instance,
+/// `Box<[T]>` is itself a `Collection` and a `CollectionMut`.
+///
+/// [`into_filtered`]: crate::Collection::into_filtered
+/// [`into_col`]: crate::IntoDerefCol::into_col
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+/// use std::rc::Rc;
+///
+/// struct Stats {
+///     numbers: DerefCol<Rc<[i32]>>,
+/// }
+///
+/// fn sum(numbers: &impl Collection<Item = i32>) -> i32 {
+///     numbers.iter().sum()
+/// }
+///
+/// let numbers: Rc<[i32]> = Rc::from(vec![1, 2, 3, 4]);
+/// let stats = Stats { numbers: numbers.clone().into_col() };
+///
+/// assert_eq!(sum(&stats.numbers), 10);
+/// assert_eq!(stats.numbers.iter().count(), 4);
+///
+/// let evens = numbers.into_col().into_filtered(|x| x % 2 == 0);
+/// assert_eq!(evens.iter().collect::<Vec<_>>(), [&2, &4]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DerefCol<P>(pub(crate) P)
+where
+    P: Deref;
+
+impl<P> DerefCol<P>
+where
+    P: Deref,
+{
+    /// Returns a reference to the wrapped pointer.
+    pub fn pointer(&self) -> &P {
+        &self.0
+    }
+
+    /// Consumes the collection and returns the wrapped pointer.
+    pub fn into_pointer(self) -> P {
+        self.0
+    }
+}
+
+impl<'a, P> Iterable for &'a DerefCol<P>
+where
+    P: Deref,
+    &'a P::Target: IntoIterator,
+{
+    type Item = <&'a P::Target as IntoIterator>::Item;
+
+    type Iter = <&'a P::Target as IntoIterator>::IntoIter;
+
+    fn iter(&self) -> Self::Iter {
+        self.0.deref().into_iter()
+    }
+}
+
+macro_rules! impl_collection {
+    ([$($lt:lifetime)?] $pointer:ident $(, $bound:path)?) => {
+        impl<$($lt,)? X> Collection for DerefCol<$pointer<$($lt,)? X>>
+        where
+            X: Collection $(+ $bound)?,
+        {
+            type Item = X::Item;
+
+            type Iterable<'i>
+                = X::Iterable<'i>
+            where
+                Self: 'i;
+
+            fn as_iterable(&self) -> Self::Iterable<'_> {
+                self.0.deref().as_iterable()
+            }
+        }
+
+        impl<$($lt,)? T> Collection for DerefCol<$pointer<$($lt,)? [T]>>
+        where
+            $([T]: $bound,)?
+        {
+            type Item = T;
+
+            type Iterable<'i>
+                = &'i [T]
+            where
+                Self: 'i;
+
+            fn as_iterable(&self) -> Self::Iterable<'_> {
+                self.0.deref()
+            }
+        }
+    };
+}
+
+impl_collection!([] Rc);
+impl_collection!([] Arc);
+impl_collection!([] Box);
+impl_collection!(['a] Cow, ToOwned);
+
+impl<X> CollectionMut for DerefCol<Box<X>>
+where
+    X: CollectionMut,
+{
+    type IterMut<'i>
+        = X::IterMut<'i>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.0.deref_mut().iter_mut()
+    }
+}
+
+impl<T> CollectionMut for DerefCol<Box<[T]>> {
+    type IterMut<'i>
+        = core::slice::IterMut<'i, T>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        self.0.iter_mut()
+    }
+}
+
+/// Trait to wrap smart pointers, namely `Rc<X>`, `Arc<X>`, `Box<X>` and `Cow<'_, X>`, into a [`DerefCol`],
+/// which is a `Collection` whenever `X` is a collection or a slice.
+///
+/// The wrapper is required since implementing `Collection` directly for these pointers would conflict with the
+/// blanket implementation of `Collection`; see [`DerefCol`] for details.
+pub trait IntoDerefCol: Deref + Sized {
+    /// Wraps this pointer into a [`DerefCol`], which is a `Collection` whenever the pointee is a
+    /// collection or a slice.
+    fn into_col(self) -> DerefCol<Self> {
+        DerefCol(self)
+    }
+}
+
+impl<X: ?Sized> IntoDerefCol for Rc<X> {}
+
+impl<X: ?Sized> IntoDerefCol for Arc<X> {}
+
+impl<X: ?Sized> IntoDerefCol for Box<X> {}
+
+impl<X> IntoDerefCol for Cow<'_, X> where X: ToOwned + ?Sized {}
@@ -0,0 +1,171 @@
+use crate::Iterable;
+use core::fmt::{Debug, Display, Formatter, Result};
+
+/// Wraps an `Iterable` and implements `Display` by writing its elements joined by a separator.
+///
+/// A new iterator is created every time the wrapper is formatted; no allocation is performed.
+/// The formatting options, such as width or precision, are applied to each element.
+///
+/// Created by the [`displayed`] method of `Iterable`.
+///
+/// [`displayed`]: crate::Iterable::displayed
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let a = vec![1.0, 2.5, 3.25, 4.0];
+///
+/// let it = a.displayed(", ");
+/// assert_eq!(format!("{}", it), "1, 2.5, 3.25, 4");
+/// assert_eq!(format!("{:.1}", it), "1.0, 2.5, 3.2, 4.0");
+///
+/// let it = a.displayed(" | ").prefix("<").suffix(">").truncated(2);
+/// assert_eq!(format!("{}", it), "<1 | 2.5 | ...>");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Displayed<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+    pub(crate) separator: &'a str,
+    pub(crate) prefix: &'a str,
+    pub(crate) suffix: &'a str,
+    pub(crate) max_items: Option<usize>,
+}
+
+impl<'a, I> Displayed<'a, I>
+where
+    I: Iterable,
+{
+    /// Sets the text to be written before the elements.
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the text to be written after the elements.
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Limits the output to the first `max_items` elements.
+    ///
+    /// When the iterable has more elements, the remaining elements are replaced by `...`.
+    pub fn truncated(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+impl<I> Display for Displayed<'_, I>
+where
+    I: Iterable,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.prefix)?;
+        for (i, x) in self.it.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            match self.max_items {
+                Some(max_items) if i == max_items => {
+                    f.write_str("...")?;
+                    break;
+                }
+                _ => x.fmt(f)?,
+            }
+        }
+        f.write_str(self.suffix)
+    }
+}
+
+/// Wraps an `Iterable` and implements `Debug` by writing its elements as a list.
+///
+/// A new iterator is created every time the wrapper is formatted; no allocation is performed.
+/// Both the regular `{:?}` and the alternate `{:#?}` formats are supported.
+/// The list is always enclosed in brackets; optional prefix and suffix texts are written outside of them.
+///
+/// Created by the [`debug_listed`] method of `Iterable`.
+///
+/// [`debug_listed`]: crate::Iterable::debug_listed
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let a = vec!["x", "y", "z"];
+///
+/// let it = a.debug_listed();
+/// assert_eq!(format!("{:?}", it), r#"["x", "y", "z"]"#);
+///
+/// let it = a.debug_listed().truncated(1);
+/// assert_eq!(format!("{:?}", it), r#"["x", ..]"#);
+///
+/// let it = a.debug_listed().prefix("Letters").suffix("!");
+/// assert_eq!(format!("{:?}", it), r#"Letters["x", "y", "z"]!"#);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct DebugListed<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+    pub(crate) prefix: &'a str,
+    pub(crate) suffix: &'a str,
+    pub(crate) max_items: Option<usize>,
+}
+
+impl<'a, I> DebugListed<'a, I>
+where
+    I: Iterable,
+{
+    /// Sets the text to be written before the opening bracket of the list.
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Sets the text to be written after the closing bracket of the list.
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    /// Limits the output to the first `max_items` elements.
+    ///
+    /// When the iterable has more elements, the list is closed as non-exhaustive, such as `[1, 2, ..]`.
+    pub fn truncated(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+impl<I> Debug for DebugListed<'_, I>
+where
+    I: Iterable,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.prefix)?;
+        let mut list = f.debug_list();
+        let mut truncated = false;
+        for (i, x) in self.it.iter().enumerate() {
+            if self.max_items == Some(i) {
+                truncated = true;
+                break;
+            }
+            list.entry(&x);
+        }
+        match truncated {
+            true => list.finish_non_exhaustive()?,
+            false => list.finish()?,
+        }
+        f.write_str(self.suffix)
+    }
+}
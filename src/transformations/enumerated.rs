@@ -2,6 +2,7 @@ use crate::Iterable;
 
 /// Wraps an `Iterable` and creates a new `Iterable` which yields the element indices
 /// together with the elements.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Enumerated<I>
 where
     I: Iterable,
@@ -21,3 +22,78 @@ where
         self.it.iter().enumerate()
     }
 }
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields the elements together with
+/// indices starting at `start` and increasing by `step`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnumeratedFrom<I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+    pub(crate) start: usize,
+    pub(crate) step: usize,
+}
+
+impl<I> Iterable for EnumeratedFrom<I>
+where
+    I: Iterable,
+{
+    type Item = (usize, I::Item);
+
+    type Iter = EnumeratedFromIter<I::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        EnumeratedFromIter::new(self.it.iter(), self.start, self.step)
+    }
+}
+
+/// Iterator yielding the elements of the wrapped iterator together with indices starting at
+/// `start` and increasing by `step`.
+///
+/// The iterator panics if the index of an element overflows `usize`.
+pub struct EnumeratedFromIter<It>
+where
+    It: Iterator,
+{
+    pub(crate) iter: It,
+    pub(crate) index: usize,
+    pub(crate) step: usize,
+    pub(crate) overflowed: bool,
+}
+
+impl<It> EnumeratedFromIter<It>
+where
+    It: Iterator,
+{
+    pub(crate) fn new(iter: It, start: usize, step: usize) -> Self {
+        Self {
+            iter,
+            index: start,
+            step,
+            overflowed: false,
+        }
+    }
+}
+
+impl<It> Iterator for EnumeratedFromIter<It>
+where
+    It: Iterator,
+{
+    type Item = (usize, It::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        assert!(
+            !self.overflowed,
+            "index of the enumerated element overflows usize"
+        );
+        let index = self.index;
+        (self.index, self.overflowed) = index.overflowing_add(self.step);
+        Some((index, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
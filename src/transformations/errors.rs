@@ -0,0 +1,24 @@
+use crate::{Iterable, TryIterable};
+
+/// Wraps a `TryIterable` and creates a new `Iterable` which yields the errors of the `Err` elements
+/// of the original iterable, skipping the values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Errors<I>
+where
+    I: TryIterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for Errors<I>
+where
+    I: TryIterable,
+{
+    type Item = I::Error;
+
+    type Iter = core::iter::FilterMap<I::Iter, fn(I::Item) -> Option<I::Error>>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().filter_map(Result::err)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::FallibleIterable;
+
+/// Wraps two `FallibleIterable`s and creates a new `FallibleIterable` which yields the elements of the first
+/// iterable followed by the elements of the second iterable.
+///
+/// Both iterators are created when `try_iter` is called; the error of the first one which fails is returned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleChained<I1, I2>
+where
+    I1: FallibleIterable,
+    I2: FallibleIterable<Item = I1::Item, Error = I1::Error>,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+}
+
+impl<I1, I2> FallibleIterable for FallibleChained<I1, I2>
+where
+    I1: FallibleIterable,
+    I2: FallibleIterable<Item = I1::Item, Error = I1::Error>,
+{
+    type Item = I1::Item;
+
+    type Error = I1::Error;
+
+    type Iter = core::iter::Chain<I1::Iter, I2::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        let iter1 = self.it1.try_iter()?;
+        let iter2 = self.it2.try_iter()?;
+        Ok(iter1.chain(iter2))
+    }
+}
@@ -0,0 +1,68 @@
+use crate::FallibleIterable;
+
+/// A fallible iterable whose iterators yield elements which are clones of references
+/// that a wrapped fallible iterable yields.
+pub struct FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T>,
+    T: Clone + 'a,
+{
+    pub(crate) it: I,
+}
+
+impl<'a, T, I> Clone for FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + Clone,
+    T: Clone + 'a,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+        }
+    }
+}
+
+impl<'a, T, I> Copy for FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + Copy,
+    T: Clone + 'a,
+{
+}
+
+impl<'a, T, I> core::fmt::Debug for FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + core::fmt::Debug,
+    T: Clone + 'a,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleCloned")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<'a, T, I> PartialEq for FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + PartialEq,
+    T: Clone + 'a,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
+impl<'a, T, I> FallibleIterable for FallibleCloned<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T>,
+    T: Clone + 'a,
+{
+    type Item = T;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Cloned<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.cloned())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::FallibleIterable;
+
+/// A fallible iterable whose iterators yield elements which are copies of references
+/// that a wrapped fallible iterable yields.
+pub struct FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T>,
+    T: Copy + 'a,
+{
+    pub(crate) it: I,
+}
+
+impl<'a, T, I> Clone for FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + Clone,
+    T: Copy + 'a,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+        }
+    }
+}
+
+impl<'a, T, I> Copy for FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + Copy,
+    T: Copy + 'a,
+{
+}
+
+impl<'a, T, I> core::fmt::Debug for FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + core::fmt::Debug,
+    T: Copy + 'a,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleCopied")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<'a, T, I> PartialEq for FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T> + PartialEq,
+    T: Copy + 'a,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
+impl<'a, T, I> FallibleIterable for FallibleCopied<'a, T, I>
+where
+    I: FallibleIterable<Item = &'a T>,
+    T: Copy + 'a,
+{
+    type Item = T;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Copied<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.copied())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::FallibleIterable;
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which yields the elements of
+/// the original iterable together with their indices.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleEnumerated<I>
+where
+    I: FallibleIterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> FallibleIterable for FallibleEnumerated<I>
+where
+    I: FallibleIterable,
+{
+    type Item = (usize, I::Item);
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Enumerate<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.enumerate())
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{debug_fn::DebugFn, FallibleIterable};
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which filters and maps the elements of
+/// the original iterable.
+pub struct FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) filter_map: M,
+}
+
+impl<I, M, U> Clone for FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable + Clone,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter_map: self.filter_map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable + Copy,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable + core::fmt::Debug,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleFilterMapped")
+            .field("it", &self.it)
+            .field("filter_map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable + PartialEq,
+    M: Fn(I::Item) -> Option<U> + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter_map == other.filter_map
+    }
+}
+
+impl<I, M, U> FallibleIterable for FallibleFilterMapped<I, M, U>
+where
+    I: FallibleIterable,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    type Item = U;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::FilterMap<I::Iter, M>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.filter_map(self.filter_map))
+    }
+}
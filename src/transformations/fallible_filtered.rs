@@ -0,0 +1,71 @@
+use crate::{debug_fn::DebugFn, FallibleIterable};
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which yields elements of
+/// the original iterable filtered by a predicate.
+pub struct FallibleFiltered<I, P>
+where
+    I: FallibleIterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) filter: P,
+}
+
+impl<I, P> Clone for FallibleFiltered<I, P>
+where
+    I: FallibleIterable + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter: self.filter,
+        }
+    }
+}
+
+impl<I, P> Copy for FallibleFiltered<I, P>
+where
+    I: FallibleIterable + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, P> core::fmt::Debug for FallibleFiltered<I, P>
+where
+    I: FallibleIterable + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleFiltered")
+            .field("it", &self.it)
+            .field("filter", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, P> PartialEq for FallibleFiltered<I, P>
+where
+    I: FallibleIterable + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter == other.filter
+    }
+}
+
+impl<I, P> FallibleIterable for FallibleFiltered<I, P>
+where
+    I: FallibleIterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Filter<I::Iter, P>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.filter(self.filter))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{debug_fn::DebugFn, FallibleIterable};
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which maps-and-flattens the elements
+/// of the original iterable.
+pub struct FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) flat_map: M,
+}
+
+impl<I, M, U> Clone for FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable + Clone,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            flat_map: self.flat_map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable + Copy,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable + core::fmt::Debug,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleFlatMapped")
+            .field("it", &self.it)
+            .field("flat_map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable + PartialEq,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.flat_map == other.flat_map
+    }
+}
+
+impl<I, M, U> FallibleIterable for FallibleFlatMapped<I, M, U>
+where
+    I: FallibleIterable,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    type Item = U::Item;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::FlatMap<I::Iter, U, M>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.flat_map(self.flat_map))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::FallibleIterable;
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which flattens the elements of
+/// the original iterable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleFlattened<I>
+where
+    I: FallibleIterable,
+    I::Item: IntoIterator,
+{
+    pub(crate) it: I,
+}
+
+impl<I> FallibleIterable for FallibleFlattened<I>
+where
+    I: FallibleIterable,
+    I::Item: IntoIterator,
+{
+    type Item = <I::Item as IntoIterator>::Item;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Flatten<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.flatten())
+    }
+}
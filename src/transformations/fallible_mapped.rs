@@ -0,0 +1,71 @@
+use crate::{debug_fn::DebugFn, FallibleIterable};
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which maps the elements of
+/// the original iterable.
+pub struct FallibleMapped<I, M, U>
+where
+    I: FallibleIterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) map: M,
+}
+
+impl<I, M, U> Clone for FallibleMapped<I, M, U>
+where
+    I: FallibleIterable + Clone,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            map: self.map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FallibleMapped<I, M, U>
+where
+    I: FallibleIterable + Copy,
+    M: Fn(I::Item) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FallibleMapped<I, M, U>
+where
+    I: FallibleIterable + core::fmt::Debug,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FallibleMapped")
+            .field("it", &self.it)
+            .field("map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FallibleMapped<I, M, U>
+where
+    I: FallibleIterable + PartialEq,
+    M: Fn(I::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.map == other.map
+    }
+}
+
+impl<I, M, U> FallibleIterable for FallibleMapped<I, M, U>
+where
+    I: FallibleIterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    type Item = U;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Map<I::Iter, M>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.map(self.map))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{FallibleIterable, Iterable};
+
+/// Wraps a `FallibleIterable` and creates an `Iterable` yielding `Result` elements: all elements
+/// wrapped in `Ok` if the iterator of the original iterable could be created, or a single `Err` otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleResults<I>
+where
+    I: FallibleIterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for FallibleResults<I>
+where
+    I: FallibleIterable,
+{
+    type Item = Result<I::Item, I::Error>;
+
+    type Iter = FallibleResultsIter<I::Iter, I::Error>;
+
+    fn iter(&self) -> Self::Iter {
+        match self.it.try_iter() {
+            Ok(iter) => FallibleResultsIter::Ok(iter),
+            Err(e) => FallibleResultsIter::Err(Some(e)),
+        }
+    }
+}
+
+/// Iterator yielding all elements of the underlying iterator wrapped in `Ok`; or a single `Err`
+/// if the underlying iterator could not be created.
+pub enum FallibleResultsIter<It, E>
+where
+    It: Iterator,
+{
+    /// Underlying iterator is successfully created.
+    Ok(It),
+    /// Underlying iterator could not be created; the error is yielded once.
+    Err(Option<E>),
+}
+
+impl<It, E> Iterator for FallibleResultsIter<It, E>
+where
+    It: Iterator,
+{
+    type Item = Result<It::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ok(iter) => iter.next().map(Ok),
+            Self::Err(e) => e.take().map(Err),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::FallibleIterable;
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which skips the first `n` elements
+/// of the original iterable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleSkipped<I>
+where
+    I: FallibleIterable,
+{
+    pub(crate) it: I,
+    pub(crate) n: usize,
+}
+
+impl<I> FallibleIterable for FallibleSkipped<I>
+where
+    I: FallibleIterable,
+{
+    type Item = I::Item;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Skip<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.skip(self.n))
+    }
+}
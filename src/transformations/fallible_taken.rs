@@ -0,0 +1,27 @@
+use crate::FallibleIterable;
+
+/// Wraps a `FallibleIterable` and creates a new `FallibleIterable` which yields only the first `n` elements
+/// of the original iterable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FallibleTaken<I>
+where
+    I: FallibleIterable,
+{
+    pub(crate) it: I,
+    pub(crate) n: usize,
+}
+
+impl<I> FallibleIterable for FallibleTaken<I>
+where
+    I: FallibleIterable,
+{
+    type Item = I::Item;
+
+    type Error = I::Error;
+
+    type Iter = core::iter::Take<I::Iter>;
+
+    fn try_iter(&self) -> Result<Self::Iter, Self::Error> {
+        self.it.try_iter().map(|x| x.take(self.n))
+    }
+}
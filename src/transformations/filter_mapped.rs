@@ -1,4 +1,4 @@
-use crate::Iterable;
+use crate::{debug_fn::DebugFn, Iterable};
 
 /// Wraps an `Iterable` and creates a new `Iterable` which filters-and-maps the elements
 /// of the original iterable.
@@ -11,6 +11,49 @@ where
     pub(crate) filter_map: M,
 }
 
+impl<I, M, U> Clone for FilterMapped<I, M, U>
+where
+    I: Iterable + Clone,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter_map: self.filter_map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FilterMapped<I, M, U>
+where
+    I: Iterable + Copy,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FilterMapped<I, M, U>
+where
+    I: Iterable + core::fmt::Debug,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FilterMapped")
+            .field("it", &self.it)
+            .field("filter_map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FilterMapped<I, M, U>
+where
+    I: Iterable + PartialEq,
+    M: Fn(I::Item) -> Option<U> + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter_map == other.filter_map
+    }
+}
+
 impl<I, M, U> Iterable for FilterMapped<I, M, U>
 where
     I: Iterable,
@@ -1,4 +1,4 @@
-use crate::{Collection, CollectionMut, Iterable};
+use crate::{debug_fn::DebugFn, Collection, CollectionMut, Iterable};
 use core::marker::PhantomData;
 use orx_self_or::SoM;
 
@@ -13,6 +13,49 @@ where
     pub(crate) filter: P,
 }
 
+impl<I, P> Clone for Filtered<I, P>
+where
+    I: Iterable + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter: self.filter,
+        }
+    }
+}
+
+impl<I, P> Copy for Filtered<I, P>
+where
+    I: Iterable + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, P> core::fmt::Debug for Filtered<I, P>
+where
+    I: Iterable + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Filtered")
+            .field("it", &self.it)
+            .field("filter", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, P> PartialEq for Filtered<I, P>
+where
+    I: Iterable + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter == other.filter
+    }
+}
+
 impl<I, P> Iterable for Filtered<I, P>
 where
     I: Iterable,
@@ -42,6 +85,54 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E, P> Clone for FilteredCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter: self.filter,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E, P> Copy for FilteredCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, E, P> core::fmt::Debug for FilteredCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FilteredCol")
+            .field("it", &self.it)
+            .field("filter", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E, P> PartialEq for FilteredCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter == other.filter
+    }
+}
+
 impl<'a, I, E, P> Iterable for &'a FilteredCol<I, E, P>
 where
     I: Collection,
@@ -1,4 +1,4 @@
-use crate::Iterable;
+use crate::{debug_fn::DebugFn, Iterable};
 
 /// Wraps an `Iterable` and creates a new `Iterable` which maps-and-flattens the elements
 /// of the original iterable.
@@ -12,6 +12,53 @@ where
     pub(crate) flat_map: M,
 }
 
+impl<I, M, U> Clone for FlatMapped<I, M, U>
+where
+    I: Iterable + Clone,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            flat_map: self.flat_map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FlatMapped<I, M, U>
+where
+    I: Iterable + Copy,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FlatMapped<I, M, U>
+where
+    I: Iterable + core::fmt::Debug,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlatMapped")
+            .field("it", &self.it)
+            .field("flat_map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FlatMapped<I, M, U>
+where
+    I: Iterable + PartialEq,
+    U: IntoIterator,
+    M: Fn(I::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.flat_map == other.flat_map
+    }
+}
+
 impl<I, M, U> Iterable for FlatMapped<I, M, U>
 where
     I: Iterable,
@@ -0,0 +1,115 @@
+use crate::{debug_fn::DebugFn, Iterable};
+
+/// Wraps an `Iterable` and creates a new `Iterable` which maps each element of the original
+/// iterable to an `Iterable` and flattens the elements of the mapped iterables.
+///
+/// Unlike [`FlatMapped`], the mapped values are not required to implement `IntoIterator`; the `iter`
+/// method of each mapped iterable is called to traverse its elements.
+///
+/// [`FlatMapped`]: crate::transformations::FlatMapped
+pub struct FlatMappedIterable<I, M, U>
+where
+    I: Iterable,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) flat_map: M,
+}
+
+impl<I, M, U> Clone for FlatMappedIterable<I, M, U>
+where
+    I: Iterable + Clone,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            flat_map: self.flat_map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for FlatMappedIterable<I, M, U>
+where
+    I: Iterable + Copy,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for FlatMappedIterable<I, M, U>
+where
+    I: Iterable + core::fmt::Debug,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlatMappedIterable")
+            .field("it", &self.it)
+            .field("flat_map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for FlatMappedIterable<I, M, U>
+where
+    I: Iterable + PartialEq,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.flat_map == other.flat_map
+    }
+}
+
+impl<I, M, U> Iterable for FlatMappedIterable<I, M, U>
+where
+    I: Iterable,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    type Item = U::Item;
+
+    type Iter = FlatMappedIterableIter<I, M, U>;
+
+    fn iter(&self) -> Self::Iter {
+        FlatMappedIterableIter {
+            iter1: self.it.iter(),
+            iter2: None,
+            flat_map: self.flat_map,
+        }
+    }
+}
+
+/// Flat mapped iterator for iterables mapping elements to iterables.
+pub struct FlatMappedIterableIter<I, M, U>
+where
+    I: Iterable,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    pub(crate) iter1: I::Iter,
+    pub(crate) iter2: Option<U::Iter>,
+    pub(crate) flat_map: M,
+}
+
+impl<I, M, U> Iterator for FlatMappedIterableIter<I, M, U>
+where
+    I: Iterable,
+    U: Iterable,
+    M: Fn(I::Item) -> U + Copy,
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.iter2.as_mut().and_then(|x| x.next()) {
+                return Some(x);
+            }
+            let iterable2: U = (self.flat_map)(self.iter1.next()?);
+            self.iter2 = Some(iterable2.iter());
+        }
+    }
+}
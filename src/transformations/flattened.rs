@@ -4,6 +4,7 @@ use orx_self_or::SoM;
 
 /// Wraps an `Iterable` and creates a new `Iterable` which flattens the elements of
 /// the original iterable filtered by a predicate.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Flattened<I>
 where
     I: Iterable,
@@ -41,6 +42,56 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E> Clone for FlattenedCol<I, E>
+where
+    I: Collection,
+    I::Item: IntoIterator,
+    for<'i> &'i I::Item: IntoIterator<Item = &'i <I::Item as IntoIterator>::Item>,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for FlattenedCol<I, E>
+where
+    I: Collection,
+    I::Item: IntoIterator,
+    for<'i> &'i I::Item: IntoIterator<Item = &'i <I::Item as IntoIterator>::Item>,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for FlattenedCol<I, E>
+where
+    I: Collection,
+    I::Item: IntoIterator,
+    for<'i> &'i I::Item: IntoIterator<Item = &'i <I::Item as IntoIterator>::Item>,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlattenedCol")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for FlattenedCol<I, E>
+where
+    I: Collection,
+    I::Item: IntoIterator,
+    for<'i> &'i I::Item: IntoIterator<Item = &'i <I::Item as IntoIterator>::Item>,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
 impl<'a, I, E> Iterable for &'a FlattenedCol<I, E>
 where
     I: Collection,
@@ -0,0 +1,237 @@
+use crate::{Collection, CollectionMut, Iterable};
+use core::marker::PhantomData;
+use orx_self_or::SoM;
+
+/// Wraps an `Iterable` whose elements are also `Iterable`s, and creates a new `Iterable` which
+/// flattens the elements of the inner iterables.
+///
+/// Unlike [`Flattened`], the elements are not required to implement `IntoIterator`; the `iter`
+/// method of each inner iterable is called to traverse its elements.
+///
+/// [`Flattened`]: crate::transformations::Flattened
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlattenedIterables<I>
+where
+    I: Iterable,
+    I::Item: Iterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for FlattenedIterables<I>
+where
+    I: Iterable,
+    I::Item: Iterable,
+{
+    type Item = <I::Item as Iterable>::Item;
+
+    type Iter = FlattenedIterablesIter<I>;
+
+    fn iter(&self) -> Self::Iter {
+        FlattenedIterablesIter {
+            outer: self.it.iter(),
+            inner: None,
+        }
+    }
+}
+
+/// Iterator over the elements of the inner iterables of a [`FlattenedIterables`].
+pub struct FlattenedIterablesIter<I>
+where
+    I: Iterable,
+    I::Item: Iterable,
+{
+    pub(crate) outer: I::Iter,
+    pub(crate) inner: Option<<I::Item as Iterable>::Iter>,
+}
+
+impl<I> Iterator for FlattenedIterablesIter<I>
+where
+    I: Iterable,
+    I::Item: Iterable,
+{
+    type Item = <I::Item as Iterable>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.inner.as_mut().and_then(|x| x.next()) {
+                return Some(x);
+            }
+            self.inner = Some(self.outer.next()?.iter());
+        }
+    }
+}
+
+// col
+
+/// Wraps a `Collection` whose elements are also `Collection`s, and creates a new `Collection`
+/// which flattens the elements of the inner collections.
+///
+/// Unlike [`FlattenedCol`], the elements are not required to implement `IntoIterator`; the `iter`
+/// and `iter_mut` methods of each inner collection are called to traverse its elements.
+///
+/// [`FlattenedCol`]: crate::transformations::FlattenedCol
+pub struct FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I>,
+{
+    pub(crate) it: E,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E> Clone for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FlattenedIterablesCol")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
+impl<'a, I, E> Iterable for &'a FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a <I::Item as Collection>::Item;
+
+    type Iter = FlattenedIterablesColIter<'a, I>;
+
+    fn iter(&self) -> Self::Iter {
+        FlattenedIterablesColIter {
+            outer: self.it.get_ref().iter(),
+            inner: None,
+        }
+    }
+}
+
+impl<I, E> Collection for FlattenedIterablesCol<I, E>
+where
+    I: Collection,
+    I::Item: Collection,
+    E: SoM<I>,
+{
+    type Item = <I::Item as Collection>::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<I, E> CollectionMut for FlattenedIterablesCol<I, E>
+where
+    I: CollectionMut,
+    I::Item: CollectionMut,
+    E: SoM<I>,
+{
+    type IterMut<'i>
+        = FlattenedIterablesColIterMut<'i, I>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        FlattenedIterablesColIterMut {
+            outer: self.it.get_mut().iter_mut(),
+            inner: None,
+        }
+    }
+}
+
+/// Iterator yielding references to the elements of the inner collections of a [`FlattenedIterablesCol`].
+pub struct FlattenedIterablesColIter<'a, I>
+where
+    I: Collection + 'a,
+    I::Item: Collection,
+{
+    pub(crate) outer: <I::Iterable<'a> as Iterable>::Iter,
+    pub(crate) inner: Option<<<I::Item as Collection>::Iterable<'a> as Iterable>::Iter>,
+}
+
+impl<'a, I> Iterator for FlattenedIterablesColIter<'a, I>
+where
+    I: Collection + 'a,
+    I::Item: Collection,
+{
+    type Item = &'a <I::Item as Collection>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.inner.as_mut().and_then(|x| x.next()) {
+                return Some(x);
+            }
+            self.inner = Some(self.outer.next()?.iter());
+        }
+    }
+}
+
+/// Iterator yielding mutable references to the elements of the inner collections of a [`FlattenedIterablesCol`].
+pub struct FlattenedIterablesColIterMut<'a, I>
+where
+    I: CollectionMut + 'a,
+    I::Item: CollectionMut,
+{
+    pub(crate) outer: I::IterMut<'a>,
+    pub(crate) inner: Option<<I::Item as CollectionMut>::IterMut<'a>>,
+}
+
+impl<'a, I> Iterator for FlattenedIterablesColIterMut<'a, I>
+where
+    I: CollectionMut + 'a,
+    I::Item: CollectionMut,
+{
+    type Item = &'a mut <I::Item as Collection>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(x) = self.inner.as_mut().and_then(|x| x.next()) {
+                return Some(x);
+            }
+            self.inner = Some(self.outer.next()?.iter_mut());
+        }
+    }
+}
@@ -3,6 +3,7 @@ use core::marker::PhantomData;
 use orx_self_or::SoM;
 
 /// Wraps an `Iterable` and transforms into a fused `Iterable`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Fused<I>
 where
     I: Iterable,
@@ -35,6 +36,46 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E> Clone for FusedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for FusedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for FusedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FusedCol").field("it", &self.it).finish()
+    }
+}
+
+impl<I, E> PartialEq for FusedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
 impl<'a, I, E> Iterable for &'a FusedCol<I, E>
 where
     I: Collection,
@@ -0,0 +1,309 @@
+use crate::{Collection, Iterable};
+use core::hash::Hash;
+use std::{collections::HashMap, vec::Vec};
+
+/// An index over a collection grouping positions of its elements by a key.
+///
+/// The index is built only once when created; afterwards, each group is exposed as
+/// a [`KeyGroup`] collection view over the original storage.
+///
+/// Together with the positions, the index stores references to the elements of each group;
+/// hence, iterating over a group does not walk over the elements of other groups.
+///
+/// Groups are ordered by the first appearance of their keys in the collection, and
+/// elements of each group preserve their order in the collection.
+pub struct GroupedByKey<'a, I, K>
+where
+    I: Collection,
+    K: Eq + Hash,
+{
+    pub(crate) col: &'a I,
+    pub(crate) groups: Vec<(K, Vec<usize>)>,
+    pub(crate) elements: Vec<Vec<&'a I::Item>>,
+    pub(crate) positions: HashMap<K, usize>,
+}
+
+impl<'a, I, K> Clone for GroupedByKey<'a, I, K>
+where
+    I: Collection,
+    K: Eq + Hash + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            col: self.col,
+            groups: self.groups.clone(),
+            elements: self.elements.clone(),
+            positions: self.positions.clone(),
+        }
+    }
+}
+
+impl<'a, I, K> core::fmt::Debug for GroupedByKey<'a, I, K>
+where
+    I: Collection + core::fmt::Debug,
+    K: Eq + Hash + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GroupedByKey")
+            .field("col", &self.col)
+            .field("groups", &self.groups)
+            .field("positions", &self.positions)
+            .finish()
+    }
+}
+
+impl<'a, I, K> PartialEq for GroupedByKey<'a, I, K>
+where
+    I: Collection + PartialEq,
+    K: Eq + Hash + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col && self.groups == other.groups && self.positions == other.positions
+    }
+}
+
+impl<'a, I, K> GroupedByKey<'a, I, K>
+where
+    I: Collection,
+    K: Eq + Hash + Clone,
+{
+    pub(crate) fn new<F>(col: &'a I, key: F) -> Self
+    where
+        F: Fn(&I::Item) -> K,
+    {
+        let mut groups: Vec<(K, Vec<usize>)> = Vec::new();
+        let mut elements: Vec<Vec<&'a I::Item>> = Vec::new();
+        let mut positions: HashMap<K, usize> = HashMap::new();
+
+        for (idx, x) in col.iter().enumerate() {
+            let key = key(x);
+            match positions.get(&key) {
+                Some(&position) => {
+                    groups[position].1.push(idx);
+                    elements[position].push(x);
+                }
+                None => {
+                    positions.insert(key.clone(), groups.len());
+                    groups.push((key, std::vec![idx]));
+                    elements.push(std::vec![x]);
+                }
+            }
+        }
+
+        Self {
+            col,
+            groups,
+            elements,
+            positions,
+        }
+    }
+}
+
+impl<I, K> GroupedByKey<'_, I, K>
+where
+    I: Collection,
+    K: Eq + Hash,
+{
+    /// Returns the number of groups; i.e., the number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if there are no groups, which is the case only when the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns an iterator over the distinct keys in order of their first appearance.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.groups.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over `(key, group)` pairs in order of first appearance of the keys.
+    ///
+    /// Note that `&GroupedByKey` is an `Iterable`; hence, the groups can be iterated over multiple times.
+    pub fn iter(&self) -> GroupedByKeyIter<'_, I, K> {
+        <&Self as Iterable>::iter(&self)
+    }
+
+    /// Returns the group of elements with the given `key`; None if there is no element with this key.
+    pub fn get(&self, key: &K) -> Option<KeyGroup<'_, I>> {
+        self.positions.get(key).map(|&position| KeyGroup {
+            col: self.col,
+            indices: &self.groups[position].1,
+            elements: &self.elements[position],
+        })
+    }
+}
+
+impl<'a, I, K> Iterable for &'a GroupedByKey<'_, I, K>
+where
+    I: Collection,
+    K: Eq + Hash,
+{
+    type Item = (&'a K, KeyGroup<'a, I>);
+
+    type Iter = GroupedByKeyIter<'a, I, K>;
+
+    fn iter(&self) -> Self::Iter {
+        GroupedByKeyIter {
+            col: self.col,
+            groups: self.groups.iter(),
+            elements: self.elements.iter(),
+        }
+    }
+}
+
+/// Iterator over the groups of a [`GroupedByKey`] index.
+pub struct GroupedByKeyIter<'a, I, K>
+where
+    I: Collection,
+{
+    pub(crate) col: &'a I,
+    pub(crate) groups: core::slice::Iter<'a, (K, Vec<usize>)>,
+    pub(crate) elements: core::slice::Iter<'a, Vec<&'a I::Item>>,
+}
+
+impl<'a, I, K> Iterator for GroupedByKeyIter<'a, I, K>
+where
+    I: Collection,
+{
+    type Item = (&'a K, KeyGroup<'a, I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, indices) = self.groups.next()?;
+        let elements = self.elements.next()?;
+        let group = KeyGroup {
+            col: self.col,
+            indices,
+            elements,
+        };
+        Some((key, group))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.groups.size_hint()
+    }
+}
+
+// group
+
+/// A group of elements of a collection sharing the same key.
+///
+/// The group does not store the elements; it is a collection view over the
+/// original storage which can be iterated over multiple times.
+pub struct KeyGroup<'a, I>
+where
+    I: Collection,
+{
+    pub(crate) col: &'a I,
+    pub(crate) indices: &'a [usize],
+    pub(crate) elements: &'a [&'a I::Item],
+}
+
+impl<'a, I> Clone for KeyGroup<'a, I>
+where
+    I: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> Copy for KeyGroup<'a, I> where I: Collection {}
+
+impl<'a, I> core::fmt::Debug for KeyGroup<'a, I>
+where
+    I: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyGroup")
+            .field("col", &self.col)
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+
+impl<'a, I> PartialEq for KeyGroup<'a, I>
+where
+    I: Collection + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col && self.indices == other.indices
+    }
+}
+
+impl<I> KeyGroup<'_, I>
+where
+    I: Collection,
+{
+    /// Returns the number of elements in the group.
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns true if the group is empty; note that groups created by grouping transformations are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Returns positions of elements of the group in the original collection.
+    pub fn indices(&self) -> &[usize] {
+        self.indices
+    }
+}
+
+impl<'i, I> Iterable for &'i KeyGroup<'_, I>
+where
+    I: Collection,
+{
+    type Item = &'i I::Item;
+
+    type Iter = KeyGroupIter<'i, I>;
+
+    fn iter(&self) -> Self::Iter {
+        let elements: &'i [&'i I::Item] = self.elements;
+        KeyGroupIter {
+            elements: elements.iter(),
+        }
+    }
+}
+
+impl<I> Collection for KeyGroup<'_, I>
+where
+    I: Collection,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+/// Iterator over elements of a [`KeyGroup`].
+pub struct KeyGroupIter<'a, I>
+where
+    I: Collection + 'a,
+{
+    pub(crate) elements: core::slice::Iter<'a, &'a I::Item>,
+}
+
+impl<'a, I> Iterator for KeyGroupIter<'a, I>
+where
+    I: Collection,
+{
+    type Item = &'a I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.elements.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.elements.size_hint()
+    }
+}
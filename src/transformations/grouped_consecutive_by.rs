@@ -0,0 +1,232 @@
+use crate::{debug_fn::DebugFn, Collection, Iterable};
+
+/// An iterable over a collection which yields groups of consecutive elements sharing the same key.
+///
+/// Each group is yielded as a `(key, group)` pair where the group is a [`ConsecutiveGroup`] view
+/// over the original collection. No allocation takes place while grouping.
+pub struct GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    pub(crate) col: &'a I,
+    pub(crate) key: F,
+}
+
+impl<'a, I, K, F> Clone for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I, K, F> Copy for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+}
+
+impl<'a, I, K, F> core::fmt::Debug for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection + core::fmt::Debug,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GroupedConsecutiveBy")
+            .field("col", &self.col)
+            .field("key", &DebugFn)
+            .finish()
+    }
+}
+
+impl<'a, I, K, F> PartialEq for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection + PartialEq,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col && self.key == other.key
+    }
+}
+
+impl<'a, I, K, F> Iterable for GroupedConsecutiveBy<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    type Item = (K, ConsecutiveGroup<'a, I>);
+
+    type Iter = GroupedConsecutiveByIter<'a, I, K, F>;
+
+    fn iter(&self) -> Self::Iter {
+        GroupedConsecutiveByIter {
+            col: self.col,
+            iter: self.col.iter().fuse(),
+            key: self.key,
+            next_key: None,
+            begin: 0,
+        }
+    }
+}
+
+/// Iterator over groups of consecutive elements sharing the same key.
+pub struct GroupedConsecutiveByIter<'a, I, K, F>
+where
+    I: Collection + 'a,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    pub(crate) col: &'a I,
+    pub(crate) iter: core::iter::Fuse<<I::Iterable<'a> as Iterable>::Iter>,
+    pub(crate) key: F,
+    pub(crate) next_key: Option<K>,
+    pub(crate) begin: usize,
+}
+
+impl<'a, I, K, F> Iterator for GroupedConsecutiveByIter<'a, I, K, F>
+where
+    I: Collection,
+    K: PartialEq,
+    F: Fn(&I::Item) -> K + Copy,
+{
+    type Item = (K, ConsecutiveGroup<'a, I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = match self.next_key.take() {
+            Some(key) => key,
+            None => (self.key)(self.iter.next()?),
+        };
+
+        let mut len = 1;
+        for x in self.iter.by_ref() {
+            let next_key = (self.key)(x);
+            match next_key == key {
+                true => len += 1,
+                false => {
+                    self.next_key = Some(next_key);
+                    break;
+                }
+            }
+        }
+
+        let begin = self.begin;
+        self.begin += len;
+
+        let group = ConsecutiveGroup {
+            col: self.col,
+            begin,
+            len,
+        };
+        Some((key, group))
+    }
+}
+
+// group
+
+/// A group of consecutive elements of a collection sharing the same key.
+///
+/// The group does not store the elements; it is a collection view over the
+/// original storage which can be iterated over multiple times.
+///
+/// The group iterates over its elements as a sub-slice of the slice-backed collection.
+pub struct ConsecutiveGroup<'a, I>
+where
+    I: Collection,
+{
+    pub(crate) col: &'a I,
+    pub(crate) begin: usize,
+    pub(crate) len: usize,
+}
+
+impl<'a, I> Clone for ConsecutiveGroup<'a, I>
+where
+    I: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> Copy for ConsecutiveGroup<'a, I> where I: Collection {}
+
+impl<'a, I> core::fmt::Debug for ConsecutiveGroup<'a, I>
+where
+    I: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ConsecutiveGroup")
+            .field("col", &self.col)
+            .field("begin", &self.begin)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a, I> PartialEq for ConsecutiveGroup<'a, I>
+where
+    I: Collection + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col && self.begin == other.begin && self.len == other.len
+    }
+}
+
+impl<I> ConsecutiveGroup<'_, I>
+where
+    I: Collection,
+{
+    /// Returns the number of elements in the group.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the group is empty; note that groups created by grouping transformations are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the position of the first element of the group in the original collection.
+    pub fn begin(&self) -> usize {
+        self.begin
+    }
+}
+
+impl<'i, I> Iterable for &'i ConsecutiveGroup<'_, I>
+where
+    I: Collection + AsRef<[<I as Collection>::Item]>,
+{
+    type Item = &'i I::Item;
+
+    type Iter = core::slice::Iter<'i, I::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        let col: &'i I = self.col;
+        col.as_ref()[self.begin..(self.begin + self.len)].iter()
+    }
+}
+
+impl<I> Collection for ConsecutiveGroup<'_, I>
+where
+    I: Collection + AsRef<[<I as Collection>::Item]>,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
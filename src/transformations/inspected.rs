@@ -0,0 +1,69 @@
+use crate::{debug_fn::DebugFn, Iterable};
+
+/// Wraps an `Iterable` and creates a new `Iterable` which calls a side-effect closure on each element
+/// of the original iterable before yielding it.
+pub struct Inspected<I, F>
+where
+    I: Iterable,
+    F: Fn(&I::Item) + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) inspect: F,
+}
+
+impl<I, F> Clone for Inspected<I, F>
+where
+    I: Iterable + Clone,
+    F: Fn(&I::Item) + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            inspect: self.inspect,
+        }
+    }
+}
+
+impl<I, F> Copy for Inspected<I, F>
+where
+    I: Iterable + Copy,
+    F: Fn(&I::Item) + Copy,
+{
+}
+
+impl<I, F> core::fmt::Debug for Inspected<I, F>
+where
+    I: Iterable + core::fmt::Debug,
+    F: Fn(&I::Item) + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Inspected")
+            .field("it", &self.it)
+            .field("inspect", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, F> PartialEq for Inspected<I, F>
+where
+    I: Iterable + PartialEq,
+    F: Fn(&I::Item) + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.inspect == other.inspect
+    }
+}
+
+impl<I, F> Iterable for Inspected<I, F>
+where
+    I: Iterable,
+    F: Fn(&I::Item) + Copy,
+{
+    type Item = I::Item;
+
+    type Iter = core::iter::Inspect<I::Iter, F>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().inspect(self.inspect)
+    }
+}
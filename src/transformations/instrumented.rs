@@ -0,0 +1,238 @@
+use crate::{Collection, CollectionMut, Iterable};
+use core::{cell::RefCell, marker::PhantomData};
+use orx_self_or::SoM;
+use std::{rc::Rc, vec::Vec};
+
+/// Shared counter recording the number of passes, i.e., calls to `iter` (or `iter_mut`), and the number
+/// of items pulled in each pass of an instrumented iterable.
+///
+/// It is a cheap handle to the shared counts; clones of it observe the same counts.
+#[derive(Clone, Debug, Default)]
+pub struct IterationStats {
+    items_per_pass: Rc<RefCell<Vec<usize>>>,
+}
+
+impl IterationStats {
+    /// Number of times an iterator of the instrumented iterable has been created.
+    pub fn passes(&self) -> usize {
+        self.items_per_pass.borrow().len()
+    }
+
+    /// Total number of items pulled over all passes.
+    pub fn total_items(&self) -> usize {
+        self.items_per_pass.borrow().iter().sum()
+    }
+
+    /// Number of items pulled in each pass, in the order that the passes were started.
+    pub fn items_per_pass(&self) -> Vec<usize> {
+        self.items_per_pass.borrow().clone()
+    }
+
+    /// Clears all recorded counts.
+    pub fn reset(&self) {
+        self.items_per_pass.borrow_mut().clear();
+    }
+
+    fn start_pass(&self) -> usize {
+        let mut items_per_pass = self.items_per_pass.borrow_mut();
+        items_per_pass.push(0);
+        items_per_pass.len() - 1
+    }
+
+    fn increment(&self, pass: usize) {
+        self.items_per_pass.borrow_mut()[pass] += 1;
+    }
+}
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields the elements of the original iterable
+/// while recording the passes and pulled items in an [`IterationStats`].
+///
+/// A clone starts with fresh, empty stats; i.e., passes over the clone are not recorded in the stats of
+/// the original, and vice versa.
+#[derive(Debug)]
+pub struct Instrumented<I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+    pub(crate) stats: IterationStats,
+}
+
+impl<I> Clone for Instrumented<I>
+where
+    I: Iterable + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            stats: Default::default(),
+        }
+    }
+}
+
+impl<I> Instrumented<I>
+where
+    I: Iterable,
+{
+    /// Returns a handle to the counts recorded by this iterable.
+    ///
+    /// The handle remains valid after this iterable is moved into further transformations or dropped.
+    pub fn stats(&self) -> IterationStats {
+        self.stats.clone()
+    }
+}
+
+impl<I> Iterable for Instrumented<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    type Iter = InstrumentedIter<I::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InstrumentedIter::new(self.it.iter(), &self.stats)
+    }
+}
+
+/// Iterator which counts the items it yields in its pass of an [`IterationStats`].
+pub struct InstrumentedIter<It>
+where
+    It: Iterator,
+{
+    iter: It,
+    stats: IterationStats,
+    pass: usize,
+}
+
+impl<It> InstrumentedIter<It>
+where
+    It: Iterator,
+{
+    fn new(iter: It, stats: &IterationStats) -> Self {
+        let pass = stats.start_pass();
+        Self {
+            iter,
+            stats: stats.clone(),
+            pass,
+        }
+    }
+}
+
+impl<It> Iterator for InstrumentedIter<It>
+where
+    It: Iterator,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter.next();
+        if x.is_some() {
+            self.stats.increment(self.pass);
+        }
+        x
+    }
+}
+
+// col
+
+/// Wraps an `Collection` and creates a new `Collection` which yields the elements of the original collection
+/// while recording the passes and pulled items in an [`IterationStats`].
+///
+/// A clone starts with fresh, empty stats; i.e., passes over the clone are not recorded in the stats of
+/// the original, and vice versa.
+pub struct InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    pub(crate) it: E,
+    pub(crate) stats: IterationStats,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E> Clone for InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            stats: Default::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> core::fmt::Debug for InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InstrumentedCol")
+            .field("it", &self.it)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+impl<I, E> InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    /// Returns a handle to the counts recorded by this collection.
+    ///
+    /// The handle remains valid after this collection is moved or dropped.
+    pub fn stats(&self) -> IterationStats {
+        self.stats.clone()
+    }
+}
+
+impl<'a, I, E> Iterable for &'a InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    type Iter = InstrumentedIter<<I::Iterable<'a> as Iterable>::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InstrumentedIter::new(self.it.get_ref().iter(), &self.stats)
+    }
+}
+
+impl<I, E> Collection for InstrumentedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+impl<I, E> CollectionMut for InstrumentedCol<I, E>
+where
+    I: CollectionMut,
+    E: SoM<I>,
+{
+    type IterMut<'i>
+        = InstrumentedIter<I::IterMut<'i>>
+    where
+        Self: 'i;
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        InstrumentedIter::new(self.it.get_mut().iter_mut(), &self.stats)
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Iterable;
+
+/// An iterable created by interleaving two iterables.
+///
+/// Its iterators alternate between the elements of the two iterables; once either of them
+/// is exhausted, remaining elements of the other are yielded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interleaved<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+}
+
+impl<I1, I2> Iterable for Interleaved<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    type Iter = InterleavedIter<I1::Iter, I2::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InterleavedIter {
+            iter1: self.it1.iter().fuse(),
+            iter2: self.it2.iter().fuse(),
+            second: false,
+        }
+    }
+}
+
+/// Iterator alternating between elements of two iterators until both are exhausted.
+pub struct InterleavedIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+{
+    pub(crate) iter1: core::iter::Fuse<I1>,
+    pub(crate) iter2: core::iter::Fuse<I2>,
+    pub(crate) second: bool,
+}
+
+impl<I1, I2> Iterator for InterleavedIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.second = !self.second;
+        match self.second {
+            true => self.iter1.next().or_else(|| self.iter2.next()),
+            false => self.iter2.next().or_else(|| self.iter1.next()),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.iter1.size_hint();
+        let (lower2, upper2) = self.iter2.size_hint();
+        let lower = lower1.saturating_add(lower2);
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+// shortest
+
+/// An iterable created by interleaving two iterables, stopping as soon as the iterable
+/// whose turn it is is exhausted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InterleavedShortest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+}
+
+impl<I1, I2> Iterable for InterleavedShortest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    type Iter = InterleavedShortestIter<I1::Iter, I2::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InterleavedShortestIter {
+            iter1: self.it1.iter().fuse(),
+            iter2: self.it2.iter().fuse(),
+            second: false,
+            exhausted: false,
+        }
+    }
+}
+
+/// Iterator alternating between elements of two iterators until the one whose turn it is is exhausted.
+pub struct InterleavedShortestIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+{
+    pub(crate) iter1: core::iter::Fuse<I1>,
+    pub(crate) iter2: core::iter::Fuse<I2>,
+    pub(crate) second: bool,
+    pub(crate) exhausted: bool,
+}
+
+impl<I1, I2> Iterator for InterleavedShortestIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator<Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let next = match self.second {
+            false => self.iter1.next(),
+            true => self.iter2.next(),
+        };
+
+        match next.is_some() {
+            true => self.second = !self.second,
+            false => self.exhausted = true,
+        }
+
+        next
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{debug_fn::DebugFn, Collection, Iterable};
+use core::{iter::Peekable, marker::PhantomData};
+use orx_self_or::SoM;
+
+/// Wraps an `Iterable` and creates a new `Iterable` which places a clone of the `separator`
+/// between adjacent elements of the original iterable.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interspersed<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    pub(crate) it: I,
+    pub(crate) separator: I::Item,
+}
+
+impl<I> Iterable for Interspersed<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    type Iter = InterspersedIter<I::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InterspersedIter::new(self.it.iter(), self.separator.clone())
+    }
+}
+
+/// Iterator placing a clone of the separator between adjacent elements of the wrapped iterator.
+pub struct InterspersedIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    pub(crate) iter: Peekable<It>,
+    pub(crate) separator: It::Item,
+    pub(crate) needs_separator: bool,
+}
+
+impl<It> InterspersedIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    pub(crate) fn new(iter: It, separator: It::Item) -> Self {
+        Self {
+            iter: iter.peekable(),
+            separator,
+            needs_separator: false,
+        }
+    }
+}
+
+impl<It> Iterator for InterspersedIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.needs_separator && self.iter.peek().is_some() {
+            true => {
+                self.needs_separator = false;
+                Some(self.separator.clone())
+            }
+            false => {
+                self.needs_separator = true;
+                self.iter.next()
+            }
+        }
+    }
+}
+
+// with
+
+/// Wraps an `Iterable` and creates a new `Iterable` which places the value returned by the
+/// `separator` function between adjacent elements of the original iterable.
+pub struct InterspersedWith<I, F>
+where
+    I: Iterable,
+    F: Fn() -> I::Item + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) separator: F,
+}
+
+impl<I, F> Clone for InterspersedWith<I, F>
+where
+    I: Iterable + Clone,
+    F: Fn() -> I::Item + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            separator: self.separator,
+        }
+    }
+}
+
+impl<I, F> Copy for InterspersedWith<I, F>
+where
+    I: Iterable + Copy,
+    F: Fn() -> I::Item + Copy,
+{
+}
+
+impl<I, F> core::fmt::Debug for InterspersedWith<I, F>
+where
+    I: Iterable + core::fmt::Debug,
+    F: Fn() -> I::Item + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InterspersedWith")
+            .field("it", &self.it)
+            .field("separator", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, F> PartialEq for InterspersedWith<I, F>
+where
+    I: Iterable + PartialEq,
+    F: Fn() -> I::Item + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.separator == other.separator
+    }
+}
+
+impl<I, F> Iterable for InterspersedWith<I, F>
+where
+    I: Iterable,
+    F: Fn() -> I::Item + Copy,
+{
+    type Item = I::Item;
+
+    type Iter = InterspersedWithIter<I::Iter, F>;
+
+    fn iter(&self) -> Self::Iter {
+        InterspersedWithIter {
+            iter: self.it.iter().peekable(),
+            separator: self.separator,
+            needs_separator: false,
+        }
+    }
+}
+
+/// Iterator placing the value returned by the separator function between adjacent elements of the wrapped iterator.
+pub struct InterspersedWithIter<It, F>
+where
+    It: Iterator,
+    F: Fn() -> It::Item,
+{
+    pub(crate) iter: Peekable<It>,
+    pub(crate) separator: F,
+    pub(crate) needs_separator: bool,
+}
+
+impl<It, F> Iterator for InterspersedWithIter<It, F>
+where
+    It: Iterator,
+    F: Fn() -> It::Item,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.needs_separator && self.iter.peek().is_some() {
+            true => {
+                self.needs_separator = false;
+                Some((self.separator)())
+            }
+            false => {
+                self.needs_separator = true;
+                self.iter.next()
+            }
+        }
+    }
+}
+
+// col
+
+/// Wraps a `Collection` together with a `separator` element, and creates a new `Collection` which
+/// yields a reference to the separator between references to adjacent elements of the original collection.
+pub struct InterspersedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    pub(crate) it: E,
+    pub(crate) separator: I::Item,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E> Clone for InterspersedCol<I, E>
+where
+    I: Collection,
+    I::Item: Clone,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            separator: self.separator.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for InterspersedCol<I, E>
+where
+    I: Collection,
+    I::Item: Copy,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for InterspersedCol<I, E>
+where
+    I: Collection,
+    I::Item: core::fmt::Debug,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InterspersedCol")
+            .field("it", &self.it)
+            .field("separator", &self.separator)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for InterspersedCol<I, E>
+where
+    I: Collection,
+    I::Item: PartialEq,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.separator == other.separator
+    }
+}
+
+impl<'a, I, E> Iterable for &'a InterspersedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    type Iter = InterspersedIter<<I::Iterable<'a> as Iterable>::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        InterspersedIter::new(self.it.get_ref().iter(), &self.separator)
+    }
+}
+
+impl<I, E> Collection for InterspersedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
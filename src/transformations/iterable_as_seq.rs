@@ -0,0 +1,127 @@
+use crate::Iterable;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Wraps an `Iterable` and implements `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash` by the
+/// sequence of elements yielded by its iterators.
+///
+/// Equality and comparisons can be performed across different iterable types as long as their
+/// items can be compared. Each comparison or hash computation creates new iterators over the wrapped
+/// iterables; no allocation is performed.
+///
+/// Created by the [`into_seq`] method of `Iterable`.
+///
+/// [`into_seq`]: crate::Iterable::into_seq
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+/// use std::collections::{HashMap, VecDeque};
+///
+/// let a = vec![1, 2, 3];
+/// let b = VecDeque::from_iter([1, 2, 3]);
+/// let c = [1, 2, 4];
+///
+/// assert_eq!(a.into_seq(), b.into_seq());
+/// assert!(a.into_seq() < c.into_seq());
+///
+/// let mut counts = HashMap::new();
+/// *counts.entry(a.into_seq()).or_insert(0) += 1;
+/// *counts.entry(a.into_seq()).or_insert(0) += 1;
+/// assert_eq!(counts.get(&a.into_seq()), Some(&2));
+/// ```
+#[derive(Clone, Copy)]
+pub struct IterableAsSeq<I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> IterableAsSeq<I>
+where
+    I: Iterable,
+{
+    /// Returns a reference to the wrapped iterable.
+    pub fn inner(&self) -> &I {
+        &self.it
+    }
+
+    /// Consumes the wrapper and returns the wrapped iterable.
+    pub fn into_inner(self) -> I {
+        self.it
+    }
+}
+
+impl<I> Iterable for IterableAsSeq<I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    type Iter = I::Iter;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter()
+    }
+}
+
+impl<I> core::fmt::Debug for IterableAsSeq<I>
+where
+    I: Iterable,
+    I::Item: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.it.iter()).finish()
+    }
+}
+
+impl<I, J> PartialEq<IterableAsSeq<J>> for IterableAsSeq<I>
+where
+    I: Iterable,
+    J: Iterable,
+    I::Item: PartialEq<J::Item>,
+{
+    fn eq(&self, other: &IterableAsSeq<J>) -> bool {
+        self.it.iter().eq(other.it.iter())
+    }
+}
+
+impl<I> Eq for IterableAsSeq<I>
+where
+    I: Iterable,
+    I::Item: Eq,
+{
+}
+
+impl<I, J> PartialOrd<IterableAsSeq<J>> for IterableAsSeq<I>
+where
+    I: Iterable,
+    J: Iterable,
+    I::Item: PartialOrd<J::Item>,
+{
+    fn partial_cmp(&self, other: &IterableAsSeq<J>) -> Option<Ordering> {
+        self.it.iter().partial_cmp(other.it.iter())
+    }
+}
+
+impl<I> Ord for IterableAsSeq<I>
+where
+    I: Iterable,
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.it.iter().cmp(other.it.iter())
+    }
+}
+
+impl<I> Hash for IterableAsSeq<I>
+where
+    I: Iterable,
+    I::Item: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.it.iter_hash(state)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Iterable;
+use crate::{debug_fn::DebugFn, Iterable};
 
 /// Wraps an `Iterable` and creates a new `Iterable` which maps the elements of
 /// the original iterable.
@@ -11,6 +11,49 @@ where
     pub(crate) map: M,
 }
 
+impl<I, M, U> Clone for Mapped<I, M, U>
+where
+    I: Iterable + Clone,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            map: self.map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for Mapped<I, M, U>
+where
+    I: Iterable + Copy,
+    M: Fn(I::Item) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for Mapped<I, M, U>
+where
+    I: Iterable + core::fmt::Debug,
+    M: Fn(I::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Mapped")
+            .field("it", &self.it)
+            .field("map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for Mapped<I, M, U>
+where
+    I: Iterable + PartialEq,
+    M: Fn(I::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.map == other.map
+    }
+}
+
 impl<I, M, U> Iterable for Mapped<I, M, U>
 where
     I: Iterable,
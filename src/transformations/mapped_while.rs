@@ -1,4 +1,4 @@
-use crate::Iterable;
+use crate::{debug_fn::DebugFn, Iterable};
 
 /// Wraps an `Iterable` and creates a new `Iterable` which maps the elements of
 /// the original iterable as long as the map-while condition is satisfied.
@@ -11,6 +11,49 @@ where
     pub(crate) map_while: M,
 }
 
+impl<I, M, U> Clone for MappedWhile<I, M, U>
+where
+    I: Iterable + Clone,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            map_while: self.map_while,
+        }
+    }
+}
+
+impl<I, M, U> Copy for MappedWhile<I, M, U>
+where
+    I: Iterable + Copy,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for MappedWhile<I, M, U>
+where
+    I: Iterable + core::fmt::Debug,
+    M: Fn(I::Item) -> Option<U> + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedWhile")
+            .field("it", &self.it)
+            .field("map_while", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for MappedWhile<I, M, U>
+where
+    I: Iterable + PartialEq,
+    M: Fn(I::Item) -> Option<U> + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.map_while == other.map_while
+    }
+}
+
 impl<I, M, U> Iterable for MappedWhile<I, M, U>
 where
     I: Iterable,
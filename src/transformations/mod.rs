@@ -1,36 +1,144 @@
+#[cfg(feature = "std")]
+mod cached;
 mod chained;
+mod circular_windows;
+#[cfg(feature = "std")]
+pub(crate) mod checked_multipass;
 mod cloned;
 mod cloning_iterable;
 mod copied;
+mod deduped;
+mod deduped_by_key;
+#[cfg(feature = "std")]
+mod deref_col;
+mod displayed;
 mod enumerated;
+mod errors;
+mod fallible_chained;
+mod fallible_cloned;
+mod fallible_copied;
+mod fallible_enumerated;
+mod fallible_filter_mapped;
+mod fallible_filtered;
+mod fallible_flat_mapped;
+mod fallible_flattened;
+mod fallible_mapped;
+mod fallible_results;
+mod fallible_skipped;
+mod fallible_taken;
 mod filter_mapped;
 mod filtered;
 mod flat_mapped;
+mod flat_mapped_iterable;
 mod flattened;
+mod flattened_iterables;
 mod fused;
+#[cfg(feature = "std")]
+mod grouped_by_key;
+mod grouped_consecutive_by;
+mod inspected;
+#[cfg(feature = "std")]
+mod instrumented;
+mod interleaved;
+mod interspersed;
+mod iterable_as_seq;
 mod mapped;
 mod mapped_while;
+mod multi_zipped;
+mod ok_values;
+mod padded_to;
+mod paged;
+mod panicking;
+mod partitioned;
 mod reversed;
+#[cfg(feature = "std")]
+mod round_robin;
+pub(crate) mod scanned;
 mod skipped;
 mod skipped_while;
 mod stepped_by;
 mod taken;
 mod taken_while;
+mod try_filtered;
+mod try_mapped;
+#[cfg(feature = "std")]
+mod unique;
+mod unzipped;
+mod with_position;
 mod zipped;
+mod zipped_exact;
+mod zipped_longest;
+mod zipped_with;
 
+#[cfg(feature = "std")]
+pub use cached::Cached;
 pub use chained::{Chained, ChainedCol};
+#[cfg(feature = "std")]
+pub use checked_multipass::{
+    CheckedMultipass, CheckedMultipassIter, Fingerprint, MultipassMismatch,
+};
+pub use circular_windows::{
+    CircularWindow, CircularWindowIter, CircularWindows, CircularWindowsIter,
+};
 pub use cloned::Cloned;
 pub use cloning_iterable::{CloningIterable, IntoCloningIterable};
 pub use copied::Copied;
-pub use enumerated::Enumerated;
+pub use deduped::{Deduped, DedupedBy, DedupedByCol, DedupedByIter, DedupedCol};
+pub use deduped_by_key::{DedupedByKey, DedupedByKeyCol, DedupedByKeyIter};
+#[cfg(feature = "std")]
+pub use deref_col::{DerefCol, IntoDerefCol};
+pub use displayed::{DebugListed, Displayed};
+pub use enumerated::{Enumerated, EnumeratedFrom, EnumeratedFromIter};
+pub use errors::Errors;
+pub use fallible_chained::FallibleChained;
+pub use fallible_cloned::FallibleCloned;
+pub use fallible_copied::FallibleCopied;
+pub use fallible_enumerated::FallibleEnumerated;
+pub use fallible_filter_mapped::FallibleFilterMapped;
+pub use fallible_filtered::FallibleFiltered;
+pub use fallible_flat_mapped::FallibleFlatMapped;
+pub use fallible_flattened::FallibleFlattened;
+pub use fallible_mapped::FallibleMapped;
+pub use fallible_results::{FallibleResults, FallibleResultsIter};
+pub use fallible_skipped::FallibleSkipped;
+pub use fallible_taken::FallibleTaken;
 pub use filter_mapped::FilterMapped;
 pub use filtered::{Filtered, FilteredCol, FilteredColIter, FilteredColIterMut};
 pub use flat_mapped::{FlatMapped, FlatMappedIter};
+pub use flat_mapped_iterable::{FlatMappedIterable, FlatMappedIterableIter};
 pub use flattened::{Flattened, FlattenedCol};
+pub use flattened_iterables::{
+    FlattenedIterables, FlattenedIterablesCol, FlattenedIterablesColIter,
+    FlattenedIterablesColIterMut, FlattenedIterablesIter,
+};
 pub use fused::{Fused, FusedCol};
+#[cfg(feature = "std")]
+pub use grouped_by_key::{GroupedByKey, GroupedByKeyIter, KeyGroup, KeyGroupIter};
+pub use grouped_consecutive_by::{
+    ConsecutiveGroup, GroupedConsecutiveBy, GroupedConsecutiveByIter,
+};
+pub use inspected::Inspected;
+#[cfg(feature = "std")]
+pub use instrumented::{Instrumented, InstrumentedCol, InstrumentedIter, IterationStats};
+pub use interleaved::{Interleaved, InterleavedIter, InterleavedShortest, InterleavedShortestIter};
+pub use interspersed::{
+    Interspersed, InterspersedCol, InterspersedIter, InterspersedWith, InterspersedWithIter,
+};
+pub use iterable_as_seq::IterableAsSeq;
 pub use mapped::Mapped;
 pub use mapped_while::MappedWhile;
+pub use multi_zipped::{
+    zip3, zip4, zip5, zip6, zip_array, MultiZipped, MultiZippedIter, ZippedArray, ZippedArrayIter,
+};
+pub use ok_values::OkValues;
+pub use padded_to::{PaddedTo, PaddedToCol, PaddedToIter};
+pub use paged::{Page, Paged, PagedIter};
+pub use panicking::Panicking;
+pub use partitioned::{Partition, PartitionCol, PartitionIter};
 pub use reversed::{Reversed, ReversedCol};
+#[cfg(feature = "std")]
+pub use round_robin::{round_robin, RoundRobin, RoundRobinIter};
+pub use scanned::{Differences, PrefixSums, RunningMax, ScanFn, Scanned};
 pub use skipped::{Skipped, SkippedCol};
 pub use skipped_while::{
     SkippedWhile, SkippedWhileCol, SkippedWhileColIter, SkippedWhileColIterMut,
@@ -38,4 +146,13 @@ pub use skipped_while::{
 pub use stepped_by::{SteppedBy, SteppedByCol};
 pub use taken::{Taken, TakenCol};
 pub use taken_while::{TakenWhile, TakenWhileCol, TakenWhileColIter, TakenWhileColIterMut};
+pub use try_filtered::{TryFiltered, TryFilteredIter};
+pub use try_mapped::{TryMapped, TryMappedIter};
+#[cfg(feature = "std")]
+pub use unique::{Unique, UniqueByKey, UniqueByKeyIter};
+pub use unzipped::{Pair, UnzippedLeft, UnzippedLeftCol, UnzippedRight, UnzippedRightCol};
+pub use with_position::{Position, WithPosition, WithPositionIter};
 pub use zipped::Zipped;
+pub use zipped_exact::{LengthMismatch, ZippedExact, ZippedExactIter};
+pub use zipped_longest::{EitherOrBoth, ZippedLongest, ZippedLongestIter};
+pub use zipped_with::{ZippedWith, ZippedWithIter};
@@ -0,0 +1,173 @@
+use crate::Iterable;
+
+/// An iterable created by zipping a tuple of iterables, which yields flat tuples of their elements.
+///
+/// See [`zip3`], [`zip4`], [`zip5`] and [`zip6`] to create multi-zipped iterables.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let a = vec![1, 2, 3];
+/// let b = ['x', 'y', 'z', 'w'];
+/// let c = vec![true, false, true];
+///
+/// let it = zip3(&a, &b, &c);
+///
+/// assert_eq!(
+///     it.iter().collect::<Vec<_>>(),
+///     [(&1, &'x', &true), (&2, &'y', &false), (&3, &'z', &true)]
+/// );
+///
+/// let it = zip4(&a, &b, &c, 10..14usize).mapped(|(a, _, _, d)| a + d);
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [11, 13, 15]);
+/// ```
+///
+/// [`zip3`]: crate::zip3
+/// [`zip4`]: crate::zip4
+/// [`zip5`]: crate::zip5
+/// [`zip6`]: crate::zip6
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MultiZipped<T> {
+    pub(crate) its: T,
+}
+
+/// Iterator zipping a tuple of iterators and yielding flat tuples of their elements.
+pub struct MultiZippedIter<T> {
+    pub(crate) iters: T,
+}
+
+macro_rules! impl_multi_zipped {
+    ($zip:ident, $n:literal, $($I:ident $it:ident),+) => {
+        impl<$($I),+> Iterable for MultiZipped<($($I,)+)>
+        where
+            $($I: Iterable),+
+        {
+            type Item = ($($I::Item,)+);
+
+            type Iter = MultiZippedIter<($($I::Iter,)+)>;
+
+            fn iter(&self) -> Self::Iter {
+                let ($($it,)+) = &self.its;
+                MultiZippedIter {
+                    iters: ($($it.iter(),)+),
+                }
+            }
+        }
+
+        impl<$($I),+> Iterator for MultiZippedIter<($($I,)+)>
+        where
+            $($I: Iterator),+
+        {
+            type Item = ($($I::Item,)+);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let ($($it,)+) = &mut self.iters;
+                Some(($($it.next()?,)+))
+            }
+        }
+
+        #[doc = concat!("‘Zips up’ ", $n, " iterables into a single iterable of flat tuples.")]
+        ///
+        /// If any of the iterators returns None, next from the zipped iterator returns None.
+        /// Unlike nested [`zipped`] calls, the items are flat tuples rather than nested pairs.
+        ///
+        /// [`zipped`]: crate::Iterable::zipped
+        pub fn $zip<$($I),+>($($it: $I),+) -> MultiZipped<($($I,)+)>
+        where
+            $($I: Iterable),+
+        {
+            MultiZipped {
+                its: ($($it,)+),
+            }
+        }
+    };
+}
+
+impl_multi_zipped!(zip3, "three", I1 it1, I2 it2, I3 it3);
+impl_multi_zipped!(zip4, "four", I1 it1, I2 it2, I3 it3, I4 it4);
+impl_multi_zipped!(zip5, "five", I1 it1, I2 it2, I3 it3, I4 it4, I5 it5);
+impl_multi_zipped!(zip6, "six", I1 it1, I2 it2, I3 it3, I4 it4, I5 it5, I6 it6);
+
+// array
+
+/// An iterable created by zipping an array of iterables of the same type, which yields arrays of their elements.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZippedArray<I, const N: usize>
+where
+    I: Iterable,
+{
+    pub(crate) its: [I; N],
+}
+
+impl<I, const N: usize> Iterable for ZippedArray<I, N>
+where
+    I: Iterable,
+{
+    type Item = [I::Item; N];
+
+    type Iter = ZippedArrayIter<I::Iter, N>;
+
+    fn iter(&self) -> Self::Iter {
+        ZippedArrayIter {
+            iters: self.its.each_ref().map(|x| x.iter()),
+        }
+    }
+}
+
+/// Iterator zipping an array of iterators and yielding arrays of their elements.
+pub struct ZippedArrayIter<I, const N: usize>
+where
+    I: Iterator,
+{
+    pub(crate) iters: [I; N],
+}
+
+impl<I, const N: usize> Iterator for ZippedArrayIter<I, N>
+where
+    I: Iterator,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            return None;
+        }
+
+        let items = self.iters.each_mut().map(|x| x.next());
+        match items.iter().all(|x| x.is_some()) {
+            true => Some(items.map(|x| match x {
+                Some(x) => x,
+                None => unreachable!("all items are checked to be some"),
+            })),
+            false => None,
+        }
+    }
+}
+
+/// ‘Zips up’ an array of iterables of the same type into a single iterable of arrays.
+///
+/// The `i`-th element of the yielded arrays is the element of the `i`-th iterable.
+/// If any of the iterators returns None, next from the zipped iterator returns None.
+/// Zipping an empty array of iterables yields no elements.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let x = vec![1, 2, 3];
+/// let y = vec![4, 5, 6, 7];
+/// let z = vec![8, 9, 10];
+///
+/// let it = zip_array([&x, &y, &z]).mapped(|[a, b, c]| a + b + c);
+///
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [13, 16, 19]);
+/// ```
+pub fn zip_array<I, const N: usize>(iterables: [I; N]) -> ZippedArray<I, N>
+where
+    I: Iterable,
+{
+    ZippedArray { its: iterables }
+}
@@ -0,0 +1,24 @@
+use crate::{Iterable, TryIterable};
+
+/// Wraps a `TryIterable` and creates a new `Iterable` which yields the values of the `Ok` elements
+/// of the original iterable, skipping the errors.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OkValues<I>
+where
+    I: TryIterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for OkValues<I>
+where
+    I: TryIterable,
+{
+    type Item = I::Value;
+
+    type Iter = core::iter::FilterMap<I::Iter, fn(I::Item) -> Option<I::Value>>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().filter_map(Result::ok)
+    }
+}
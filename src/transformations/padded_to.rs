@@ -0,0 +1,179 @@
+use crate::{Collection, Iterable};
+use core::{iter::Fuse, marker::PhantomData};
+use orx_self_or::SoM;
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields the elements of the original iterable
+/// followed by clones of the padding `value` until at least `len` elements are yielded.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PaddedTo<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    pub(crate) it: I,
+    pub(crate) len: usize,
+    pub(crate) value: I::Item,
+}
+
+impl<I> Iterable for PaddedTo<I>
+where
+    I: Iterable,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    type Iter = PaddedToIter<I::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        PaddedToIter::new(self.it.iter(), self.len, self.value.clone())
+    }
+}
+
+/// Iterator yielding the elements of the wrapped iterator followed by clones of the padding value
+/// until at least `len` elements are yielded.
+pub struct PaddedToIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    pub(crate) iter: Fuse<It>,
+    pub(crate) len: usize,
+    pub(crate) value: It::Item,
+    pub(crate) count: usize,
+}
+
+impl<It> PaddedToIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    pub(crate) fn new(iter: It, len: usize, value: It::Item) -> Self {
+        Self {
+            iter: iter.fuse(),
+            len,
+            value,
+            count: 0,
+        }
+    }
+}
+
+impl<It> Iterator for PaddedToIter<It>
+where
+    It: Iterator,
+    It::Item: Clone,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match self.iter.next() {
+            Some(x) => x,
+            None if self.count < self.len => self.value.clone(),
+            None => return None,
+        };
+        self.count += 1;
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let padding = self.len.saturating_sub(self.count);
+        (lower.max(padding), upper.map(|upper| upper.max(padding)))
+    }
+}
+
+// col
+
+/// Wraps a `Collection` together with a padding `value`, and creates a new `Collection` which yields
+/// references to the elements of the original collection followed by references to the padding value
+/// until at least `len` elements are yielded.
+pub struct PaddedToCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    pub(crate) it: E,
+    pub(crate) len: usize,
+    pub(crate) value: I::Item,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I, E> Clone for PaddedToCol<I, E>
+where
+    I: Collection,
+    I::Item: Clone,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            len: self.len,
+            value: self.value.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for PaddedToCol<I, E>
+where
+    I: Collection,
+    I::Item: Copy,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for PaddedToCol<I, E>
+where
+    I: Collection,
+    I::Item: core::fmt::Debug,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PaddedToCol")
+            .field("it", &self.it)
+            .field("len", &self.len)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for PaddedToCol<I, E>
+where
+    I: Collection,
+    I::Item: PartialEq,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.len == other.len && self.value == other.value
+    }
+}
+
+impl<'a, I, E> Iterable for &'a PaddedToCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = &'a I::Item;
+
+    type Iter = PaddedToIter<<I::Iterable<'a> as Iterable>::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        PaddedToIter::new(self.it.get_ref().iter(), self.len, &self.value)
+    }
+}
+
+impl<I, E> Collection for PaddedToCol<I, E>
+where
+    I: Collection,
+    E: SoM<I>,
+{
+    type Item = I::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
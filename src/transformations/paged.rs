@@ -0,0 +1,160 @@
+use crate::Iterable;
+
+/// An iterable borrowing an `Iterable` as its source and yielding its pages, each of which is an [`Page`]
+/// iterable over at most `page_size` consecutive elements of the source.
+///
+/// Created by the [`paged`] method of `Iterable`.
+///
+/// [`paged`]: crate::Iterable::paged
+#[derive(Debug, PartialEq)]
+pub struct Paged<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) page_size: usize,
+}
+
+impl<I> Clone for Paged<'_, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for Paged<'_, I> where I: Iterable {}
+
+impl<'a, I> Paged<'a, I>
+where
+    I: Iterable,
+{
+    /// Returns the maximum number of elements in each page.
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the page with the given zero-based `index`.
+    ///
+    /// Creating the page is a constant time operation. Iterating over the page skips the elements of the preceding
+    /// pages which is also a constant time operation when the source is an indexed collection such as a slice or a vector;
+    /// the page is empty if `index` is out of bounds.
+    pub fn page(&self, index: usize) -> Page<'a, I> {
+        Page {
+            it: self.it,
+            index,
+            page_size: self.page_size,
+        }
+    }
+
+    /// Returns the number of pages which is computed in constant time from the length of the source.
+    pub fn page_count(&self) -> usize
+    where
+        I::Iter: ExactSizeIterator,
+    {
+        self.it.iter().len().div_ceil(self.page_size)
+    }
+}
+
+impl<'a, I> Iterable for Paged<'a, I>
+where
+    I: Iterable,
+{
+    type Item = Page<'a, I>;
+
+    type Iter = PagedIter<'a, I>;
+
+    fn iter(&self) -> Self::Iter {
+        PagedIter {
+            it: self.it,
+            iter: self.it.iter(),
+            page_size: self.page_size,
+            index: 0,
+        }
+    }
+}
+
+/// Iterator yielding the non-empty pages of a [`Paged`] iterable.
+pub struct PagedIter<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) iter: I::Iter,
+    pub(crate) page_size: usize,
+    pub(crate) index: usize,
+}
+
+impl<'a, I> Iterator for PagedIter<'a, I>
+where
+    I: Iterable,
+{
+    type Item = Page<'a, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()?;
+        if self.page_size > 1 {
+            _ = self.iter.nth(self.page_size - 2);
+        }
+
+        let page = Page {
+            it: self.it,
+            index: self.index,
+            page_size: self.page_size,
+        };
+        self.index += 1;
+        Some(page)
+    }
+}
+
+/// A page of a [`Paged`] iterable which yields at most `page_size` consecutive elements of the source starting
+/// at position `index * page_size`.
+#[derive(Debug, PartialEq)]
+pub struct Page<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+    pub(crate) index: usize,
+    pub(crate) page_size: usize,
+}
+
+impl<I> Clone for Page<'_, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for Page<'_, I> where I: Iterable {}
+
+impl<I> Page<'_, I>
+where
+    I: Iterable,
+{
+    /// Returns the zero-based index of the page.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the position of the first element of the page in the source.
+    pub fn offset(&self) -> usize {
+        self.index.saturating_mul(self.page_size)
+    }
+}
+
+impl<I> Iterable for Page<'_, I>
+where
+    I: Iterable,
+{
+    type Item = I::Item;
+
+    type Iter = core::iter::Take<core::iter::Skip<I::Iter>>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().skip(self.offset()).take(self.page_size)
+    }
+}
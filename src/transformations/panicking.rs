@@ -0,0 +1,32 @@
+use crate::{FallibleIterable, Iterable};
+use core::fmt::Debug;
+
+/// Wraps a `FallibleIterable` and creates an `Iterable` which panics whenever the iterator of
+/// the original iterable cannot be created.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Panicking<I>
+where
+    I: FallibleIterable,
+    I::Error: Debug,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for Panicking<I>
+where
+    I: FallibleIterable,
+    I::Error: Debug,
+{
+    type Item = I::Item;
+
+    type Iter = I::Iter;
+
+    /// # Panics
+    ///
+    /// Panics if `try_iter` of the underlying fallible iterable returns an error.
+    fn iter(&self) -> Self::Iter {
+        self.it
+            .try_iter()
+            .expect("failed to create the iterator of the fallible iterable")
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{debug_fn::DebugFn, Collection, Iterable};
+use core::{borrow::Borrow, marker::PhantomData};
+
+/// One side of a partition of an `Iterable` with respect to a predicate.
+///
+/// Created in pairs by [`partitioned`]: the first one yields the elements satisfying the predicate
+/// and the second one yields the remaining elements.
+///
+/// [`partitioned`]: crate::Iterable::partitioned
+pub struct Partition<'a, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    pub(crate) it: &'a I,
+    pub(crate) pred: P,
+    pub(crate) value: bool,
+}
+
+impl<'a, I, P> Clone for Partition<'a, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I, P> Copy for Partition<'a, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<'a, I, P> core::fmt::Debug for Partition<'a, I, P>
+where
+    I: Iterable + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Partition")
+            .field("it", &self.it)
+            .field("pred", &DebugFn)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<'a, I, P> PartialEq for Partition<'a, I, P>
+where
+    I: Iterable + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.pred == other.pred && self.value == other.value
+    }
+}
+
+impl<I, P> Partition<'_, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    /// Returns true if this partition yields the elements satisfying the predicate; false if it yields the others.
+    pub fn satisfies_predicate(&self) -> bool {
+        self.value
+    }
+}
+
+impl<I, P> Iterable for Partition<'_, I, P>
+where
+    I: Iterable,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    type Item = I::Item;
+
+    type Iter = PartitionIter<I::Item, I::Iter, P>;
+
+    fn iter(&self) -> Self::Iter {
+        PartitionIter::new(self.it.iter(), self.pred, self.value)
+    }
+}
+
+// col
+
+/// One side of a partition of a `Collection` with respect to a predicate.
+///
+/// Created in pairs by [`as_partitioned`]: the first one contains the elements satisfying the predicate
+/// and the second one contains the remaining elements, both borrowing the same collection.
+///
+/// [`as_partitioned`]: crate::Collection::as_partitioned
+pub struct PartitionCol<'a, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    pub(crate) col: &'a C,
+    pub(crate) pred: P,
+    pub(crate) value: bool,
+}
+
+impl<'a, C, P> Clone for PartitionCol<'a, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C, P> Copy for PartitionCol<'a, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+}
+
+impl<'a, C, P> core::fmt::Debug for PartitionCol<'a, C, P>
+where
+    C: Collection + core::fmt::Debug,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PartitionCol")
+            .field("col", &self.col)
+            .field("pred", &DebugFn)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<'a, C, P> PartialEq for PartitionCol<'a, C, P>
+where
+    C: Collection + PartialEq,
+    P: Fn(&C::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col && self.pred == other.pred && self.value == other.value
+    }
+}
+
+impl<C, P> PartitionCol<'_, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    /// Returns true if this partition contains the elements satisfying the predicate; false if it contains the others.
+    pub fn satisfies_predicate(&self) -> bool {
+        self.value
+    }
+}
+
+impl<'i, C, P> Iterable for &'i PartitionCol<'_, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    type Item = &'i C::Item;
+
+    type Iter = PartitionIter<C::Item, <C::Iterable<'i> as Iterable>::Iter, P>;
+
+    fn iter(&self) -> Self::Iter {
+        let col: &'i C = self.col;
+        PartitionIter::new(col.iter(), self.pred, self.value)
+    }
+}
+
+impl<C, P> Collection for PartitionCol<'_, C, P>
+where
+    C: Collection,
+    P: Fn(&C::Item) -> bool + Copy,
+{
+    type Item = C::Item;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+// iter
+
+/// Iterator yielding elements for which the predicate evaluates to the expected value.
+pub struct PartitionIter<T, It, P>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    P: Fn(&T) -> bool,
+{
+    pub(crate) iter: It,
+    pub(crate) pred: P,
+    pub(crate) value: bool,
+    pub(crate) phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, It, P> PartitionIter<T, It, P>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    P: Fn(&T) -> bool,
+{
+    pub(crate) fn new(iter: It, pred: P, value: bool) -> Self {
+        Self {
+            iter,
+            pred,
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, It, P> Iterator for PartitionIter<T, It, P>
+where
+    It: Iterator,
+    It::Item: Borrow<T>,
+    P: Fn(&T) -> bool,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .by_ref()
+            .find(|x| (self.pred)(x.borrow()) == self.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
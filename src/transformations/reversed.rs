@@ -4,6 +4,7 @@ use orx_self_or::SoM;
 
 /// Wraps an `Iterable` and creates a new `Iterable` which yields the elements
 /// of the original iterable in reverse order.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Reversed<I>
 where
     I: Iterable,
@@ -40,6 +41,50 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E> Clone for ReversedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    for<'b> <I::Iterable<'b> as Iterable>::Iter: DoubleEndedIterator,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for ReversedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    for<'b> <I::Iterable<'b> as Iterable>::Iter: DoubleEndedIterator,
+{
+}
+
+impl<I, E> core::fmt::Debug for ReversedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    for<'b> <I::Iterable<'b> as Iterable>::Iter: DoubleEndedIterator,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReversedCol").field("it", &self.it).finish()
+    }
+}
+
+impl<I, E> PartialEq for ReversedCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    for<'b> <I::Iterable<'b> as Iterable>::Iter: DoubleEndedIterator,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
 impl<'a, I, E> Iterable for &'a ReversedCol<I, E>
 where
     I: Collection,
@@ -0,0 +1,100 @@
+use crate::{Collection, Iterable};
+use std::vec::Vec;
+
+/// An iterable created from a collection of iterables which yields elements of the
+/// iterables in a round-robin manner.
+///
+/// Every iteration visits the first element of each of the iterables in order, then the
+/// second elements, and so on. Exhausted iterables are skipped, and the iteration ends
+/// once all iterables are exhausted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RoundRobin<C>
+where
+    C: Collection,
+    C::Item: Iterable,
+{
+    pub(crate) iterables: C,
+}
+
+impl<C> Iterable for RoundRobin<C>
+where
+    C: Collection,
+    C::Item: Iterable,
+{
+    type Item = <C::Item as Iterable>::Item;
+
+    type Iter = RoundRobinIter<<C::Item as Iterable>::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        RoundRobinIter {
+            iters: self.iterables.iter().map(|x| x.iter()).collect(),
+            current: 0,
+        }
+    }
+}
+
+/// Iterator yielding elements of a collection of iterators in a round-robin manner.
+pub struct RoundRobinIter<I>
+where
+    I: Iterator,
+{
+    pub(crate) iters: Vec<I>,
+    pub(crate) current: usize,
+}
+
+impl<I> Iterator for RoundRobinIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.iters.is_empty() {
+            match self.iters[self.current].next() {
+                Some(x) => {
+                    self.current = (self.current + 1) % self.iters.len();
+                    return Some(x);
+                }
+                None => {
+                    self.iters.remove(self.current);
+                    if self.current == self.iters.len() {
+                        self.current = 0;
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Creates an iterable which yields elements of the given collection of `iterables` in a round-robin manner.
+///
+/// Every iteration visits the first element of each of the iterables in order, then the
+/// second elements, and so on. Exhausted iterables are skipped, and the iteration ends
+/// once all iterables are exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let a = vec![1, 2, 3];
+/// let b = vec![10];
+/// let c = vec![100, 200];
+///
+/// let it = round_robin([&a, &b, &c]);
+///
+/// assert_eq!(it.iter().count(), 6);
+/// assert_eq!(it.iter().copied().collect::<Vec<_>>(), [1, 10, 100, 2, 200, 3]);
+///
+/// let it = round_robin(vec![0..3, 10..12]);
+///
+/// assert_eq!(it.iter().collect::<Vec<_>>(), [0, 10, 1, 11, 2]);
+/// ```
+pub fn round_robin<C>(iterables: C) -> RoundRobin<C>
+where
+    C: Collection,
+    C::Item: Iterable,
+{
+    RoundRobin { iterables }
+}
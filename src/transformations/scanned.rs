@@ -0,0 +1,146 @@
+use crate::{debug_fn::DebugFn, transformations::Flattened, Iterable};
+use core::ops::{Add, Sub};
+
+/// Wraps an `Iterable` and creates a new `Iterable` which scans the elements of the
+/// original iterable with a state.
+///
+/// The state is created by cloning the initial state every time the `iter` method is called;
+/// therefore, each iteration starts with a fresh state.
+pub struct Scanned<I, S, F, U>
+where
+    I: Iterable,
+    S: Clone,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) init: S,
+    pub(crate) scan: F,
+}
+
+impl<I, S, F, U> Clone for Scanned<I, S, F, U>
+where
+    I: Iterable + Clone,
+    S: Clone,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            init: self.init.clone(),
+            scan: self.scan,
+        }
+    }
+}
+
+impl<I, S, F, U> Copy for Scanned<I, S, F, U>
+where
+    I: Iterable + Copy,
+    S: Clone + Copy,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+}
+
+impl<I, S, F, U> core::fmt::Debug for Scanned<I, S, F, U>
+where
+    I: Iterable + core::fmt::Debug,
+    S: Clone + core::fmt::Debug,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Scanned")
+            .field("it", &self.it)
+            .field("init", &self.init)
+            .field("scan", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, S, F, U> PartialEq for Scanned<I, S, F, U>
+where
+    I: Iterable + PartialEq,
+    S: Clone + PartialEq,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.init == other.init && self.scan == other.scan
+    }
+}
+
+impl<I, S, F, U> Iterable for Scanned<I, S, F, U>
+where
+    I: Iterable,
+    S: Clone,
+    F: Fn(&mut S, I::Item) -> Option<U> + Copy,
+{
+    type Item = U;
+
+    type Iter = core::iter::Scan<I::Iter, S, F>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().scan(self.init.clone(), self.scan)
+    }
+}
+
+// scans
+
+/// Scan function of accumulating values.
+pub type ScanFn<T, U> = fn(&mut Option<T>, T) -> Option<U>;
+
+/// An iterable yielding the cumulative sums of the elements of the wrapped iterable.
+pub type PrefixSums<I> = Scanned<
+    I,
+    Option<<I as Iterable>::Item>,
+    ScanFn<<I as Iterable>::Item, <I as Iterable>::Item>,
+    <I as Iterable>::Item,
+>;
+
+/// An iterable yielding the maximum of the elements of the wrapped iterable observed so far.
+pub type RunningMax<I> = Scanned<
+    I,
+    Option<<I as Iterable>::Item>,
+    ScanFn<<I as Iterable>::Item, <I as Iterable>::Item>,
+    <I as Iterable>::Item,
+>;
+
+/// An iterable yielding the differences between consecutive elements of the wrapped iterable.
+pub type Differences<I> = Flattened<
+    Scanned<
+        I,
+        Option<<I as Iterable>::Item>,
+        ScanFn<<I as Iterable>::Item, Option<<I as Iterable>::Item>>,
+        Option<<I as Iterable>::Item>,
+    >,
+>;
+
+pub(crate) fn prefix_sum<T>(state: &mut Option<T>, x: T) -> Option<T>
+where
+    T: Add<Output = T> + Clone,
+{
+    let sum = match state.take() {
+        Some(sum) => sum + x,
+        None => x,
+    };
+    *state = Some(sum.clone());
+    Some(sum)
+}
+
+pub(crate) fn running_max<T>(state: &mut Option<T>, x: T) -> Option<T>
+where
+    T: Ord + Clone,
+{
+    let max = match state.take() {
+        Some(max) => core::cmp::max(max, x),
+        None => x,
+    };
+    *state = Some(max.clone());
+    Some(max)
+}
+
+pub(crate) fn difference<T>(state: &mut Option<T>, x: T) -> Option<Option<T>>
+where
+    T: Sub<Output = T> + Clone,
+{
+    let difference = state.take().map(|prev| x.clone() - prev);
+    *state = Some(x);
+    Some(difference)
+}
@@ -1,4 +1,4 @@
-use crate::{Collection, CollectionMut, Iterable};
+use crate::{debug_fn::DebugFn, Collection, CollectionMut, Iterable};
 use core::marker::PhantomData;
 use orx_self_or::SoM;
 
@@ -14,6 +14,49 @@ where
     pub(crate) skip_while: P,
 }
 
+impl<I, P> Clone for SkippedWhile<I, P>
+where
+    I: Iterable + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            skip_while: self.skip_while,
+        }
+    }
+}
+
+impl<I, P> Copy for SkippedWhile<I, P>
+where
+    I: Iterable + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, P> core::fmt::Debug for SkippedWhile<I, P>
+where
+    I: Iterable + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SkippedWhile")
+            .field("it", &self.it)
+            .field("skip_while", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, P> PartialEq for SkippedWhile<I, P>
+where
+    I: Iterable + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.skip_while == other.skip_while
+    }
+}
+
 impl<I, P> Iterable for SkippedWhile<I, P>
 where
     I: Iterable,
@@ -44,6 +87,54 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E, P> Clone for SkippedWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            skip_while: self.skip_while,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E, P> Copy for SkippedWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, E, P> core::fmt::Debug for SkippedWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SkippedWhileCol")
+            .field("it", &self.it)
+            .field("skip_while", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E, P> PartialEq for SkippedWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.skip_while == other.skip_while
+    }
+}
+
 impl<'a, I, E, P> Iterable for &'a SkippedWhileCol<I, E, P>
 where
     I: Collection,
@@ -4,6 +4,7 @@ use orx_self_or::SoM;
 
 /// Wraps an `Iterable` and creates a new `Iterable` which yields elements of
 /// the original iterable by stepping by a given step size.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct SteppedBy<I>
 where
     I: Iterable,
@@ -39,6 +40,50 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E> Clone for SteppedByCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            step: self.step,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for SteppedByCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for SteppedByCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SteppedByCol")
+            .field("it", &self.it)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for SteppedByCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.step == other.step
+    }
+}
+
 impl<'a, I, E> Iterable for &'a SteppedByCol<I, E>
 where
     I: Collection,
@@ -4,6 +4,7 @@ use orx_self_or::SoM;
 
 /// Wraps an `Iterable` and creates a new `Iterable` which yields only the first `n` the elements
 /// of the original iterable.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Taken<I>
 where
     I: Iterable,
@@ -39,6 +40,50 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E> Clone for TakenCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            n: self.n,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E> Copy for TakenCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+{
+}
+
+impl<I, E> core::fmt::Debug for TakenCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TakenCol")
+            .field("it", &self.it)
+            .field("n", &self.n)
+            .finish()
+    }
+}
+
+impl<I, E> PartialEq for TakenCol<I, E>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.n == other.n
+    }
+}
+
 impl<'a, I, E> Iterable for &'a TakenCol<I, E>
 where
     I: Collection,
@@ -1,4 +1,4 @@
-use crate::{Collection, CollectionMut, Iterable};
+use crate::{debug_fn::DebugFn, Collection, CollectionMut, Iterable};
 use core::marker::PhantomData;
 use orx_self_or::SoM;
 
@@ -13,6 +13,49 @@ where
     pub(crate) take_while: P,
 }
 
+impl<I, P> Clone for TakenWhile<I, P>
+where
+    I: Iterable + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            take_while: self.take_while,
+        }
+    }
+}
+
+impl<I, P> Copy for TakenWhile<I, P>
+where
+    I: Iterable + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, P> core::fmt::Debug for TakenWhile<I, P>
+where
+    I: Iterable + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TakenWhile")
+            .field("it", &self.it)
+            .field("take_while", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, P> PartialEq for TakenWhile<I, P>
+where
+    I: Iterable + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.take_while == other.take_while
+    }
+}
+
 impl<I, P> Iterable for TakenWhile<I, P>
 where
     I: Iterable,
@@ -42,6 +85,54 @@ where
     pub(crate) phantom: PhantomData<I>,
 }
 
+impl<I, E, P> Clone for TakenWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Clone,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            take_while: self.take_while,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, E, P> Copy for TakenWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + Copy,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+}
+
+impl<I, E, P> core::fmt::Debug for TakenWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + core::fmt::Debug,
+    P: Fn(&I::Item) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TakenWhileCol")
+            .field("it", &self.it)
+            .field("take_while", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, E, P> PartialEq for TakenWhileCol<I, E, P>
+where
+    I: Collection,
+    E: SoM<I> + PartialEq,
+    P: Fn(&I::Item) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.take_while == other.take_while
+    }
+}
+
 impl<'a, I, E, P> Iterable for &'a TakenWhileCol<I, E, P>
 where
     I: Collection,
@@ -0,0 +1,110 @@
+use crate::{debug_fn::DebugFn, Iterable, TryIterable};
+
+/// Wraps a `TryIterable` and creates a new `TryIterable` which yields only the `Ok` elements of the original
+/// iterable with values satisfying a filter.
+///
+/// Iterators yield `Err` elements as they are and end right after the first error.
+pub struct TryFiltered<I, P>
+where
+    I: TryIterable,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) filter: P,
+}
+
+impl<I, P> Clone for TryFiltered<I, P>
+where
+    I: TryIterable + Clone,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            filter: self.filter,
+        }
+    }
+}
+
+impl<I, P> Copy for TryFiltered<I, P>
+where
+    I: TryIterable + Copy,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+}
+
+impl<I, P> core::fmt::Debug for TryFiltered<I, P>
+where
+    I: TryIterable + core::fmt::Debug,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TryFiltered")
+            .field("it", &self.it)
+            .field("filter", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, P> PartialEq for TryFiltered<I, P>
+where
+    I: TryIterable + PartialEq,
+    P: Fn(&I::Value) -> bool + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.filter == other.filter
+    }
+}
+
+impl<I, P> Iterable for TryFiltered<I, P>
+where
+    I: TryIterable,
+    P: Fn(&I::Value) -> bool + Copy,
+{
+    type Item = Result<I::Value, I::Error>;
+
+    type Iter = TryFilteredIter<I::Iter, P>;
+
+    fn iter(&self) -> Self::Iter {
+        TryFilteredIter {
+            iter: self.it.iter(),
+            filter: self.filter,
+            done: false,
+        }
+    }
+}
+
+/// Iterator yielding `Ok` elements with values satisfying a filter, which ends right after yielding the first error.
+pub struct TryFilteredIter<It, P>
+where
+    It: Iterator,
+{
+    pub(crate) iter: It,
+    pub(crate) filter: P,
+    pub(crate) done: bool,
+}
+
+impl<It, P, T, E> Iterator for TryFilteredIter<It, P>
+where
+    It: Iterator<Item = Result<T, E>>,
+    P: Fn(&T) -> bool,
+{
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match self.iter.next()? {
+                Ok(x) if (self.filter)(&x) => return Some(Ok(x)),
+                Ok(_) => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
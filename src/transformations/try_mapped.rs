@@ -0,0 +1,106 @@
+use crate::{debug_fn::DebugFn, Iterable, TryIterable};
+
+/// Wraps a `TryIterable` and creates a new `TryIterable` which maps the values of the `Ok` elements
+/// of the original iterable.
+///
+/// Iterators yield `Err` elements as they are and end right after the first error.
+pub struct TryMapped<I, M, U>
+where
+    I: TryIterable,
+    M: Fn(I::Value) -> U + Copy,
+{
+    pub(crate) it: I,
+    pub(crate) map: M,
+}
+
+impl<I, M, U> Clone for TryMapped<I, M, U>
+where
+    I: TryIterable + Clone,
+    M: Fn(I::Value) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            map: self.map,
+        }
+    }
+}
+
+impl<I, M, U> Copy for TryMapped<I, M, U>
+where
+    I: TryIterable + Copy,
+    M: Fn(I::Value) -> U + Copy,
+{
+}
+
+impl<I, M, U> core::fmt::Debug for TryMapped<I, M, U>
+where
+    I: TryIterable + core::fmt::Debug,
+    M: Fn(I::Value) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TryMapped")
+            .field("it", &self.it)
+            .field("map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, M, U> PartialEq for TryMapped<I, M, U>
+where
+    I: TryIterable + PartialEq,
+    M: Fn(I::Value) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.map == other.map
+    }
+}
+
+impl<I, M, U> Iterable for TryMapped<I, M, U>
+where
+    I: TryIterable,
+    M: Fn(I::Value) -> U + Copy,
+{
+    type Item = Result<U, I::Error>;
+
+    type Iter = TryMappedIter<I::Iter, M>;
+
+    fn iter(&self) -> Self::Iter {
+        TryMappedIter {
+            iter: self.it.iter(),
+            map: self.map,
+            done: false,
+        }
+    }
+}
+
+/// Iterator mapping the values of `Ok` elements, which ends right after yielding the first error.
+pub struct TryMappedIter<It, M>
+where
+    It: Iterator,
+{
+    pub(crate) iter: It,
+    pub(crate) map: M,
+    pub(crate) done: bool,
+}
+
+impl<It, M, T, E, U> Iterator for TryMappedIter<It, M>
+where
+    It: Iterator<Item = Result<T, E>>,
+    M: Fn(T) -> U,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.done {
+            true => None,
+            false => match self.iter.next()? {
+                Ok(x) => Some(Ok((self.map)(x))),
+                Err(e) => {
+                    self.done = true;
+                    Some(Err(e))
+                }
+            },
+        }
+    }
+}
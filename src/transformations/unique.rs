@@ -0,0 +1,122 @@
+use crate::{debug_fn::DebugFn, Iterable};
+use core::hash::Hash;
+use std::collections::HashSet;
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields only the first occurrence of
+/// the elements of the original iterable with the same key.
+///
+/// The set of visited keys is created from scratch every time the `iter` method is called.
+pub struct UniqueByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+    pub(crate) it: I,
+    pub(crate) key: F,
+}
+
+impl<I, F, K> Clone for UniqueByKey<I, F, K>
+where
+    I: Iterable + Clone,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it: self.it.clone(),
+            key: self.key,
+        }
+    }
+}
+
+impl<I, F, K> Copy for UniqueByKey<I, F, K>
+where
+    I: Iterable + Copy,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+}
+
+impl<I, F, K> core::fmt::Debug for UniqueByKey<I, F, K>
+where
+    I: Iterable + core::fmt::Debug,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UniqueByKey")
+            .field("it", &self.it)
+            .field("key", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I, F, K> PartialEq for UniqueByKey<I, F, K>
+where
+    I: Iterable + PartialEq,
+    F: Fn(&I::Item) -> K + Copy + PartialEq,
+    K: Eq + Hash,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it && self.key == other.key
+    }
+}
+
+impl<I, F, K> Iterable for UniqueByKey<I, F, K>
+where
+    I: Iterable,
+    F: Fn(&I::Item) -> K + Copy,
+    K: Eq + Hash,
+{
+    type Item = I::Item;
+
+    type Iter = UniqueByKeyIter<I::Iter, F, K>;
+
+    fn iter(&self) -> Self::Iter {
+        UniqueByKeyIter {
+            iter: self.it.iter(),
+            key: self.key,
+            visited: HashSet::new(),
+        }
+    }
+}
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields only the first occurrence of
+/// the equal elements of the original iterable.
+pub type Unique<I> =
+    UniqueByKey<I, fn(&<I as Iterable>::Item) -> <I as Iterable>::Item, <I as Iterable>::Item>;
+
+/// Iterator yielding only the first occurrence of elements with the same key.
+pub struct UniqueByKeyIter<It, F, K>
+where
+    It: Iterator,
+    F: Fn(&It::Item) -> K,
+    K: Eq + Hash,
+{
+    pub(crate) iter: It,
+    pub(crate) key: F,
+    pub(crate) visited: HashSet<K>,
+}
+
+impl<It, F, K> Iterator for UniqueByKeyIter<It, F, K>
+where
+    It: Iterator,
+    F: Fn(&It::Item) -> K,
+    K: Eq + Hash,
+{
+    type Item = It::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let x = self.iter.next()?;
+            if self.visited.insert((self.key)(&x)) {
+                return Some(x);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
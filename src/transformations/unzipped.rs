@@ -0,0 +1,315 @@
+use crate::{Collection, Iterable};
+
+/// A pair of values which can be split into its left and right elements.
+///
+/// Implemented for tuples `(A, B)`; it is the element type required by the [`unzipped`] and [`as_unzipped`] transformations.
+///
+/// [`unzipped`]: crate::Iterable::unzipped
+/// [`as_unzipped`]: crate::Collection::as_unzipped
+pub trait Pair {
+    /// Type of the left, or first, element of the pair.
+    type Left;
+
+    /// Type of the right, or second, element of the pair.
+    type Right;
+
+    /// Consumes the pair and returns its left element.
+    fn into_left(self) -> Self::Left;
+
+    /// Consumes the pair and returns its right element.
+    fn into_right(self) -> Self::Right;
+
+    /// Returns a reference to the left element of the pair.
+    fn left(&self) -> &Self::Left;
+
+    /// Returns a reference to the right element of the pair.
+    fn right(&self) -> &Self::Right;
+}
+
+impl<A, B> Pair for (A, B) {
+    type Left = A;
+
+    type Right = B;
+
+    fn into_left(self) -> Self::Left {
+        self.0
+    }
+
+    fn into_right(self) -> Self::Right {
+        self.1
+    }
+
+    fn left(&self) -> &Self::Left {
+        &self.0
+    }
+
+    fn right(&self) -> &Self::Right {
+        &self.1
+    }
+}
+
+/// An iterable over the left elements of pairs yielded by the wrapped iterable.
+///
+/// Created by [`unzipped`] together with an [`UnzippedRight`] borrowing the same source.
+///
+/// [`unzipped`]: crate::Iterable::unzipped
+pub struct UnzippedLeft<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+}
+
+impl<'a, I> Clone for UnzippedLeft<'a, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> Copy for UnzippedLeft<'a, I> where I: Iterable {}
+
+impl<'a, I> core::fmt::Debug for UnzippedLeft<'a, I>
+where
+    I: Iterable + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnzippedLeft")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<'a, I> PartialEq for UnzippedLeft<'a, I>
+where
+    I: Iterable + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
+impl<I> Iterable for UnzippedLeft<'_, I>
+where
+    I: Iterable,
+    I::Item: Pair,
+{
+    type Item = <I::Item as Pair>::Left;
+
+    type Iter = core::iter::Map<I::Iter, fn(I::Item) -> Self::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().map(Pair::into_left)
+    }
+}
+
+/// An iterable over the right elements of pairs yielded by the wrapped iterable.
+///
+/// Created by [`unzipped`] together with an [`UnzippedLeft`] borrowing the same source.
+///
+/// [`unzipped`]: crate::Iterable::unzipped
+pub struct UnzippedRight<'a, I>
+where
+    I: Iterable,
+{
+    pub(crate) it: &'a I,
+}
+
+impl<'a, I> Clone for UnzippedRight<'a, I>
+where
+    I: Iterable,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, I> Copy for UnzippedRight<'a, I> where I: Iterable {}
+
+impl<'a, I> core::fmt::Debug for UnzippedRight<'a, I>
+where
+    I: Iterable + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnzippedRight")
+            .field("it", &self.it)
+            .finish()
+    }
+}
+
+impl<'a, I> PartialEq for UnzippedRight<'a, I>
+where
+    I: Iterable + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it == other.it
+    }
+}
+
+impl<I> Iterable for UnzippedRight<'_, I>
+where
+    I: Iterable,
+    I::Item: Pair,
+{
+    type Item = <I::Item as Pair>::Right;
+
+    type Iter = core::iter::Map<I::Iter, fn(I::Item) -> Self::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        self.it.iter().map(Pair::into_right)
+    }
+}
+
+// col
+
+/// A collection view over the left elements of pairs stored in the wrapped collection.
+///
+/// Created by [`as_unzipped`] together with an [`UnzippedRightCol`] borrowing the same collection.
+///
+/// [`as_unzipped`]: crate::Collection::as_unzipped
+pub struct UnzippedLeftCol<'a, C>
+where
+    C: Collection,
+{
+    pub(crate) col: &'a C,
+}
+
+impl<'a, C> Clone for UnzippedLeftCol<'a, C>
+where
+    C: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C> Copy for UnzippedLeftCol<'a, C> where C: Collection {}
+
+impl<'a, C> core::fmt::Debug for UnzippedLeftCol<'a, C>
+where
+    C: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnzippedLeftCol")
+            .field("col", &self.col)
+            .finish()
+    }
+}
+
+impl<'a, C> PartialEq for UnzippedLeftCol<'a, C>
+where
+    C: Collection + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col
+    }
+}
+
+impl<'i, C> Iterable for &'i UnzippedLeftCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = &'i <C::Item as Pair>::Left;
+
+    type Iter = core::iter::Map<<C::Iterable<'i> as Iterable>::Iter, fn(&'i C::Item) -> Self::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        let col: &'i C = self.col;
+        col.iter().map(Pair::left)
+    }
+}
+
+impl<C> Collection for UnzippedLeftCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = <C::Item as Pair>::Left;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
+
+/// A collection view over the right elements of pairs stored in the wrapped collection.
+///
+/// Created by [`as_unzipped`] together with an [`UnzippedLeftCol`] borrowing the same collection.
+///
+/// [`as_unzipped`]: crate::Collection::as_unzipped
+pub struct UnzippedRightCol<'a, C>
+where
+    C: Collection,
+{
+    pub(crate) col: &'a C,
+}
+
+impl<'a, C> Clone for UnzippedRightCol<'a, C>
+where
+    C: Collection,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, C> Copy for UnzippedRightCol<'a, C> where C: Collection {}
+
+impl<'a, C> core::fmt::Debug for UnzippedRightCol<'a, C>
+where
+    C: Collection + core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnzippedRightCol")
+            .field("col", &self.col)
+            .finish()
+    }
+}
+
+impl<'a, C> PartialEq for UnzippedRightCol<'a, C>
+where
+    C: Collection + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.col == other.col
+    }
+}
+
+impl<'i, C> Iterable for &'i UnzippedRightCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = &'i <C::Item as Pair>::Right;
+
+    type Iter = core::iter::Map<<C::Iterable<'i> as Iterable>::Iter, fn(&'i C::Item) -> Self::Item>;
+
+    fn iter(&self) -> Self::Iter {
+        let col: &'i C = self.col;
+        col.iter().map(Pair::right)
+    }
+}
+
+impl<C> Collection for UnzippedRightCol<'_, C>
+where
+    C: Collection,
+    C::Item: Pair,
+{
+    type Item = <C::Item as Pair>::Right;
+
+    type Iterable<'i>
+        = &'i Self
+    where
+        Self: 'i;
+
+    fn as_iterable(&self) -> Self::Iterable<'_> {
+        self
+    }
+}
@@ -0,0 +1,98 @@
+use crate::Iterable;
+use core::iter::Peekable;
+
+/// Position of an element within the sequence yielded by an iterator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// The first element of a sequence with more than one element.
+    First,
+    /// An element which is neither the first nor the last element of the sequence.
+    Middle,
+    /// The last element of a sequence with more than one element.
+    Last,
+    /// The only element of a sequence with exactly one element.
+    Only,
+}
+
+impl Position {
+    /// Returns true if the element is the first element of the sequence; i.e., `First` or `Only`.
+    pub fn is_first(&self) -> bool {
+        matches!(self, Self::First | Self::Only)
+    }
+
+    /// Returns true if the element is the last element of the sequence; i.e., `Last` or `Only`.
+    pub fn is_last(&self) -> bool {
+        matches!(self, Self::Last | Self::Only)
+    }
+}
+
+/// Wraps an `Iterable` and creates a new `Iterable` which yields the elements together with
+/// their [`Position`] in the sequence.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WithPosition<I>
+where
+    I: Iterable,
+{
+    pub(crate) it: I,
+}
+
+impl<I> Iterable for WithPosition<I>
+where
+    I: Iterable,
+{
+    type Item = (Position, I::Item);
+
+    type Iter = WithPositionIter<I::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        WithPositionIter::new(self.it.iter())
+    }
+}
+
+/// Iterator yielding the elements of the wrapped iterator together with their [`Position`],
+/// using a one element look-ahead to detect the last element.
+pub struct WithPositionIter<It>
+where
+    It: Iterator,
+{
+    pub(crate) iter: Peekable<It>,
+    pub(crate) started: bool,
+}
+
+impl<It> WithPositionIter<It>
+where
+    It: Iterator,
+{
+    pub(crate) fn new(iter: It) -> Self {
+        Self {
+            iter: iter.peekable(),
+            started: false,
+        }
+    }
+}
+
+impl<It> Iterator for WithPositionIter<It>
+where
+    It: Iterator,
+{
+    type Item = (Position, It::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.iter.next()?;
+        let is_first = !self.started;
+        self.started = true;
+        let is_last = self.iter.peek().is_none();
+
+        let position = match (is_first, is_last) {
+            (true, true) => Position::Only,
+            (true, false) => Position::First,
+            (false, true) => Position::Last,
+            (false, false) => Position::Middle,
+        };
+        Some((position, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
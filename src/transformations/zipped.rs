@@ -1,6 +1,7 @@
 use crate::Iterable;
 
 /// An iterable created by zipping two iterables.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Zipped<I1, I2>
 where
     I1: Iterable,
@@ -0,0 +1,116 @@
+use crate::Iterable;
+
+/// Error yielded by iterators of [`ZippedExact`] when lengths of the zipped iterables do not match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthMismatch {
+    /// The first iterable is longer; the value is the length of the second iterable.
+    FirstIsLonger(usize),
+    /// The second iterable is longer; the value is the length of the first iterable.
+    SecondIsLonger(usize),
+}
+
+impl core::fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::FirstIsLonger(len) => {
+                write!(
+                    f,
+                    "the second iterable ended after {len} elements while the first continued"
+                )
+            }
+            Self::SecondIsLonger(len) => {
+                write!(
+                    f,
+                    "the first iterable ended after {len} elements while the second continued"
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LengthMismatch {}
+
+/// An iterable created by zipping two iterables which are expected to have the same length.
+///
+/// Its iterators yield `Ok` pairs while both iterators yield elements.
+/// If one of them ends before the other, a single `Err(LengthMismatch)` is yielded and the iteration ends.
+///
+/// Unlike most transformations, there exists no collection counterpart of this iterable.
+/// A [`Collection`] yields references to elements it owns; whereas the pairs created by zipping
+/// are constructed on the fly and are not stored anywhere to be referenced.
+///
+/// [`Collection`]: crate::Collection
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZippedExact<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+}
+
+impl<I1, I2> Iterable for ZippedExact<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    type Item = Result<(I1::Item, I2::Item), LengthMismatch>;
+
+    type Iter = ZippedExactIter<I1::Iter, I2::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        ZippedExactIter {
+            iter1: self.it1.iter(),
+            iter2: self.it2.iter(),
+            len: 0,
+            done: false,
+        }
+    }
+}
+
+/// Iterator zipping two iterators which are expected to have the same length.
+pub struct ZippedExactIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator,
+{
+    pub(crate) iter1: I1,
+    pub(crate) iter2: I2,
+    pub(crate) len: usize,
+    pub(crate) done: bool,
+}
+
+impl<I1, I2> Iterator for ZippedExactIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator,
+{
+    type Item = Result<(I1::Item, I2::Item), LengthMismatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match (self.iter1.next(), self.iter2.next()) {
+            (Some(x), Some(y)) => {
+                self.len += 1;
+                Some(Ok((x, y)))
+            }
+            (None, None) => {
+                self.done = true;
+                None
+            }
+            (Some(_), None) => {
+                self.done = true;
+                Some(Err(LengthMismatch::FirstIsLonger(self.len)))
+            }
+            (None, Some(_)) => {
+                self.done = true;
+                Some(Err(LengthMismatch::SecondIsLonger(self.len)))
+            }
+        }
+    }
+}
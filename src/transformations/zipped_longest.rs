@@ -0,0 +1,137 @@
+use crate::Iterable;
+
+/// A value which holds either or both of the two values.
+///
+/// It is the item type of iterables created by [`zipped_longest`].
+///
+/// [`zipped_longest`]: crate::Iterable::zipped_longest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    /// Both values are present.
+    Both(L, R),
+    /// Only the left value is present.
+    Left(L),
+    /// Only the right value is present.
+    Right(R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Returns true if both values are present.
+    pub fn is_both(&self) -> bool {
+        matches!(self, Self::Both(_, _))
+    }
+
+    /// Returns true if only the left value is present.
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Returns true if only the right value is present.
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns the left value if present; None otherwise.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Both(l, _) | Self::Left(l) => Some(l),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value if present; None otherwise.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Both(_, r) | Self::Right(r) => Some(r),
+            Self::Left(_) => None,
+        }
+    }
+
+    /// Returns both values if both are present; None otherwise.
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            Self::Both(l, r) => Some((l, r)),
+            _ => None,
+        }
+    }
+
+    /// Converts into a pair of optional values.
+    pub fn into_options(self) -> (Option<L>, Option<R>) {
+        match self {
+            Self::Both(l, r) => (Some(l), Some(r)),
+            Self::Left(l) => (Some(l), None),
+            Self::Right(r) => (None, Some(r)),
+        }
+    }
+}
+
+/// An iterable created by zipping two iterables until both of them are exhausted.
+///
+/// Unlike most transformations, there exists no collection counterpart of this iterable.
+/// A [`Collection`] yields references to elements it owns; whereas the `EitherOrBoth` values created by zipping
+/// are constructed on the fly and are not stored anywhere to be referenced.
+///
+/// [`Collection`]: crate::Collection
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZippedLongest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+}
+
+impl<I1, I2> Iterable for ZippedLongest<I1, I2>
+where
+    I1: Iterable,
+    I2: Iterable,
+{
+    type Item = EitherOrBoth<I1::Item, I2::Item>;
+
+    type Iter = ZippedLongestIter<I1::Iter, I2::Iter>;
+
+    fn iter(&self) -> Self::Iter {
+        ZippedLongestIter {
+            iter1: self.it1.iter().fuse(),
+            iter2: self.it2.iter().fuse(),
+        }
+    }
+}
+
+/// Iterator zipping two iterators until both of them are exhausted.
+pub struct ZippedLongestIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator,
+{
+    pub(crate) iter1: core::iter::Fuse<I1>,
+    pub(crate) iter2: core::iter::Fuse<I2>,
+}
+
+impl<I1, I2> Iterator for ZippedLongestIter<I1, I2>
+where
+    I1: Iterator,
+    I2: Iterator,
+{
+    type Item = EitherOrBoth<I1::Item, I2::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.iter1.next(), self.iter2.next()) {
+            (Some(x), Some(y)) => Some(EitherOrBoth::Both(x, y)),
+            (Some(x), None) => Some(EitherOrBoth::Left(x)),
+            (None, Some(y)) => Some(EitherOrBoth::Right(y)),
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.iter1.size_hint();
+        let (lower2, upper2) = self.iter2.size_hint();
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => Some(x.max(y)),
+            _ => None,
+        };
+        (lower1.max(lower2), upper)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{debug_fn::DebugFn, Iterable};
+
+/// An iterable created by zipping two iterables and mapping each pair into a new value.
+///
+/// Unlike most transformations, there exists no collection counterpart of this iterable.
+/// A [`Collection`] yields references to elements it owns; whereas the pairs created by zipping
+/// are constructed on the fly and are not stored anywhere to be referenced.
+///
+/// [`Collection`]: crate::Collection
+pub struct ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable,
+    I2: Iterable,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+    pub(crate) it1: I1,
+    pub(crate) it2: I2,
+    pub(crate) map: M,
+}
+
+impl<I1, I2, M, U> Clone for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable + Clone,
+    I2: Iterable + Clone,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+    fn clone(&self) -> Self {
+        Self {
+            it1: self.it1.clone(),
+            it2: self.it2.clone(),
+            map: self.map,
+        }
+    }
+}
+
+impl<I1, I2, M, U> Copy for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable + Copy,
+    I2: Iterable + Copy,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+}
+
+impl<I1, I2, M, U> core::fmt::Debug for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable + core::fmt::Debug,
+    I2: Iterable + core::fmt::Debug,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ZippedWith")
+            .field("it1", &self.it1)
+            .field("it2", &self.it2)
+            .field("map", &DebugFn)
+            .finish()
+    }
+}
+
+impl<I1, I2, M, U> PartialEq for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable + PartialEq,
+    I2: Iterable + PartialEq,
+    M: Fn(I1::Item, I2::Item) -> U + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.it1 == other.it1 && self.it2 == other.it2 && self.map == other.map
+    }
+}
+
+impl<I1, I2, M, U> Iterable for ZippedWith<I1, I2, M, U>
+where
+    I1: Iterable,
+    I2: Iterable,
+    M: Fn(I1::Item, I2::Item) -> U + Copy,
+{
+    type Item = U;
+
+    type Iter = ZippedWithIter<I1::Iter, I2::Iter, M, U>;
+
+    fn iter(&self) -> Self::Iter {
+        ZippedWithIter {
+            iter1: self.it1.iter(),
+            iter2: self.it2.iter(),
+            map: self.map,
+        }
+    }
+}
+
+/// Iterator zipping two iterators and mapping each pair into a new value.
+pub struct ZippedWithIter<I1, I2, M, U>
+where
+    I1: Iterator,
+    I2: Iterator,
+    M: Fn(I1::Item, I2::Item) -> U,
+{
+    pub(crate) iter1: I1,
+    pub(crate) iter2: I2,
+    pub(crate) map: M,
+}
+
+impl<I1, I2, M, U> Iterator for ZippedWithIter<I1, I2, M, U>
+where
+    I1: Iterator,
+    I2: Iterator,
+    M: Fn(I1::Item, I2::Item) -> U,
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x = self.iter1.next()?;
+        let y = self.iter2.next()?;
+        Some((self.map)(x, y))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower1, upper1) = self.iter1.size_hint();
+        let (lower2, upper2) = self.iter2.size_hint();
+        let upper = match (upper1, upper2) {
+            (Some(x), Some(y)) => Some(x.min(y)),
+            (Some(x), None) | (None, Some(x)) => Some(x),
+            (None, None) => None,
+        };
+        (lower1.min(lower2), upper)
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{
+    transformations::{Errors, OkValues, TryFiltered, TryMapped},
+    Iterable,
+};
+use core::iter::Sum;
+
+/// A `TryIterable` is an [`Iterable`] yielding fallible elements of type `Result<Value, Error>`.
+///
+/// It is automatically implemented for all iterables with `Result` elements and provides
+/// transformations on the `Ok` side of the elements which propagate errors, as well as
+/// short-circuiting reducers similar to those used with the `?` operator.
+///
+/// Transformations do not change re-iterability: every call to `iter` of a transformed
+/// `TryIterable` creates a new iterator over the source.
+///
+/// # Examples
+///
+/// ```
+/// use orx_iterable::*;
+///
+/// let lines = ["1", "2", "x", "4"];
+/// let parse = |x: &&str| x.parse::<i32>();
+///
+/// assert_eq!(lines.mapped(parse).ok_values().iter().sum::<i32>(), 7);
+/// assert_eq!(lines.mapped(parse).errors().iter().count(), 1);
+///
+/// let doubled = lines.mapped(parse).try_mapped(|x| x * 2);
+/// assert!(doubled.try_sum::<i32>().is_err());
+/// assert_eq!(doubled.iter().count(), 3);
+///
+/// let valid = lines.taken(2).mapped(|x| x.parse::<i32>());
+/// assert_eq!(valid.try_collect::<Vec<_>>(), Ok(vec![1, 2]));
+/// ```
+pub trait TryIterable: Iterable<Item = Result<Self::Value, Self::Error>> {
+    /// Type of the values of the `Ok` elements.
+    type Value;
+
+    /// Type of the errors of the `Err` elements.
+    type Error;
+
+    // provided
+
+    /// Creates an iterable which yields only the errors of the `Err` elements of this iterable,
+    /// skipping the `Ok` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [Ok(1), Err('a'), Ok(2), Err('b')];
+    ///
+    /// let it = a.copied().errors();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), ['a', 'b']);
+    /// ```
+    fn errors(self) -> Errors<Self>
+    where
+        Self: Sized,
+    {
+        Errors { it: self }
+    }
+
+    /// Creates an iterable which yields only the values of the `Ok` elements of this iterable,
+    /// skipping the `Err` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [Ok(1), Err('a'), Ok(2), Err('b')];
+    ///
+    /// let it = a.copied().ok_values();
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(it.iter().sum::<i32>(), 3);
+    /// ```
+    fn ok_values(self) -> OkValues<Self>
+    where
+        Self: Sized,
+    {
+        OkValues { it: self }
+    }
+
+    /// Collects the values of the elements into a collection of type `B`; or returns the first error
+    /// if any of the elements is an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = ["1", "2", "3"];
+    /// let it = a.mapped(|x| x.parse::<i32>());
+    /// assert_eq!(it.try_collect::<Vec<_>>(), Ok(vec![1, 2, 3]));
+    ///
+    /// let a = ["1", "x", "3"];
+    /// let it = a.mapped(|x| x.parse::<i32>());
+    /// assert!(it.try_collect::<Vec<_>>().is_err());
+    /// ```
+    fn try_collect<B>(&self) -> Result<B, Self::Error>
+    where
+        B: FromIterator<Self::Value>,
+    {
+        self.iter().collect()
+    }
+
+    /// Creates an iterable which yields only the `Ok` elements with values satisfying the `filter`.
+    ///
+    /// `Err` elements are yielded as they are; however, iteration short-circuits after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [Ok(1), Ok(2), Ok(3), Ok(4)];
+    /// let it = a.copied().try_filtered(|x| x % 2 == 0);
+    /// assert_eq!(it.iter().collect::<Vec<Result<i32, char>>>(), [Ok(2), Ok(4)]);
+    ///
+    /// let a = [Ok(1), Ok(2), Err('x'), Ok(4)];
+    /// let it = a.copied().try_filtered(|x| x % 2 == 0);
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(2), Err('x')]);
+    /// ```
+    fn try_filtered<P>(self, filter: P) -> TryFiltered<Self, P>
+    where
+        Self: Sized,
+        P: Fn(&Self::Value) -> bool + Copy,
+    {
+        TryFiltered { it: self, filter }
+    }
+
+    /// Calls the function `f` on the values of the elements; stops and returns the first error if any of the
+    /// elements is an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [Ok(1), Ok(2), Err('x'), Ok(4)];
+    ///
+    /// let mut sum = 0;
+    /// let result = a.copied().try_for_each(|x| sum += x);
+    ///
+    /// assert_eq!(result, Err('x'));
+    /// assert_eq!(sum, 3);
+    /// ```
+    fn try_for_each<F>(&self, mut f: F) -> Result<(), Self::Error>
+    where
+        F: FnMut(Self::Value),
+    {
+        for x in self.iter() {
+            f(x?);
+        }
+        Ok(())
+    }
+
+    /// Creates an iterable which maps the values of the `Ok` elements by the `map` function.
+    ///
+    /// `Err` elements are yielded as they are; however, iteration short-circuits after the first error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = [Ok(1), Ok(2), Err('x'), Ok(4)];
+    ///
+    /// let it = a.copied().try_mapped(|x| x * 10);
+    ///
+    /// assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(10), Ok(20), Err('x')]);
+    /// assert_eq!(it.iter().collect::<Result<Vec<_>, _>>(), Err('x'));
+    /// ```
+    fn try_mapped<M, U>(self, map: M) -> TryMapped<Self, M, U>
+    where
+        Self: Sized,
+        M: Fn(Self::Value) -> U + Copy,
+    {
+        TryMapped { it: self, map }
+    }
+
+    /// Sums up the values of the elements; or returns the first error if any of the elements is an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use orx_iterable::*;
+    ///
+    /// let a = ["1", "2", "3"];
+    /// assert_eq!(a.mapped(|x| x.parse::<i32>()).try_sum::<i32>(), Ok(6));
+    ///
+    /// let a = ["1", "x", "3"];
+    /// assert!(a.mapped(|x| x.parse::<i32>()).try_sum::<i32>().is_err());
+    /// ```
+    fn try_sum<S>(&self) -> Result<S, Self::Error>
+    where
+        S: Sum<Self::Value>,
+    {
+        self.iter().sum()
+    }
+}
+
+impl<I, T, E> TryIterable for I
+where
+    I: Iterable<Item = Result<T, E>>,
+{
+    type Value = T;
+
+    type Error = E;
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::*;
+use std::cell::Cell;
+
+fn test_it(values: Vec<usize>, col: impl Iterable<Item = usize>) {
+    let sum = values.iter().sum::<usize>();
+    let count = values.len();
+
+    // tests
+    assert_eq!(col.iter().count(), count);
+    assert_eq!(col.iter().sum::<usize>(), sum);
+}
+
+#[test]
+fn cached() {
+    let num_calls = Cell::new(0);
+    let a = vec![1, 3, 7, 2, 8];
+    let it = a
+        .mapped(|x| {
+            num_calls.set(num_calls.get() + 1);
+            x * 10
+        })
+        .cached();
+    assert!(!it.is_cached());
+    assert_eq!(num_calls.get(), 0);
+
+    test_it(vec![10, 30, 70, 20, 80], it.copied());
+    assert!(it.is_cached());
+    assert_eq!(num_calls.get(), 5);
+
+    test_it(vec![10, 30, 70, 20, 80], it.copied());
+    assert_eq!(num_calls.get(), 5);
+}
+
+#[test]
+fn cached_partial_first_pass_materializes_all() {
+    let num_calls = Cell::new(0);
+    let it = (0..5usize)
+        .mapped(|x| {
+            num_calls.set(num_calls.get() + 1);
+            x
+        })
+        .cached();
+
+    assert_eq!(it.iter().next(), Some(&0));
+    assert_eq!(num_calls.get(), 5);
+    assert_eq!(it.materialize(), [0, 1, 2, 3, 4]);
+    assert_eq!(num_calls.get(), 5);
+}
+
+#[test]
+fn cached_invalidate() {
+    let offset = Cell::new(0);
+    let mut it = (0..3usize).mapped(|x| x + offset.get()).cached();
+    test_it(vec![0, 1, 2], it.copied());
+
+    offset.set(10);
+    test_it(vec![0, 1, 2], it.copied());
+
+    it.invalidate();
+    assert!(!it.is_cached());
+    test_it(vec![10, 11, 12], it.copied());
+}
+
+#[test]
+fn cached_mut() {
+    let a = vec![1, 3, 7, 2, 8];
+    let mut it = a.copied().cached();
+    for x in it.iter_mut() {
+        *x += 10;
+    }
+    test_it(vec![11, 13, 17, 12, 18], it.copied());
+
+    it.invalidate();
+    test_it(vec![1, 3, 7, 2, 8], it.copied());
+    assert_eq!(it.into_inner().iter().count(), 5);
+}
+
+#[test]
+fn cached_col_transformations() {
+    let a = vec![1, 3, 7, 2, 8];
+    let it = a.copied().mapped(|x| x * 2).cached();
+    let col = it.into_filtered(|x| *x > 5).into_taken(2);
+    test_it(vec![6, 14], col.copied());
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_cached() {
+        let a = vec![1, 3, 7, 2, 8];
+        let mut it = a.copied().mapped(|x| x + 1).cached();
+
+        let col: &mut dyn CollectionMutObj<Item = usize> = &mut it;
+        for x in col.boxed_iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(col.boxed_iter().sum::<usize>(), 52);
+
+        let it: &dyn IterableObj<Item = &usize> = &&it;
+        assert_eq!(it.boxed_iter().count(), 5);
+    }
+}
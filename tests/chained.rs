@@ -7,7 +7,7 @@ use std::collections::VecDeque;
 fn chained() {
     let a = vec![1, 3, 4];
     let b = [8, 10];
-    let c = VecDeque::from_iter([2, 7].into_iter());
+    let c = VecDeque::from_iter([2, 7]);
 
     test_it(vec![1, 3, 4, 8, 10], a.chained(&b));
     test_it(vec![1, 3, 4, 8, 10, 2, 7], a.chained(&b).chained(&c));
@@ -22,7 +22,7 @@ fn into_chained() {
 
     let a = vec![1, 3, 4];
     let b = [8, 10];
-    let c = VecDeque::from_iter([2, 7].into_iter());
+    let c = VecDeque::from_iter([2, 7]);
     test_col(
         vec![1, 3, 4, 8, 10, 2, 7],
         a.into_chained(b).into_chained(c),
@@ -37,7 +37,7 @@ fn chained_mut() {
 
     let mut a = vec![1, 3, 4];
     let mut b = [8, 10];
-    let mut c = VecDeque::from_iter([2, 7].into_iter());
+    let mut c = VecDeque::from_iter([2, 7]);
     test_col(
         vec![1, 3, 4, 8, 10, 2, 7],
         a.chained_mut(&mut b).chained_mut(&mut c),
@@ -53,7 +53,7 @@ fn obj_chained() {
 
     let a = vec![1, 3, 4];
     let b = [8, 10];
-    let c = VecDeque::from_iter([2, 7].into_iter());
+    let c = VecDeque::from_iter([2, 7]);
 
     obj_test_it(vec![1, 3, 4, 8, 10], &a.chained(&b));
     obj_test_it(vec![1, 3, 4, 8, 10, 2, 7], &a.chained(&b).chained(&c));
@@ -71,7 +71,7 @@ fn obj_into_chained() {
 
     let a = vec![1, 3, 4];
     let b = [8, 10];
-    let c = VecDeque::from_iter([2, 7].into_iter());
+    let c = VecDeque::from_iter([2, 7]);
     obj_test_col(
         vec![1, 3, 4, 8, 10, 2, 7],
         &a.into_chained(b).into_chained(c),
@@ -89,7 +89,7 @@ fn obj_chained_mut() {
 
     let mut a = vec![1, 3, 4];
     let mut b = [8, 10];
-    let mut c = VecDeque::from_iter([2, 7].into_iter());
+    let mut c = VecDeque::from_iter([2, 7]);
     obj_test_col(
         vec![1, 3, 4, 8, 10, 2, 7],
         &a.chained_mut(&mut b).chained_mut(&mut c),
@@ -0,0 +1,104 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::*;
+use std::cell::{Cell, RefCell};
+
+#[test]
+fn checked_multipass_deterministic() {
+    let a = vec![1, 3, 7, 2, 8];
+    let it = a.checked_multipass();
+    assert_eq!(it.fingerprint(), None);
+
+    assert_eq!(it.iter().sum::<usize>(), 21);
+    let fingerprint = it.fingerprint().unwrap();
+    assert_eq!(fingerprint.len, 5);
+
+    for _ in 0..3 {
+        assert_eq!(it.iter().count(), 5);
+    }
+    assert_eq!(it.fingerprint(), Some(fingerprint));
+    assert_eq!(it.completed_passes(), 4);
+}
+
+#[test]
+fn checked_multipass_partial_passes_are_not_checked() {
+    let a = vec![1, 3, 7, 2, 8];
+    let it = a.checked_multipass();
+
+    assert_eq!(it.iter().take(2).count(), 2);
+    assert_eq!(it.iter().find(|x| **x == 7), Some(&7));
+    assert_eq!(it.completed_passes(), 0);
+    assert_eq!(it.fingerprint(), None);
+
+    assert_eq!(it.iter().count(), 5);
+    assert_eq!(it.iter().take(1).count(), 1);
+    assert_eq!(it.completed_passes(), 1);
+}
+
+#[test]
+#[should_panic]
+fn checked_multipass_panics_on_mismatch() {
+    let data = RefCell::new(vec![1, 2, 3]);
+    let it = (0..3usize).mapped(|i| data.borrow()[i]).checked_multipass();
+
+    assert_eq!(it.iter().count(), 3);
+    data.borrow_mut()[1] = 42;
+    let _ = it.iter().count();
+}
+
+#[test]
+fn checked_multipass_with_reports_mismatches() {
+    let len = Cell::new(3);
+    let reports = RefCell::new(vec![]);
+    let it = (0..10)
+        .filtered(|x| *x < len.get())
+        .checked_multipass_with(|m| reports.borrow_mut().push(*m));
+
+    assert_eq!(it.iter().count(), 3);
+    assert_eq!(it.iter().count(), 3);
+
+    len.set(5);
+    assert_eq!(it.iter().count(), 5);
+    len.set(3);
+    assert_eq!(it.iter().count(), 3);
+    len.set(2);
+    assert_eq!(it.iter().count(), 2);
+
+    let reports = reports.borrow();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].pass, 2);
+    assert_eq!(reports[0].expected.len, 3);
+    assert_eq!(reports[0].actual.len, 5);
+    assert_eq!(reports[1].pass, 4);
+    assert_eq!(reports[1].actual.len, 2);
+    assert_eq!(reports[1].expected, it.fingerprint().unwrap());
+}
+
+#[test]
+fn checked_multipass_with_same_length_different_elements() {
+    let offset = Cell::new(0);
+    let mismatches = Cell::new(0);
+    let it = (0..4)
+        .mapped(|x| x + offset.get())
+        .checked_multipass_with(|_| mismatches.set(mismatches.get() + 1));
+
+    assert_eq!(it.iter().count(), 4);
+    offset.set(1);
+    assert_eq!(it.iter().count(), 4);
+    assert_eq!(mismatches.get(), 1);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_checked_multipass() {
+        let a = vec![1, 3, 7, 2, 8];
+        let it = a.checked_multipass();
+        let obj: &dyn IterableObj<Item = &usize> = &it;
+        assert_eq!(obj.boxed_iter().count(), 5);
+        assert_eq!(obj.boxed_iter().sum::<usize>(), 21);
+        assert_eq!(it.completed_passes(), 2);
+    }
+}
@@ -0,0 +1,79 @@
+use orx_iterable::*;
+
+fn windows_of<I: Iterable>(it: &I, size: usize) -> Vec<Vec<I::Item>> {
+    it.circular_windows(size)
+        .iter()
+        .map(|w| w.iter().collect())
+        .collect()
+}
+
+#[test]
+fn circular_windows() {
+    let a = vec![1, 2, 3];
+    let numbers = a.copied();
+
+    assert_eq!(
+        windows_of(&numbers, 2),
+        [vec![1, 2], vec![2, 3], vec![3, 1]]
+    );
+    assert_eq!(windows_of(&numbers, 1), [vec![1], vec![2], vec![3]]);
+    assert_eq!(
+        windows_of(&numbers, 3),
+        [vec![1, 2, 3], vec![2, 3, 1], vec![3, 1, 2]]
+    );
+}
+
+#[test]
+fn circular_windows_larger_than_source() {
+    let a = vec![1, 2];
+    assert_eq!(
+        windows_of(&a.copied(), 5),
+        [vec![1, 2, 1, 2, 1], vec![2, 1, 2, 1, 2]]
+    );
+
+    let a = vec![7];
+    assert_eq!(windows_of(&a.copied(), 3), [vec![7, 7, 7]]);
+}
+
+#[test]
+fn circular_windows_empty() {
+    let a: Vec<i32> = vec![];
+    assert!(windows_of(&a.copied(), 2).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn circular_windows_zero_size() {
+    let a = vec![1, 2];
+    let _ = a.copied().circular_windows(0);
+}
+
+#[test]
+fn circular_window_start() {
+    let a = vec!['a', 'b', 'c', 'd'];
+    let source = &a;
+    let windows = source.circular_windows(2);
+
+    let starts: Vec<_> = windows.iter().map(|w| w.start()).collect();
+    assert_eq!(starts, [0, 1, 2, 3]);
+
+    let last = windows.iter().last().unwrap();
+    assert_eq!(last.iter().collect::<Vec<_>>(), [&'d', &'a']);
+    assert_eq!(last.iter().collect::<Vec<_>>(), [&'d', &'a']);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_circular_windows() {
+        let a = vec![1, 2, 3];
+        let numbers = a.copied();
+        let windows = numbers.circular_windows(2);
+
+        let obj: &dyn IterableObj<Item = _> = &windows;
+        let sums: Vec<i32> = obj.boxed_iter().map(|w| w.boxed_iter().sum()).collect();
+        assert_eq!(sums, [3, 5, 4]);
+    }
+}
@@ -0,0 +1,115 @@
+use orx_iterable::*;
+
+fn is_copy<T: Copy>(_: &T) {}
+
+#[test]
+fn clone_common_prefix() {
+    let a = vec![1, 3, 7, 2, 8, 4];
+    let prefix = a.iter().into_iterable().filtered(|x| *x % 2 == 0).copied();
+
+    let doubled = prefix.clone().mapped(|x| x * 2);
+    let taken = prefix.taken(2);
+
+    assert_eq!(doubled.iter().collect::<Vec<_>>(), [4, 16, 8]);
+    assert_eq!(taken.iter().collect::<Vec<_>>(), [2, 8]);
+}
+
+#[test]
+fn copy_when_all_parts_are_copy() {
+    let a = [1, 3, 7, 2, 8, 4];
+    let it = a.filtered(|x| *x > &2).mapped(|x| x * 10).skipped(1);
+    is_copy(&it);
+
+    let other = it;
+    assert_eq!(it.iter().count(), other.iter().count());
+
+    let it = a.stepped_by(3).zipped(repeat_n('x', 5)).enumerated();
+    is_copy(&it);
+    assert_eq!(it.iter().count(), 2);
+
+    let it = once(42).chained(empty()).reversed();
+    is_copy(&it);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [42]);
+}
+
+#[test]
+fn debug_shows_pipeline_structure() {
+    let a = vec![1, 2, 3];
+    let it = a.filtered(|x| **x > 1).taken(5);
+    assert_eq!(
+        format!("{:?}", it),
+        "Taken { it: Filtered { it: [1, 2, 3], filter: <fn> }, n: 5 }"
+    );
+
+    let it = (0..3).zipped(repeat(1)).mapped(|(a, b)| a + b);
+    assert_eq!(
+        format!("{:?}", it),
+        "Mapped { it: Zipped { it1: 0..3, it2: Repeat { value: 1 } }, map: <fn> }"
+    );
+
+    let it = vec![1, 2].into_taken(1);
+    assert_eq!(format!("{:?}", it), "TakenCol { it: [1, 2], n: 1 }");
+
+    assert_eq!(format!("{:?}", empty::<i32>()), "Empty");
+}
+
+#[test]
+fn partial_eq() {
+    let a = vec![1, 2, 3];
+    assert_eq!(a.clone().taken(2), a.clone().taken(2));
+    assert_ne!(a.clone().taken(2), a.clone().taken(3));
+    assert_ne!(a.clone().taken(2), vec![1, 2].taken(2));
+
+    fn is_odd(x: &&i32) -> bool {
+        **x % 2 == 1
+    }
+    let f = is_odd as fn(&&i32) -> bool;
+    assert_eq!(a.filtered(f), a.filtered(f));
+
+    assert_eq!(repeat_n('a', 3), repeat_n('a', 3));
+    assert_eq!(once_col(1), once_col(1));
+    assert_eq!(empty::<i32>(), empty::<i32>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn clone_checked_multipass_starts_fresh() {
+    let a = [1, 2, 3];
+    let it = a.checked_multipass();
+    assert_eq!(it.iter().count(), 3);
+    assert_eq!(it.completed_passes(), 1);
+
+    let cloned = it.clone();
+    assert_eq!(cloned.completed_passes(), 0);
+    assert_eq!(cloned.fingerprint(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn clone_instrumented_starts_fresh() {
+    let a = vec![1, 2, 3];
+    let it = a.copied().instrumented();
+    assert_eq!(it.iter().count(), 3);
+
+    let cloned = it.clone();
+    assert_eq!(cloned.stats().passes(), 0);
+    assert_eq!(cloned.iter().take(2).count(), 2);
+
+    assert_eq!(it.stats().items_per_pass(), [3]);
+    assert_eq!(cloned.stats().items_per_pass(), [2]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn clone_instrumented_col_starts_fresh() {
+    let col = vec![1, 2, 3].into_instrumented();
+    assert_eq!(col.iter().count(), 3);
+
+    let mut cloned = col.clone();
+    assert_eq!(cloned.stats().passes(), 0);
+    cloned.iter_mut().for_each(|x| *x += 1);
+    assert_eq!(cloned.iter().sum::<i32>(), 9);
+
+    assert_eq!(col.stats().items_per_pass(), [3]);
+    assert_eq!(cloned.stats().items_per_pass(), [3, 3]);
+}
@@ -9,8 +9,8 @@ fn std_collections() {
 
     test_col(values(), [1, 3, 7]);
     test_col(values(), vec![1, 3, 7]);
-    test_col(values(), VecDeque::from_iter([1, 3, 7].into_iter()));
-    test_col(values(), LinkedList::from_iter([1, 3, 7].into_iter()));
+    test_col(values(), VecDeque::from_iter([1, 3, 7]));
+    test_col(values(), LinkedList::from_iter([1, 3, 7]));
 
     test_col(vec![10], Some(10));
     test_col(vec![], None);
@@ -40,8 +40,8 @@ fn obj_std_collections() {
 
     obj_test_col(values(), &[1, 3, 7]);
     obj_test_col(values(), &vec![1, 3, 7]);
-    obj_test_col(values(), &VecDeque::from_iter([1, 3, 7].into_iter()));
-    obj_test_col(values(), &LinkedList::from_iter([1, 3, 7].into_iter()));
+    obj_test_col(values(), &VecDeque::from_iter([1, 3, 7]));
+    obj_test_col(values(), &LinkedList::from_iter([1, 3, 7]));
 }
 
 #[cfg(feature = "std")]
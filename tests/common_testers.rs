@@ -32,9 +32,9 @@ pub fn test_col(values: Vec<usize>, col: impl Collection<Item = usize>) {
 // obj
 
 #[cfg(feature = "std")]
-pub fn obj_test_it<'a>(
+pub fn obj_test_it(
     values: Vec<usize>,
-    col: &dyn orx_iterable::obj_safe::IterableObj<Item = &'a usize>,
+    col: &dyn orx_iterable::obj_safe::IterableObj<Item = &usize>,
 ) {
     let sum = values.iter().sum::<usize>();
     let count = values.len();
@@ -19,22 +19,22 @@ fn copied_cloned() {
     test(values(), vec![1, 3, 7].copied());
     test(
         values(),
-        VecDeque::from_iter([1, 3, 7].into_iter()).copied(),
+        VecDeque::from_iter([1, 3, 7]).copied(),
     );
     test(
         values(),
-        LinkedList::from_iter([1, 3, 7].into_iter()).copied(),
+        LinkedList::from_iter([1, 3, 7]).copied(),
     );
 
     test(values(), [1, 3, 7].cloned());
     test(values(), vec![1, 3, 7].cloned());
     test(
         values(),
-        VecDeque::from_iter([1, 3, 7].into_iter()).copied(),
+        VecDeque::from_iter([1, 3, 7]).copied(),
     );
     test(
         values(),
-        LinkedList::from_iter([1, 3, 7].into_iter()).copied(),
+        LinkedList::from_iter([1, 3, 7]).copied(),
     );
 
     let col = custom_iterables::EvensThenOdds {
@@ -64,22 +64,22 @@ fn obj_copied_cloned() {
     test(values(), &vec![1, 3, 7].copied());
     test(
         values(),
-        &VecDeque::from_iter([1, 3, 7].into_iter()).copied(),
+        &VecDeque::from_iter([1, 3, 7]).copied(),
     );
     test(
         values(),
-        &LinkedList::from_iter([1, 3, 7].into_iter()).copied(),
+        &LinkedList::from_iter([1, 3, 7]).copied(),
     );
 
     test(values(), &[1, 3, 7].cloned());
     test(values(), &vec![1, 3, 7].cloned());
     test(
         values(),
-        &VecDeque::from_iter([1, 3, 7].into_iter()).copied(),
+        &VecDeque::from_iter([1, 3, 7]).copied(),
     );
     test(
         values(),
-        &LinkedList::from_iter([1, 3, 7].into_iter()).copied(),
+        &LinkedList::from_iter([1, 3, 7]).copied(),
     );
 
     let col = custom_iterables::EvensThenOdds {
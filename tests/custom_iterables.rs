@@ -11,7 +11,7 @@ impl IntoIterator for EvensThenOddsCol {
     type IntoIter = core::iter::Chain<std::vec::IntoIter<usize>, std::vec::IntoIter<usize>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.evens.into_iter().chain(self.odds.into_iter())
+        self.evens.into_iter().chain(self.odds)
     }
 }
 
@@ -0,0 +1,93 @@
+mod common_testers;
+use common_testers::{test_col, test_it};
+use orx_iterable::*;
+
+#[test]
+fn deduped() {
+    let a = vec![1, 1, 3, 4, 4, 4, 8, 1, 1];
+
+    test_it(vec![1, 3, 4, 8, 1], a.deduped());
+    test_it(vec![1, 3, 4, 8, 1], a.deduped().deduped());
+    test_it(vec![], Vec::<usize>::new().deduped());
+    test_it(vec![7], [7, 7, 7].deduped());
+}
+
+#[test]
+fn deduped_by() {
+    let a = vec![1, 2, 3, 4, 5, 7, 6, 8];
+
+    test_it(
+        vec![1, 2, 3, 4, 5, 6],
+        a.deduped_by(|x, y| *x % 2 == *y % 2),
+    );
+    test_it(vec![1, 3, 5, 7, 6, 8], a.deduped_by(|x, y| **y == **x + 1));
+}
+
+#[test]
+fn deduped_by_key() {
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 12];
+
+    test_it(vec![1, 4, 7, 10], a.deduped_by_key(|x| *x % 2));
+    test_it(vec![1, 4, 8, 7, 10, 12], a.deduped_by_key(|x| *x / 4));
+    test_it(vec![1], a.deduped_by_key(|_| 0));
+}
+
+#[test]
+fn into_deduped() {
+    let a = vec![1, 1, 3, 4, 4, 4, 8, 1, 1];
+    test_col(vec![1, 3, 4, 8, 1], a.into_deduped());
+
+    let a = vec![1, 2, 3, 4, 5, 7, 6, 8];
+    test_col(
+        vec![1, 2, 3, 4, 5, 6],
+        a.into_deduped_by(|x, y| x % 2 == y % 2),
+    );
+
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 12];
+    test_col(vec![1, 4, 7, 10], a.into_deduped_by_key(|x| x % 2));
+}
+
+#[test]
+fn into_deduped_iter_mut() {
+    let a = vec![1, 1, 3, 4, 4, 4, 8, 1, 1];
+    let mut it = a.into_deduped();
+    let mutated: Vec<_> = it
+        .iter_mut()
+        .map(|x| {
+            *x += 10;
+            *x
+        })
+        .collect();
+    assert_eq!(mutated, [11, 13, 14, 18, 11]);
+
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 12];
+    let mut it = a.into_deduped_by_key(|x| x % 2);
+    for x in it.iter_mut() {
+        *x += 10;
+    }
+    test_col(vec![11, 14, 17, 20], it);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_deduped() {
+    use common_testers::{obj_test_col, obj_test_it};
+    use orx_iterable::obj_safe::*;
+
+    let a = vec![1, 1, 3, 4, 4, 4, 8, 1, 1];
+
+    obj_test_it(vec![1, 3, 4, 8, 1], &a.deduped());
+    obj_test_it(vec![1, 2, 3], &[1, 2, 2, 3].deduped_by(|x, y| x == y));
+    obj_test_it(vec![1, 4, 1], &a.deduped_by_key(|x| **x > 3));
+
+    obj_test_col(vec![1, 3, 4, 8, 1], &a.clone().into_deduped());
+    obj_test_col(vec![1, 4, 1], &a.clone().into_deduped_by_key(|x| *x > 3));
+
+    let mut it = a.into_deduped_by_key(|x| *x % 2);
+    for x in it.boxed_iter_mut() {
+        *x += 10;
+    }
+    obj_test_col(vec![11, 14, 11], &it);
+}
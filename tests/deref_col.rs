@@ -0,0 +1,110 @@
+#![cfg(feature = "std")]
+
+mod common_testers;
+use common_testers::{test_col, test_it};
+use orx_iterable::*;
+use std::{borrow::Cow, collections::VecDeque, rc::Rc, sync::Arc};
+
+#[test]
+fn rc_arc() {
+    let rc = Rc::new(vec![1usize, 2, 3]);
+    test_col(vec![1, 2, 3], rc.clone().into_col());
+    test_it(vec![1, 2, 3], &rc.clone().into_col());
+
+    let rc: Rc<[usize]> = Rc::from(vec![1, 2, 3]);
+    test_col(vec![1, 2, 3], rc.clone().into_col());
+    test_it(vec![1, 2, 3], &rc.into_col());
+
+    let arc = Arc::new(VecDeque::from_iter([1usize, 2, 3]));
+    test_col(vec![1, 2, 3], arc.clone().into_col());
+    test_it(vec![1, 2, 3], &arc.into_col());
+
+    let arc: Arc<[usize]> = Arc::from(vec![1, 2, 3]);
+    test_col(vec![1, 2, 3], arc.into_col());
+}
+
+#[test]
+fn boxed() {
+    let mut col = Box::new(vec![1usize, 2, 3]).into_col();
+    col.iter_mut().for_each(|x| *x *= 10);
+    test_col(vec![10, 20, 30], col);
+
+    let mut col = Box::<[usize]>::from(vec![1, 2, 3]).into_col();
+    col.iter_mut().for_each(|x| *x += 1);
+    test_it(vec![2, 3, 4], &col);
+    test_col(vec![2, 3, 4], col);
+}
+
+#[test]
+fn boxed_slice_without_wrapper() {
+    let mut col = Box::<[usize]>::from(vec![1, 2, 3]);
+    col.iter_mut().for_each(|x| *x *= 2);
+    test_col(vec![2, 4, 6], col);
+}
+
+#[test]
+fn cow() {
+    let vec = vec![1usize, 2, 3];
+
+    let cow: Cow<'_, [usize]> = Cow::Borrowed(&vec);
+    test_col(vec![1, 2, 3], cow.into_col());
+
+    let cow: Cow<'_, [usize]> = Cow::Owned(vec![4, 5]);
+    test_col(vec![4, 5], cow.into_col());
+
+    let deque = VecDeque::from_iter([1usize, 2, 3]);
+    let cow: Cow<'_, VecDeque<usize>> = Cow::Borrowed(&deque);
+    test_it(vec![1, 2, 3], &cow.into_col());
+}
+
+#[test]
+fn shared_source_of_transformations() {
+    let numbers: Rc<[usize]> = Rc::from(vec![1, 2, 3, 4, 5, 6]);
+
+    let evens = numbers.clone().into_col().into_filtered(|x| x % 2 == 0);
+    let odds = numbers.clone().into_col().into_filtered(|x| x % 2 == 1);
+    let rev = numbers.clone().into_col().into_reversed();
+
+    test_col(vec![2, 4, 6], evens);
+    test_col(vec![1, 3, 5], odds);
+    assert_eq!(rev.iter().copied().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1]);
+    assert_eq!(Rc::strong_count(&numbers), 2);
+
+    let col = numbers.clone().into_col();
+    assert!(Rc::ptr_eq(col.pointer(), &numbers));
+    assert!(Rc::ptr_eq(&col.into_pointer(), &numbers));
+}
+
+#[test]
+fn struct_fields() {
+    struct Data {
+        values: DerefCol<Arc<Vec<usize>>>,
+    }
+
+    fn total(values: &impl Collection<Item = usize>) -> usize {
+        values.iter().sum()
+    }
+
+    let values = Arc::new(vec![1, 2, 3]);
+    let data = Data {
+        values: values.clone().into_col(),
+    };
+    assert_eq!(total(&data.values), 6);
+    assert_eq!(data.values.iter().count(), 3);
+    assert_eq!(Arc::strong_count(&values), 2);
+}
+
+// obj
+
+#[test]
+fn obj_deref_col() {
+    use common_testers::{obj_test_col, obj_test_it};
+
+    let rc: Rc<[usize]> = Rc::from(vec![1, 2, 3]);
+    obj_test_col(vec![1, 2, 3], &rc.clone().into_col());
+    obj_test_it(vec![1, 2, 3], &&rc.into_col());
+
+    let mut col = Box::new(vec![1usize, 2, 3]).into_col();
+    orx_iterable::obj_safe::CollectionMutObj::boxed_iter_mut(&mut col).for_each(|x| *x += 1);
+    obj_test_col(vec![2, 3, 4], &col);
+}
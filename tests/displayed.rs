@@ -0,0 +1,87 @@
+use orx_iterable::*;
+
+#[test]
+fn displayed() {
+    let a = vec![1, 2, 3];
+
+    assert_eq!(format!("{}", a.displayed(", ")), "1, 2, 3");
+    assert_eq!(format!("{}", a.displayed("")), "123");
+    assert_eq!(format!("{}", a.taken(1).displayed(", ")), "1");
+    assert_eq!(format!("{}", empty::<i32>().displayed(", ")), "");
+}
+
+#[test]
+fn displayed_prefix_suffix() {
+    let a = ["a", "b"];
+
+    let it = a.displayed(",").prefix("{").suffix("}");
+    assert_eq!(format!("{}", it), "{a,b}");
+
+    let it = empty::<&str>().displayed(",").prefix("{").suffix("}");
+    assert_eq!(format!("{}", it), "{}");
+}
+
+#[test]
+fn displayed_truncated() {
+    let a = vec![1, 2, 3, 4];
+
+    assert_eq!(format!("{}", a.displayed(", ").truncated(2)), "1, 2, ...");
+    assert_eq!(format!("{}", a.displayed(", ").truncated(4)), "1, 2, 3, 4");
+    assert_eq!(format!("{}", a.displayed(", ").truncated(10)), "1, 2, 3, 4");
+    assert_eq!(format!("{}", a.displayed(", ").truncated(0)), "...");
+    assert_eq!(
+        format!("{}", a.displayed("-").prefix("[").suffix("]").truncated(1)),
+        "[1-...]"
+    );
+}
+
+#[test]
+fn displayed_formats_each_element() {
+    let a = [1.5, 2.25];
+
+    assert_eq!(format!("{:.2}", a.displayed(" ")), "1.50 2.25");
+    assert_eq!(format!("{:<4}", a.copied().displayed("|")), "1.5 |2.25");
+}
+
+#[test]
+fn displayed_iterates_on_every_format() {
+    let a = vec![1, 2, 3];
+    let it = a.mapped(|x| x * 2).displayed(" ");
+
+    assert_eq!(format!("{}", it), "2 4 6");
+    assert_eq!(format!("{}", it), "2 4 6");
+}
+
+#[test]
+fn debug_listed() {
+    let a = vec!['x', 'y'];
+
+    assert_eq!(format!("{:?}", a.debug_listed()), "['x', 'y']");
+    assert_eq!(format!("{:?}", empty::<char>().debug_listed()), "[]");
+    assert_eq!(format!("{:#?}", a.debug_listed()), "[\n    'x',\n    'y',\n]");
+}
+
+#[test]
+fn debug_listed_truncated() {
+    let a = vec![1, 2, 3];
+
+    assert_eq!(format!("{:?}", a.debug_listed().truncated(2)), "[1, 2, ..]");
+    assert_eq!(format!("{:?}", a.debug_listed().truncated(3)), "[1, 2, 3]");
+    assert_eq!(format!("{:?}", a.debug_listed().truncated(0)), "[..]");
+}
+
+#[test]
+fn debug_listed_prefix_suffix() {
+    let a = vec![1, 2, 3];
+
+    let it = a.debug_listed().prefix("Vec").suffix(";");
+    assert_eq!(format!("{:?}", it), "Vec[1, 2, 3];");
+    assert_eq!(format!("{:?}", it.truncated(1)), "Vec[1, ..];");
+    assert_eq!(
+        format!("{:#?}", it.truncated(1)),
+        "Vec[\n    1,\n    ..\n];"
+    );
+
+    let it = empty::<i32>().debug_listed().prefix("<").suffix(">");
+    assert_eq!(format!("{:?}", it), "<[]>");
+}
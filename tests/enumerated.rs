@@ -20,13 +20,71 @@ fn enumerated() {
     );
 }
 
+#[test]
+fn enumerated_from() {
+    let a = vec![1, 2, 3, 4];
+    test_it(
+        a.iter().enumerate().map(|(i, x)| (i + 1, *x)).collect(),
+        a.enumerated_from(1),
+    );
+    assert_eq!(
+        a.enumerated_from(5).iter().map(|x| x.0).collect::<Vec<_>>(),
+        [5, 6, 7, 8]
+    );
+}
+
+#[test]
+fn enumerated_by() {
+    let a = vec![1, 2, 3, 4];
+    test_it(
+        a.iter().enumerate().map(|(i, x)| (3 + 2 * i, *x)).collect(),
+        a.enumerated_by(3, 2),
+    );
+    assert_eq!(
+        a.enumerated_by(7, 0)
+            .iter()
+            .map(|x| x.0)
+            .collect::<Vec<_>>(),
+        [7, 7, 7, 7]
+    );
+    assert_eq!(
+        a.taken(1)
+            .enumerated_by(usize::MAX, 1)
+            .iter()
+            .map(|x| x.0)
+            .collect::<Vec<_>>(),
+        [usize::MAX]
+    );
+}
+
+#[test]
+#[should_panic]
+fn enumerated_by_overflow() {
+    let a = vec![1, 2, 3];
+    _ = a.enumerated_by(usize::MAX - 1, 1).iter().count();
+}
+
+#[test]
+fn enumerated_mut() {
+    let mut a = vec![0, 0, 0];
+    for (i, x) in a.enumerated_from_mut(1) {
+        *x = i;
+    }
+    assert_eq!(a, [1, 2, 3]);
+
+    for (i, x) in a.enumerated_by_mut(10, 10) {
+        *x += i;
+    }
+    assert_eq!(a, [11, 22, 33]);
+}
+
 #[cfg(feature = "std")]
 mod object_safe {
     use orx_iterable::{obj_safe::*, *};
 
-    fn obj_test_it<'a>(
+    fn obj_test_it(
         values: Vec<(usize, usize)>,
-        col: &dyn IterableObj<Item = (usize, &'a usize)>,
+        col: &dyn IterableObj<Item = (usize, &usize)>,
     ) {
         let sum_values = values.iter().map(|x| x.1).sum::<usize>();
         let sum_indices = values.iter().map(|x| x.0).sum::<usize>();
@@ -46,4 +104,16 @@ mod object_safe {
             &a.enumerated(),
         );
     }
+
+    #[test]
+    fn obj_enumerated_from() {
+        let a = vec![1, 2, 3, 4];
+        obj_test_it(
+            a.iter()
+                .enumerate()
+                .map(|(i, x)| (10 + 3 * i, *x))
+                .collect(),
+            &a.enumerated_by(10, 3),
+        );
+    }
 }
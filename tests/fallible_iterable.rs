@@ -0,0 +1,173 @@
+use orx_iterable::*;
+use std::cell::Cell;
+
+fn source(available: &Cell<bool>) -> impl FallibleIterable<Item = usize, Error = String> + '_ {
+    fallible_source(move || match available.get() {
+        true => Ok(0..6),
+        false => Err("unavailable".to_string()),
+    })
+}
+
+#[test]
+fn try_iter() {
+    let available = Cell::new(true);
+    let it = source(&available);
+    assert_eq!(it.try_iter().map(|x| x.sum::<usize>()), Ok(15));
+    assert_eq!(it.try_iter().map(|x| x.count()), Ok(6));
+
+    available.set(false);
+    assert_eq!(it.try_iter().err(), Some("unavailable".to_string()));
+
+    available.set(true);
+    assert_eq!(it.try_iter().map(|x| x.count()), Ok(6));
+}
+
+#[test]
+fn transformations() {
+    let available = Cell::new(true);
+    let it = source(&available)
+        .skipped(1)
+        .filtered(|x| x % 2 == 1)
+        .mapped(|x| x * 10)
+        .filter_mapped(|x| (x < 50).then_some(x + 1))
+        .taken(5);
+    assert_eq!(
+        it.try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![11, 31])
+    );
+
+    available.set(false);
+    assert!(it.try_iter().is_err());
+}
+
+#[test]
+fn panicking() {
+    let available = Cell::new(true);
+    let it = source(&available).mapped(|x| x + 1).panicking();
+    assert_eq!(it.iter().sum::<usize>(), 21);
+    assert_eq!(it.iter().count(), 6);
+}
+
+#[test]
+#[should_panic]
+fn panicking_when_unavailable() {
+    let available = Cell::new(false);
+    let it = source(&available).panicking();
+    let _ = it.iter().count();
+}
+
+#[test]
+fn results() {
+    let available = Cell::new(true);
+    let it = source(&available).taken(3).results();
+    assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(0), Ok(1), Ok(2)]);
+    assert_eq!(it.try_sum::<usize>(), Ok(3));
+
+    available.set(false);
+    assert_eq!(
+        it.iter().collect::<Vec<_>>(),
+        [Err("unavailable".to_string())]
+    );
+    assert_eq!(it.ok_values().iter().count(), 0);
+}
+
+#[test]
+fn chained() {
+    let available = Cell::new(true);
+    let other = Cell::new(true);
+    let it = source(&available)
+        .taken(2)
+        .chained(source(&other).skipped(4));
+    assert_eq!(
+        it.try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![0, 1, 4, 5])
+    );
+
+    other.set(false);
+    assert_eq!(it.try_iter().err(), Some("unavailable".to_string()));
+}
+
+#[test]
+fn enumerated() {
+    let available = Cell::new(true);
+    let it = source(&available).skipped(3).enumerated();
+    assert_eq!(
+        it.try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![(0, 3), (1, 4), (2, 5)])
+    );
+
+    available.set(false);
+    assert!(it.try_iter().is_err());
+}
+
+#[test]
+fn flattened_flat_mapped() {
+    let available = Cell::new(true);
+    let it = source(&available)
+        .taken(3)
+        .mapped(|x| vec![x; x])
+        .flattened();
+    assert_eq!(
+        it.try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![1, 2, 2])
+    );
+
+    let it = source(&available).taken(3).flat_mapped(|x| 0..x);
+    assert_eq!(
+        it.try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![0, 0, 1])
+    );
+
+    available.set(false);
+    assert!(it.try_iter().is_err());
+}
+
+#[test]
+fn copied_cloned() {
+    let numbers = vec![1, 2, 3];
+    let available = Cell::new(true);
+    let it = || {
+        fallible_source(|| match available.get() {
+            true => Ok(numbers.iter()),
+            false => Err("unavailable".to_string()),
+        })
+    };
+    assert_eq!(it().copied().try_iter().map(|x| x.sum::<i32>()), Ok(6));
+    assert_eq!(
+        it().cloned().try_iter().map(|x| x.collect::<Vec<_>>()),
+        Ok(vec![1, 2, 3])
+    );
+
+    available.set(false);
+    assert!(it().copied().try_iter().is_err());
+    assert!(it().cloned().try_iter().is_err());
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use super::source;
+    use orx_iterable::{obj_safe::*, *};
+    use std::cell::Cell;
+
+    #[test]
+    fn results_obj() {
+        let available = Cell::new(false);
+        let it = source(&available).results();
+        let it: &dyn IterableObj<Item = Result<usize, String>> = &it;
+        assert_eq!(it.boxed_iter().count(), 1);
+
+        available.set(true);
+        assert_eq!(it.boxed_iter().count(), 6);
+    }
+
+    #[test]
+    fn fallible_iterable_obj() {
+        let available = Cell::new(true);
+        let it = source(&available).enumerated().mapped(|(i, x)| i + x);
+        let it: &dyn FallibleIterableObj<Item = usize, Error = String> = &it;
+        assert_eq!(it.try_boxed_iter().map(|x| x.sum::<usize>()), Ok(30));
+
+        available.set(false);
+        assert!(it.try_boxed_iter().is_err());
+    }
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::{obj_safe::*, *};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, content: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("orx_iterable_test_{}_{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        Self(path)
+    }
+
+    fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn file_lines_multiple_passes() {
+    let file = TempFile::new("lines_passes", "a\nbb\n\nccc");
+    let lines = file_lines(file.path());
+    assert_eq!(lines.path(), file.path());
+
+    let expected = ["a", "bb", "", "ccc"];
+    for _ in 0..3 {
+        assert_eq!(lines.try_collect::<Vec<_>>().unwrap(), expected);
+    }
+
+    let lengths = lines.ok_values().mapped(|x| x.len());
+    assert_eq!(lengths.iter().sum::<usize>(), 6);
+    assert_eq!(lengths.iter().max(), Some(3));
+}
+
+#[test]
+fn file_lines_reopened_on_every_iter() {
+    let file = TempFile::new("lines_reopened", "1\n2\n");
+    let lines = file_lines(file.path());
+    assert_eq!(lines.iter().count(), 2);
+
+    fs::write(file.path(), "1\n2\n3\n").unwrap();
+    assert_eq!(lines.iter().count(), 3);
+}
+
+#[test]
+fn file_lines_missing_file() {
+    let lines = file_lines(std::env::temp_dir().join("orx_iterable_test_missing_file"));
+    let items: Vec<_> = lines.iter().collect();
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_err());
+    assert_eq!(lines.ok_values().iter().count(), 0);
+}
+
+#[test]
+fn file_lines_obj() {
+    let file = TempFile::new("lines_obj", "x\ny\nz\n");
+    let lines = file_lines(file.path());
+    let it: &dyn IterableObj<Item = std::io::Result<String>> = &lines;
+    assert_eq!(it.boxed_iter().count(), 3);
+    assert_eq!(it.boxed_iter().count(), 3);
+}
+
+#[test]
+fn file_chunks_multiple_passes() {
+    let file = TempFile::new("chunks_passes", "abcdefgh");
+
+    let chunks = file_chunks(file.path(), 3);
+    assert_eq!(chunks.chunk_size(), 3);
+    for _ in 0..3 {
+        let expected = [b"abc".to_vec(), b"def".to_vec(), b"gh".to_vec()];
+        assert_eq!(chunks.try_collect::<Vec<_>>().unwrap(), expected);
+    }
+
+    let chunks = file_chunks(file.path(), 4);
+    assert_eq!(chunks.iter().count(), 2);
+
+    let chunks = file_chunks(file.path(), 100);
+    assert_eq!(
+        chunks.try_collect::<Vec<_>>().unwrap(),
+        [b"abcdefgh".to_vec()]
+    );
+}
+
+#[test]
+fn file_chunks_empty_and_missing() {
+    let file = TempFile::new("chunks_empty", "");
+    assert_eq!(file_chunks(file.path(), 2).iter().count(), 0);
+
+    let chunks = file_chunks(
+        std::env::temp_dir().join("orx_iterable_test_missing_file"),
+        2,
+    );
+    let items: Vec<_> = chunks.iter().collect();
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_err());
+}
+
+#[test]
+#[should_panic]
+fn file_chunks_zero_size() {
+    let _ = file_chunks("any", 0);
+}
+
+#[test]
+fn file_chunks_obj() {
+    let file = TempFile::new("chunks_obj", "abcde");
+    let chunks = file_chunks(file.path(), 2);
+    let it: &dyn IterableObj<Item = std::io::Result<Vec<u8>>> = &chunks;
+    assert_eq!(it.boxed_iter().count(), 3);
+}
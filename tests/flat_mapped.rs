@@ -16,7 +16,7 @@ fn flat_mapped() {
         vec![vec![0, 3], vec![6], vec![10, 2, 7]].flat_mapped(|x| x.iter().map(|x| x + 1)),
     );
 
-    let data = vec![vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
+    let data = [vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
     let indices = vec![0, 2, 3];
     assert_eq!(
         indices
@@ -56,7 +56,7 @@ mod object_safe {
             &vec![vec![0, 3], vec![6], vec![10, 2, 7]].flat_mapped(|x| x.iter().map(|x| x + 1)),
         );
 
-        let data = vec![vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
+        let data = [vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
         let indices = vec![0, 2, 3];
         assert_eq!(
             indices
@@ -18,7 +18,7 @@ fn flattened() {
             .flattened(),
     );
 
-    let data = vec![vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
+    let data = [vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
     let indices = vec![0, 2, 3];
     assert_eq!(
         indices
@@ -88,7 +88,7 @@ mod object_safe {
                 .flattened(),
         );
 
-        let data = vec![vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
+        let data = [vec![1], vec![333], vec![4, 2], vec![8, 8, 3], vec![1000]];
         let indices = vec![0, 2, 3];
         assert_eq!(
             indices
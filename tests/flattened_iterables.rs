@@ -0,0 +1,113 @@
+use orx_iterable::*;
+
+fn test_it(values: Vec<usize>, col: impl Iterable<Item = usize>) {
+    let sum = values.iter().sum::<usize>();
+    let count = values.len();
+
+    // tests
+    assert_eq!(col.iter().count(), count);
+    assert_eq!(col.iter().sum::<usize>(), sum);
+}
+
+#[test]
+fn flattened_iterables() {
+    let data = vec![1usize, 2, 3, 4, 5, 6];
+    let multiples_of = |k: usize| data.filtered(move |x| *x % k == 0).copied();
+
+    let pipelines = vec![multiples_of(2), multiples_of(3), multiples_of(7)];
+    test_it(
+        vec![2, 4, 6, 3, 6],
+        pipelines.copied().flattened_iterables(),
+    );
+
+    let prefixes = (0..4usize).mapped(|n| data.copied().taken(n));
+    let it = prefixes.flattened_iterables();
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 1, 2, 1, 2, 3]);
+    test_it(vec![1, 1, 2, 1, 2, 3], it);
+}
+
+#[test]
+fn flattened_iterables_empty() {
+    let empty_outer = empty::<core::ops::Range<usize>>();
+    test_it(vec![], empty_outer.flattened_iterables());
+
+    let empty_inners = vec![0..0usize, 0..0, 0..0];
+    test_it(vec![], empty_inners.cloned().flattened_iterables());
+}
+
+#[test]
+fn flat_mapped_iterable() {
+    let data = vec![1usize, 2, 3];
+    let factors = [1usize, 10, 100];
+
+    let it = data.flat_mapped_iterable(|x| factors.taken(*x).mapped(move |f| x * f));
+    test_it(vec![1, 2, 20, 3, 30, 300], it);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 20, 3, 30, 300]);
+
+    let it = data.flat_mapped_iterable(|_| empty::<usize>());
+    test_it(vec![], it);
+}
+
+#[test]
+fn into_flattened_iterables() {
+    let data = vec![
+        vec![0usize, 1, 2].into_skipped(1),
+        vec![3].into_skipped(1),
+        vec![4, 5].into_skipped(1),
+    ];
+    let mut col = data.into_flattened_iterables();
+    for x in col.iter_mut() {
+        *x += 10;
+    }
+    assert_eq!(col.iter().copied().collect::<Vec<_>>(), [11, 12, 15]);
+    test_it(vec![11, 12, 15], col.copied());
+}
+
+#[test]
+fn flattened_iterables_mut() {
+    let mut data = vec![vec![vec![1usize], vec![2, 3]], vec![], vec![vec![4]]]
+        .into_iter()
+        .map(|x| x.into_flattened())
+        .collect::<Vec<_>>();
+
+    let mut col = data.flattened_iterables_mut();
+    for x in col.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(col.iter().copied().collect::<Vec<_>>(), [2, 4, 6, 8]);
+    assert_eq!(data[0].iter().copied().collect::<Vec<_>>(), [2, 4, 6]);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    fn obj_test_it(values: Vec<usize>, col: &dyn IterableObj<Item = usize>) {
+        let sum = values.iter().sum::<usize>();
+        let count = values.len();
+
+        // tests
+        assert_eq!(col.boxed_iter().count(), count);
+        assert_eq!(col.boxed_iter().sum::<usize>(), sum);
+    }
+
+    #[test]
+    fn obj_flattened_iterables() {
+        let ranges = vec![0..2usize, 5..7];
+        obj_test_it(vec![0, 1, 5, 6], &ranges.cloned().flattened_iterables());
+
+        let data = vec![1usize, 2];
+        let it = data.flat_mapped_iterable(|x| (0..*x).mapped(move |y| x + y));
+        obj_test_it(vec![1, 2, 3], &it);
+    }
+
+    #[test]
+    fn obj_into_flattened_iterables() {
+        let data = vec![vec![1usize, 2].into_taken(1), vec![3, 4].into_taken(2)];
+        let mut col = data.into_flattened_iterables();
+        col.boxed_iter_mut().for_each(|x| *x += 1);
+
+        let obj: &dyn CollectionObj<Item = usize> = &col;
+        assert_eq!(obj.boxed_iter().copied().collect::<Vec<_>>(), [2, 4, 5]);
+    }
+}
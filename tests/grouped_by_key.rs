@@ -0,0 +1,83 @@
+#![cfg(feature = "std")]
+
+mod common_testers;
+use common_testers::{test_col, test_it};
+use orx_iterable::*;
+
+#[test]
+fn grouped_by_key() {
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 5];
+    let groups = a.grouped_by_key(|x| x % 3);
+
+    assert_eq!(groups.len(), 3);
+    assert!(!groups.is_empty());
+    assert_eq!(groups.keys().copied().collect::<Vec<_>>(), [1, 0, 2]);
+
+    let group = groups.get(&1).unwrap();
+    assert_eq!(group.indices(), &[0, 2, 5, 6]);
+    test_col(vec![1, 4, 7, 10], group);
+
+    let group = groups.get(&2).unwrap();
+    test_it(vec![8, 5], &group);
+    test_it(vec![8, 5], &group);
+
+    assert!(groups.get(&3).is_none());
+
+    let sums: Vec<_> = groups
+        .iter()
+        .map(|(key, group)| (*key, group.iter().sum::<usize>()))
+        .collect();
+    assert_eq!(sums, [(1, 22), (0, 9), (2, 13)]);
+}
+
+#[test]
+fn grouped_by_key_empty() {
+    let a: Vec<usize> = vec![];
+    let groups = a.grouped_by_key(|x| *x);
+
+    assert_eq!(groups.len(), 0);
+    assert!(groups.is_empty());
+    assert_eq!(groups.iter().count(), 0);
+}
+
+#[test]
+fn grouped_by_key_non_slice_storage() {
+    use std::collections::LinkedList;
+
+    let a = LinkedList::from_iter([1, 3, 4, 6, 8, 7, 10, 5]);
+    let groups = a.grouped_by_key(|x| x % 2 == 0);
+
+    test_col(vec![1, 3, 7, 5], groups.get(&false).unwrap());
+    test_col(vec![4, 6, 8, 10], groups.get(&true).unwrap());
+}
+
+#[test]
+fn grouped_by_key_does_not_revisit_source() {
+    use std::collections::LinkedList;
+
+    let col = LinkedList::from_iter(0..100).into_instrumented();
+    let stats = col.stats();
+    let groups = col.grouped_by_key(|x| x % 10);
+    assert_eq!(stats.items_per_pass(), [100]);
+
+    let total: usize = groups.iter().map(|(_, g)| g.iter().count()).sum();
+    assert_eq!(total, 100);
+    assert_eq!(stats.items_per_pass(), [100]);
+}
+
+// obj
+
+#[test]
+fn obj_grouped_by_key() {
+    use common_testers::{obj_test_col, obj_test_it};
+    use orx_iterable::obj_safe::*;
+
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 5];
+    let groups = a.grouped_by_key(|x| x % 3);
+
+    assert_eq!((&groups).boxed_iter().count(), 3);
+
+    let group = groups.get(&0).unwrap();
+    obj_test_col(vec![3, 6], &group);
+    obj_test_it(vec![3, 6], &&group);
+}
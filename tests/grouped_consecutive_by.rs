@@ -0,0 +1,80 @@
+mod common_testers;
+use common_testers::{test_col, test_it};
+use orx_iterable::*;
+
+#[test]
+fn grouped_consecutive_by() {
+    let a = vec![1, 3, 4, 6, 8, 7, 10];
+    let groups = a.grouped_consecutive_by(|x| x % 2);
+
+    let keys: Vec<_> = groups.iter().map(|(key, _)| key).collect();
+    assert_eq!(keys, [1, 0, 1, 0]);
+
+    let mut iter = groups.iter();
+    let (_, group) = iter.next().unwrap();
+    assert_eq!(group.begin(), 0);
+    assert_eq!(group.len(), 2);
+    test_col(vec![1, 3], group);
+
+    let (_, group) = iter.next().unwrap();
+    test_it(vec![4, 6, 8], &group);
+    test_it(vec![4, 6, 8], &group);
+
+    let (_, group) = iter.next().unwrap();
+    test_col(vec![7], group);
+
+    let (_, group) = iter.next().unwrap();
+    test_col(vec![10], group);
+
+    assert!(iter.next().is_none());
+
+    // second pass
+    let lengths: Vec<_> = groups.iter().map(|(_, g)| g.len()).collect();
+    assert_eq!(lengths, [2, 3, 1, 1]);
+}
+
+#[test]
+fn grouped_consecutive_by_empty() {
+    let a: Vec<usize> = vec![];
+    let groups = a.grouped_consecutive_by(|x| *x);
+    assert_eq!(groups.iter().count(), 0);
+}
+
+#[test]
+fn grouped_consecutive_by_single_group() {
+    let a = vec![2, 4, 6];
+    let groups = a.grouped_consecutive_by(|x| x % 2 == 0);
+
+    assert_eq!(groups.iter().count(), 1);
+    let (key, group) = groups.iter().next().unwrap();
+    assert!(key);
+    test_col(vec![2, 4, 6], group);
+}
+
+#[test]
+fn grouped_consecutive_by_array() {
+    let a = [5, 5, 1, 1, 1, 5];
+    let groups = a.grouped_consecutive_by(|x| *x);
+
+    let lengths: Vec<_> = groups.iter().map(|(k, g)| (k, g.iter().count())).collect();
+    assert_eq!(lengths, [(5, 2), (1, 3), (5, 1)]);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_grouped_consecutive_by() {
+    use common_testers::{obj_test_col, obj_test_it};
+    use orx_iterable::obj_safe::*;
+
+    let a = vec![1, 3, 4, 6, 8, 7, 10];
+    let groups = a.grouped_consecutive_by(|x| x % 2);
+
+    assert_eq!(groups.boxed_iter().count(), 4);
+
+    let (key, group) = groups.boxed_iter().nth(1).unwrap();
+    assert_eq!(key, 0);
+    obj_test_col(vec![4, 6, 8], &group);
+    obj_test_it(vec![4, 6, 8], &&group);
+}
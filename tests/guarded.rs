@@ -0,0 +1,124 @@
+mod common_testers;
+use common_testers::test_it_val;
+use core::cell::RefCell;
+use orx_iterable::*;
+
+#[test]
+fn ref_cell() {
+    let cell = RefCell::new(vec![1usize, 2, 3]);
+    let it = cell.guarded();
+
+    test_it_val(vec![1, 2, 3], cell.guarded());
+    assert_eq!(it.iter().count(), 3);
+
+    cell.borrow_mut().push(4);
+    test_it_val(vec![1, 2, 3, 4], cell.guarded());
+
+    let mut iter = it.iter();
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert!(cell.try_borrow_mut().is_err());
+    assert_eq!(it.iter().next(), Some(1));
+    drop(iter);
+    assert!(cell.try_borrow_mut().is_ok());
+
+    it.write(|x| x.retain(|x| *x > 2));
+    assert_eq!(it.read(|x| x.len()), 2);
+    test_it_val(vec![3, 4], it.filtered(|_| true));
+}
+
+#[test]
+fn ref_cell_non_vec() {
+    use std::collections::VecDeque;
+
+    let cell = RefCell::new(VecDeque::from_iter([1usize, 2, 3]));
+    cell.borrow_mut().push_front(0);
+    test_it_val(vec![0, 1, 2, 3], cell.guarded());
+
+    let cell = RefCell::new([7usize, 8]);
+    test_it_val(vec![7, 8], cell.guarded());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn mutex() {
+    use std::sync::{Arc, Mutex};
+
+    let numbers = Arc::new(Mutex::new(vec![1usize, 2, 3]));
+    let it = numbers.guarded();
+
+    test_it_val(vec![1, 2, 3], numbers.guarded());
+
+    let iter = it.iter();
+    assert!(numbers.try_lock().is_err());
+    drop(iter);
+
+    numbers.lock().unwrap().push(4);
+    test_it_val(vec![1, 2, 3, 4], numbers.guarded());
+
+    it.write(|x| x.clear());
+    assert_eq!(it.read(|x| x.len()), 0);
+    assert_eq!(it.iter().count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn mutex_across_threads() {
+    use std::sync::{Arc, Mutex};
+
+    let numbers = Arc::new(Mutex::new(vec![1usize, 2, 3]));
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let numbers = numbers.clone();
+            std::thread::spawn(move || numbers.guarded().write(|x| x.push(10 * i)))
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    test_it_val(vec![1, 2, 3, 0, 10, 20, 30], numbers.guarded());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn rw_lock() {
+    use std::sync::{Arc, RwLock};
+
+    let numbers = Arc::new(RwLock::new(vec![1usize, 2, 3]));
+    let it = numbers.guarded();
+
+    // multiple readers are allowed
+    test_it_val(
+        vec![2, 4, 6],
+        it.zipped_with(numbers.guarded(), |a, b| a + b),
+    );
+
+    let iter = it.iter();
+    assert!(numbers.try_write().is_err());
+    drop(iter);
+
+    it.write(|x| x.push(4));
+    assert_eq!(it.read(|x| x.len()), 4);
+    test_it_val(vec![1, 2, 3, 4], it);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_guarded() {
+    use common_testers::obj_test_it_val;
+    use std::sync::{Mutex, RwLock};
+
+    let cell = RefCell::new(vec![1usize, 2, 3]);
+    obj_test_it_val(vec![1, 2, 3], &cell.guarded());
+
+    let mutex = Mutex::new(vec![1usize, 2, 3]);
+    obj_test_it_val(vec![1, 2, 3], &mutex.guarded());
+
+    let lock = RwLock::new(vec![1usize, 2, 3]);
+    obj_test_it_val(vec![1, 2, 3], &lock.guarded());
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::*;
+use std::cell::RefCell;
+
+#[test]
+fn instrumented() {
+    let a = vec![1, 3, 7, 2, 8];
+    let it = a.instrumented();
+    let stats = it.stats();
+    assert_eq!(stats.passes(), 0);
+    assert_eq!(stats.total_items(), 0);
+
+    let it = it.copied().mapped(|x| x * 2);
+    assert_eq!(it.iter().sum::<usize>(), 42);
+    assert_eq!(it.iter().take(2).count(), 2);
+    assert_eq!(it.iter().find(|x| *x == 14), Some(14));
+
+    let _unused = it.iter();
+
+    assert_eq!(stats.passes(), 4);
+    assert_eq!(stats.items_per_pass(), [5, 2, 3, 0]);
+    assert_eq!(stats.total_items(), 10);
+
+    stats.reset();
+    assert_eq!(stats.passes(), 0);
+    assert_eq!(it.iter().count(), 5);
+    assert_eq!(stats.items_per_pass(), [5]);
+}
+
+#[test]
+fn instrumented_interleaved_passes() {
+    let it = (0..4).instrumented();
+    let stats = it.stats();
+
+    let mut first = it.iter();
+    let mut second = it.iter();
+    first.next();
+    second.next();
+    second.next();
+    first.next();
+    first.next();
+
+    assert_eq!(stats.items_per_pass(), [3, 2]);
+}
+
+#[test]
+fn instrumented_mut() {
+    let mut a = vec![1, 3, 7, 2, 8];
+    let mut col = a.instrumented_mut();
+    let stats = col.stats();
+    for x in col.iter_mut() {
+        *x += 10;
+    }
+    assert_eq!(col.iter().sum::<usize>(), 71);
+    assert_eq!(stats.items_per_pass(), [5, 5]);
+    assert_eq!(a, [11, 13, 17, 12, 18]);
+}
+
+#[test]
+fn into_instrumented() {
+    let a = vec![1, 3, 7, 2, 8];
+    let mut a = a.into_instrumented();
+    let stats = a.stats();
+    for x in a.iter_mut().take(3) {
+        *x += 10;
+    }
+    assert_eq!(a.copied().iter().sum::<usize>(), 51);
+    assert_eq!(stats.items_per_pass(), [3, 5]);
+}
+
+#[test]
+fn inspected() {
+    let a = vec![1, 3, 7, 2, 8];
+    let seen = RefCell::new(vec![]);
+    let it = a.copied().inspected(|x| seen.borrow_mut().push(*x));
+
+    assert_eq!(it.iter().take(2).sum::<usize>(), 4);
+    assert_eq!(seen.borrow().as_slice(), [1, 3]);
+
+    assert_eq!(it.iter().count(), 5);
+    assert_eq!(seen.borrow().as_slice(), [1, 3, 1, 3, 7, 2, 8]);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+    use std::cell::Cell;
+
+    #[test]
+    fn obj_instrumented() {
+        let a = vec![1, 3, 7, 2, 8];
+        let it = a.instrumented();
+        let stats = it.stats();
+        let it: &dyn IterableObj<Item = &usize> = &it;
+        assert_eq!(it.boxed_iter().count(), 5);
+        assert_eq!(it.boxed_iter().nth(1), Some(&3));
+        assert_eq!(stats.items_per_pass(), [5, 2]);
+    }
+
+    #[test]
+    fn obj_into_instrumented() {
+        let a = vec![1, 3, 7, 2, 8];
+        let mut a = a.into_instrumented();
+        let stats = a.stats();
+        let col: &mut dyn CollectionMutObj<Item = usize> = &mut a;
+        for x in col.boxed_iter_mut() {
+            *x += 1;
+        }
+        assert_eq!(col.boxed_iter().sum::<usize>(), 26);
+        assert_eq!(stats.passes(), 2);
+    }
+
+    #[test]
+    fn obj_inspected() {
+        let a = vec![1, 3, 7, 2, 8];
+        let count = Cell::new(0);
+        let it = a.inspected(|_| count.set(count.get() + 1));
+        let it: &dyn IterableObj<Item = &usize> = &it;
+        assert_eq!(it.boxed_iter().count(), 5);
+        assert_eq!(count.get(), 5);
+    }
+}
@@ -0,0 +1,61 @@
+use orx_iterable::*;
+
+fn test_it(values: Vec<usize>, col: impl Iterable<Item = usize>) {
+    // tests
+    assert_eq!(col.iter().count(), values.len());
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+}
+
+#[test]
+fn interleaved() {
+    let a = vec![1, 2, 3];
+    let b = vec![10, 20, 30];
+
+    test_it(vec![1, 10, 2, 20, 3, 30], a.interleaved(&b).copied());
+    test_it(vec![1, 10, 2, 20, 3], a.interleaved(b.taken(2)).copied());
+    test_it(vec![1, 10, 20, 30], a.taken(1).interleaved(&b).copied());
+    test_it(vec![10, 20, 30], a.taken(0).interleaved(&b).copied());
+    test_it(vec![1, 2, 3], a.interleaved(b.taken(0)).copied());
+    test_it(vec![0, 7, 1, 8, 2, 3], (0..4usize).interleaved(7..9usize));
+}
+
+#[test]
+fn interleaved_shortest() {
+    let a = vec![1, 2, 3];
+    let b = vec![10, 20, 30];
+
+    test_it(
+        vec![1, 10, 2, 20, 3, 30],
+        a.interleaved_shortest(&b).copied(),
+    );
+    test_it(
+        vec![1, 10, 2, 20, 3],
+        a.interleaved_shortest(b.taken(2)).copied(),
+    );
+    test_it(vec![1, 10, 2], a.interleaved_shortest(b.taken(1)).copied());
+    test_it(vec![1, 10], a.taken(1).interleaved_shortest(&b).copied());
+    test_it(vec![], a.taken(0).interleaved_shortest(&b).copied());
+    test_it(vec![1], a.interleaved_shortest(b.taken(0)).copied());
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    fn obj_test_it(values: Vec<usize>, col: &dyn IterableObj<Item = usize>) {
+        // tests
+        assert_eq!(col.boxed_iter().count(), values.len());
+        assert_eq!(col.boxed_iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn obj_interleaved() {
+        let a = vec![1, 2, 3];
+        let b = vec![10, 20];
+
+        obj_test_it(vec![1, 10, 2, 20, 3], &a.interleaved(&b).copied());
+        obj_test_it(vec![1, 10, 2, 20, 3], &a.interleaved_shortest(&b).copied());
+        obj_test_it(vec![10, 1, 20, 2], &b.interleaved_shortest(&a).copied());
+    }
+}
@@ -0,0 +1,73 @@
+use orx_iterable::*;
+
+#[test]
+fn interspersed() {
+    let a = vec![1, 2, 3];
+
+    let it = a.copied().interspersed(0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 0, 2, 0, 3]);
+    assert_eq!(it.iter().count(), 5);
+
+    let it = a.interspersed(&0);
+    assert_eq!(it.iter().copied().collect::<Vec<_>>(), [1, 0, 2, 0, 3]);
+
+    assert_eq!(a.copied().taken(1).interspersed(0).iter().count(), 1);
+    assert_eq!(empty::<i32>().interspersed(0).iter().count(), 0);
+}
+
+#[test]
+fn interspersed_non_fused_source() {
+    let a = vec![1, 2, 3, 4, 5];
+    let it = a.copied().filtered(|x| *x % 2 == 1).interspersed(0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 0, 3, 0, 5]);
+}
+
+#[test]
+fn interspersed_with() {
+    let a = vec!["x", "y", "z"];
+
+    let it = a
+        .copied()
+        .mapped(String::from)
+        .interspersed_with(|| String::from(","));
+    assert_eq!(it.iter().collect::<String>(), "x,y,z");
+    assert_eq!(it.iter().collect::<String>(), "x,y,z");
+
+    let it = a.copied().taken(0).mapped(String::from);
+    let it = it.interspersed_with(String::new);
+    assert_eq!(it.iter().count(), 0);
+}
+
+#[test]
+fn into_interspersed() {
+    let a = vec![1, 2, 3];
+    let col = a.into_interspersed(-1);
+
+    assert_eq!(col.iter().copied().collect::<Vec<_>>(), [1, -1, 2, -1, 3]);
+    assert_eq!(col.iter().count(), 5);
+
+    let col = Vec::<i32>::new().into_interspersed(-1);
+    assert_eq!(col.iter().count(), 0);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_interspersed() {
+        let a = vec![1, 2];
+
+        let it = a.copied().interspersed(9);
+        let obj: &dyn IterableObj<Item = i32> = &it;
+        assert_eq!(obj.boxed_iter().collect::<Vec<_>>(), [1, 9, 2]);
+
+        let it = a.copied().interspersed_with(|| 7);
+        let obj: &dyn IterableObj<Item = i32> = &it;
+        assert_eq!(obj.boxed_iter().collect::<Vec<_>>(), [1, 7, 2]);
+
+        let col = a.into_interspersed(5);
+        let obj: &dyn CollectionObj<Item = i32> = &col;
+        assert_eq!(obj.boxed_iter().copied().collect::<Vec<_>>(), [1, 5, 2]);
+    }
+}
@@ -9,10 +9,10 @@ fn std_collections() {
 
     test_it(values(), &[1, 3, 7]);
     test_it(values(), &vec![1, 3, 7]);
-    test_it(values(), &VecDeque::from_iter([1, 3, 7].into_iter()));
-    test_it(values(), &LinkedList::from_iter([1, 3, 7].into_iter()));
-    test_it(values(), &HashSet::<_>::from_iter([1, 3, 7].into_iter()));
-    test_it(values(), &BTreeSet::<_>::from_iter([1, 3, 7].into_iter()));
+    test_it(values(), &VecDeque::from_iter([1, 3, 7]));
+    test_it(values(), &LinkedList::from_iter([1, 3, 7]));
+    test_it(values(), &HashSet::<_>::from_iter([1, 3, 7]));
+    test_it(values(), &BTreeSet::<_>::from_iter([1, 3, 7]));
 
     test_it(vec![10], &Some(10));
     test_it(vec![], &None);
@@ -54,10 +54,10 @@ fn obj_std_collections() {
 
     obj_test_it(values(), &&[1, 3, 7]);
     obj_test_it(values(), &&vec![1, 3, 7]);
-    obj_test_it(values(), &&VecDeque::from_iter([1, 3, 7].into_iter()));
-    obj_test_it(values(), &&LinkedList::from_iter([1, 3, 7].into_iter()));
-    obj_test_it(values(), &&HashSet::<_>::from_iter([1, 3, 7].into_iter()));
-    obj_test_it(values(), &&BTreeSet::<_>::from_iter([1, 3, 7].into_iter()));
+    obj_test_it(values(), &&VecDeque::from_iter([1, 3, 7]));
+    obj_test_it(values(), &&LinkedList::from_iter([1, 3, 7]));
+    obj_test_it(values(), &&HashSet::<_>::from_iter([1, 3, 7]));
+    obj_test_it(values(), &&BTreeSet::<_>::from_iter([1, 3, 7]));
 
     obj_test_it(vec![10], &&Some(10));
     obj_test_it(vec![], &&None);
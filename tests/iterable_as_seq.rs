@@ -0,0 +1,124 @@
+use core::cmp::Ordering;
+use orx_iterable::*;
+
+#[test]
+fn iter_eq_across_types() {
+    let a = vec![1, 2, 3, 4];
+    let b = [2, 4];
+
+    assert!(a.filtered(|x| *x % 2 == 0).iter_eq(&b));
+    assert!(a.copied().iter_eq(a.mapped(|x| *x)));
+    assert!(!a.taken(2).iter_eq(&b));
+    assert!(!a.taken(1).iter_eq(&a));
+    assert!(empty::<i32>().iter_eq(empty::<i32>()));
+}
+
+#[test]
+fn iter_cmp() {
+    let a = vec![1, 2, 3];
+
+    assert_eq!((&a).iter_cmp(&a), Ordering::Equal);
+    assert_eq!(a.taken(2).iter_cmp(&a), Ordering::Less);
+    assert_eq!(a.skipped(1).iter_cmp(&a), Ordering::Greater);
+    assert_eq!(empty().iter_cmp(&a), Ordering::Less);
+}
+
+#[test]
+fn iter_partial_cmp() {
+    let a = vec![1.0, 2.0];
+    let b = [1.0, f64::NAN];
+
+    assert_eq!(a.taken(5).iter_partial_cmp(&a), Some(Ordering::Equal));
+    assert_eq!(a.taken(1).iter_partial_cmp(&b), Some(Ordering::Less));
+    assert_eq!(a.taken(2).iter_partial_cmp(&b), None);
+}
+
+#[cfg(feature = "std")]
+mod hashing {
+    use orx_iterable::*;
+    use std::collections::{hash_map::DefaultHasher, HashMap, HashSet, VecDeque};
+    use std::hash::{Hash, Hasher};
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn iter_hash_of<I: Iterable>(it: I) -> u64
+    where
+        I::Item: Hash,
+    {
+        let mut hasher = DefaultHasher::new();
+        it.iter_hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn iter_hash_is_consistent_with_iter_eq() {
+        let a = vec![1, 2, 3];
+        let b = VecDeque::from_iter([0, 1, 2, 3]);
+
+        assert_eq!(iter_hash_of(&a), iter_hash_of(b.skipped(1)));
+        assert_ne!(iter_hash_of(&a), iter_hash_of(&b));
+        assert_ne!(iter_hash_of(empty::<i32>()), iter_hash_of(once(0)));
+    }
+
+    #[test]
+    fn as_seq_equality_and_ordering() {
+        let a = vec![1, 2, 3, 4];
+        let b = VecDeque::from_iter([2, 4]);
+
+        assert_eq!(a.filtered(|x| *x % 2 == 0).into_seq(), b.into_seq());
+        assert_ne!(a.into_seq(), b.into_seq());
+        assert!(a.into_seq() < b.into_seq());
+        assert!(b.into_seq() > a.taken(3).into_seq());
+        assert_eq!(a.into_seq().cmp(&a.into_seq()), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn as_seq_hash() {
+        let a = vec![1, 2, 3];
+        let b = [1, 2, 3];
+
+        assert_eq!(hash_of(&a.into_seq()), hash_of(&b.into_seq()));
+        assert_eq!(hash_of(&a.into_seq()), iter_hash_of(&a));
+    }
+
+    #[test]
+    fn as_seq_as_map_key() {
+        let words = ["ab", "ba", "ab"];
+        let data: Vec<Vec<char>> = words.iter().map(|x| x.chars().collect()).collect();
+
+        let mut counts = HashMap::new();
+        for chars in &data {
+            *counts.entry(chars.into_seq()).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&data[0].into_seq()], 2);
+
+        let set: HashSet<_> = data.iter().map(|x| x.into_seq()).collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn as_seq_debug_and_inner() {
+        let a = vec![1, 2, 3];
+        let seq = a.mapped(|x| x * 2).into_seq();
+
+        assert_eq!(format!("{:?}", seq), "[2, 4, 6]");
+        assert_eq!(seq.iter().sum::<i32>(), 12);
+        assert_eq!(seq.inner().iter().count(), 3);
+        assert_eq!(seq.into_inner().iter().max(), Some(6));
+    }
+
+    #[test]
+    fn as_seq_obj_safe() {
+        use orx_iterable::obj_safe::*;
+
+        let a = vec![1, 2, 3];
+        let seq = a.copied().into_seq();
+        let obj: &dyn IterableObj<Item = i32> = &seq;
+        assert_eq!(obj.boxed_iter().sum::<i32>(), 6);
+    }
+}
@@ -0,0 +1,146 @@
+#![cfg(feature = "std")]
+
+mod common_testers;
+use common_testers::{obj_test_col, obj_test_it, test_col, test_it};
+use orx_iterable::{obj_safe::*, *};
+use std::collections::VecDeque;
+
+struct Stack<T> {
+    items: Vec<T>,
+}
+
+impl_collection_via_field!(mut [T] Stack<T>, items: Vec<T>);
+
+struct Ring<T, const N: usize>([T; N]);
+
+impl_collection_via_field!(mut [T, const N: usize] Ring<T, N>, 0: [T; N]);
+
+struct Queue {
+    values: VecDeque<usize>,
+}
+
+impl_collection_via_field!([] Queue, values: VecDeque<usize>);
+
+struct Labeled<T>
+where
+    T: Clone,
+{
+    label: &'static str,
+    values: Vec<T>,
+}
+
+impl_collection_via_field!([T] Labeled<T>, values: Vec<T> where T: Clone);
+
+struct Sorted<T> {
+    data: Vec<T>,
+}
+
+impl<T> Sorted<T> {
+    fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl_iterable_via!(mut [T] Sorted<T>, as_slice() -> [T], as_mut_slice());
+
+struct Grid<const N: usize> {
+    cells: [usize; N],
+}
+
+impl<const N: usize> Grid<N> {
+    fn cells(&self) -> &[usize; N] {
+        &self.cells
+    }
+}
+
+impl_iterable_via!([const N: usize] Grid<N>, cells() -> [usize; N]);
+
+#[test]
+fn collection_via_field_generic() {
+    let mut stack = Stack {
+        items: vec![1, 2, 3],
+    };
+    test_it(vec![1, 2, 3], &stack);
+
+    for x in stack.iter_mut() {
+        *x *= 10;
+    }
+    for x in &mut stack {
+        *x += 1;
+    }
+    test_col(vec![11, 21, 31], stack);
+}
+
+#[test]
+fn collection_via_field_const_generic() {
+    let mut ring = Ring([1, 2, 3, 4]);
+    ring.iter_mut().for_each(|x| *x += 1);
+    test_it(vec![2, 3, 4, 5], &ring);
+    assert_eq!(ring.into_iter().collect::<Vec<_>>(), [2, 3, 4, 5]);
+}
+
+#[test]
+fn collection_via_field_non_generic() {
+    let queue = Queue {
+        values: VecDeque::from_iter([4, 5, 6]),
+    };
+    test_col(vec![4, 5, 6], queue);
+}
+
+#[test]
+fn collection_via_field_with_where_clause() {
+    let labeled = Labeled {
+        label: "a",
+        values: vec![1, 2],
+    };
+    assert_eq!(labeled.label, "a");
+    test_col(vec![1, 2], labeled);
+}
+
+#[test]
+fn collection_via_field_transformations() {
+    let stack = Stack {
+        items: vec![1, 2, 3, 4],
+    };
+    let evens = stack.into_filtered(|x| *x % 2 == 0);
+    assert_eq!(evens.iter().copied().collect::<Vec<_>>(), [2, 4]);
+}
+
+#[test]
+fn collection_via_field_obj_safe() {
+    let mut stack = Stack {
+        items: vec![1, 2, 3],
+    };
+    stack.boxed_iter_mut().for_each(|x| *x += 1);
+    obj_test_col(vec![2, 3, 4], &stack);
+
+    let cols: Vec<Box<dyn CollectionObj<Item = usize>>> = vec![
+        Box::new(stack),
+        Box::new(Ring([5, 6])),
+        Box::new(Queue {
+            values: VecDeque::from_iter([7]),
+        }),
+    ];
+    let sum: usize = cols.iter().flat_map(|x| x.boxed_iter()).sum();
+    assert_eq!(sum, 2 + 3 + 4 + 5 + 6 + 7);
+}
+
+#[test]
+fn iterable_via_accessor() {
+    let mut sorted = Sorted {
+        data: vec![1, 2, 3],
+    };
+    for x in &mut sorted {
+        *x *= 2;
+    }
+    test_it(vec![2, 4, 6], &sorted);
+    obj_test_it(vec![2, 4, 6], &&sorted);
+
+    let grid = Grid { cells: [7, 8, 9] };
+    test_it(vec![7, 8, 9], &grid);
+    assert_eq!((&grid).mapped(|x| x + 1).iter().sum::<usize>(), 27);
+}
@@ -0,0 +1,92 @@
+use core::fmt::Debug;
+use orx_iterable::*;
+
+fn test_zipped<T: PartialEq + Debug>(expected: Vec<T>, it: impl Iterable<Item = T>) {
+    assert_eq!(it.iter().count(), expected.len());
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn zip3_zip4() {
+    let a = vec![1, 2, 3, 4];
+    let b = vec!['a', 'b', 'c'];
+    let c = vec![10, 20, 30, 40, 50];
+
+    test_zipped(
+        vec![(&1, &'a', &10), (&2, &'b', &20), (&3, &'c', &30)],
+        zip3(&a, &b, &c),
+    );
+    test_zipped(
+        vec![(1, 'a', 10, 0), (2, 'b', 20, 1)],
+        zip4(a.copied(), b.copied(), c.copied(), 0..2usize),
+    );
+    test_zipped(vec![], zip3(&a, b.taken(0), &c));
+}
+
+#[test]
+fn zip5_zip6() {
+    let a = vec![1, 2];
+    test_zipped(
+        vec![(1, 1, 1, 1, 1), (2, 2, 2, 2, 2)],
+        zip5(a.copied(), a.copied(), a.copied(), a.copied(), a.copied()),
+    );
+    test_zipped(
+        vec![(1, 1, 1, 1, 1, 5)],
+        zip6(
+            a.copied(),
+            a.copied(),
+            a.copied(),
+            a.copied(),
+            a.copied(),
+            5..6usize,
+        ),
+    );
+}
+
+#[test]
+fn zipped_array() {
+    let a = vec![1, 2, 3];
+    let b = vec![4, 5, 6, 7];
+    let c = vec![8, 9, 10];
+    let empty: Vec<i32> = vec![];
+
+    test_zipped(
+        vec![[&1, &4, &8], [&2, &5, &9], [&3, &6, &10]],
+        zip_array([&a, &b, &c]),
+    );
+    test_zipped(
+        vec![[1, 4], [2, 5]],
+        zip_array([a.copied().taken(2), b.copied().taken(4)]),
+    );
+    test_zipped(vec![], zip_array([&a, &empty]));
+}
+
+#[test]
+fn zipped_empty_array() {
+    let it = zip_array::<&Vec<i32>, 0>([]);
+    assert_eq!(it.iter().next(), None);
+    assert_eq!(it.iter().count(), 0);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_multi_zipped() {
+    use orx_iterable::obj_safe::*;
+
+    let a = vec![1, 2, 3];
+    let b = vec![4, 5, 6, 7];
+
+    let it = zip3(a.copied(), b.copied(), 0..2usize);
+    let it: &dyn IterableObj<Item = (i32, i32, usize)> = &it;
+    assert_eq!(it.boxed_iter().collect::<Vec<_>>(), [(1, 4, 0), (2, 5, 1)]);
+
+    let it = zip_array([&a, &b]);
+    let it: &dyn IterableObj<Item = [&i32; 2]> = &it;
+    assert_eq!(
+        it.boxed_iter().collect::<Vec<_>>(),
+        [[&1, &4], [&2, &5], [&3, &6]]
+    );
+}
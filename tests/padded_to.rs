@@ -0,0 +1,66 @@
+use orx_iterable::*;
+
+#[test]
+fn padded_to() {
+    let a = vec![1, 2, 3];
+
+    let it = a.copied().padded_to(5, 0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 3, 0, 0]);
+    assert_eq!(it.iter().count(), 5);
+
+    let it = a.copied().padded_to(3, 0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 3]);
+
+    let it = a.copied().padded_to(1, 0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(it.iter().count(), 3);
+
+    let it = empty::<i32>().padded_to(2, 7);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [7, 7]);
+}
+
+#[test]
+fn padded_to_references() {
+    let a = vec!['a', 'b'];
+    let it = a.padded_to(4, &'-');
+    assert_eq!(it.iter().copied().collect::<String>(), "ab--");
+}
+
+#[test]
+fn padded_to_size_hint() {
+    let a = vec![1, 2, 3, 4];
+    let it = a.copied().filtered(|x| *x > 2).padded_to(3, 0);
+
+    let mut iter = it.iter();
+    assert_eq!(iter.size_hint(), (3, Some(4)));
+    _ = iter.next();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.collect::<Vec<_>>(), [4, 0]);
+}
+
+#[test]
+fn into_padded_to() {
+    let a = vec![String::from("x")];
+    let col = a.into_padded_to(3, String::from("_"));
+
+    assert_eq!(col.iter().map(|x| x.as_str()).collect::<String>(), "x__");
+    assert_eq!(col.iter().count(), 3);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_padded_to() {
+        let a = vec![1, 2];
+
+        let it = a.copied().padded_to(3, 0);
+        let obj: &dyn IterableObj<Item = i32> = &it;
+        assert_eq!(obj.boxed_iter().collect::<Vec<_>>(), [1, 2, 0]);
+
+        let col = a.into_padded_to(4, 0);
+        let obj: &dyn CollectionObj<Item = i32> = &col;
+        assert_eq!(obj.boxed_iter().copied().collect::<Vec<_>>(), [1, 2, 0, 0]);
+    }
+}
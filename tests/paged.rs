@@ -0,0 +1,102 @@
+use orx_iterable::*;
+
+fn pages_of<I: Iterable>(it: &I, page_size: usize) -> Vec<Vec<I::Item>> {
+    it.paged(page_size)
+        .iter()
+        .map(|page| page.iter().collect())
+        .collect()
+}
+
+#[test]
+fn paged() {
+    let a = vec![1, 2, 3, 4, 5, 6, 7];
+
+    assert_eq!(
+        pages_of(&a.copied(), 3),
+        [vec![1, 2, 3], vec![4, 5, 6], vec![7]]
+    );
+    assert_eq!(pages_of(&a.copied(), 7), [vec![1, 2, 3, 4, 5, 6, 7]]);
+    assert_eq!(pages_of(&a.copied(), 1).len(), 7);
+    assert_eq!(pages_of(&a.copied().taken(6), 2).len(), 3);
+    assert_eq!(pages_of(&a.copied(), 100), vec![a]);
+}
+
+#[test]
+fn paged_empty() {
+    let a: Vec<i32> = vec![];
+
+    assert!(pages_of(&a.copied(), 3).is_empty());
+    assert_eq!(a.copied().paged(3).page_count(), 0);
+    assert_eq!(a.copied().paged(3).page(0).iter().count(), 0);
+}
+
+#[test]
+fn paged_non_exact_size_source() {
+    let a = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    let evens = a.filtered(|x| *x % 2 == 0).copied();
+
+    let pages = evens.paged(3);
+    assert_eq!(pages.iter().count(), 2);
+    assert_eq!(pages.page(1).iter().collect::<Vec<_>>(), [8]);
+    assert_eq!(pages_of(&evens, 3), [vec![2, 4, 6], vec![8]]);
+}
+
+#[test]
+fn page_random_access() {
+    let a: Vec<_> = (0..100).collect();
+    let numbers = a.copied();
+    let pages = numbers.paged(10);
+
+    assert_eq!(pages.page_size(), 10);
+    assert_eq!(pages.page_count(), 10);
+
+    let page = pages.page(7);
+    assert_eq!(page.index(), 7);
+    assert_eq!(page.offset(), 70);
+    assert_eq!(
+        page.iter().collect::<Vec<_>>(),
+        (70..80).collect::<Vec<_>>()
+    );
+
+    assert_eq!(pages.page(10).iter().count(), 0);
+    assert_eq!(pages.page(usize::MAX).iter().count(), 0);
+}
+
+#[test]
+fn pages_are_reiterable() {
+    let a = vec!['a', 'b', 'c', 'd', 'e'];
+    let source = &a;
+    let pages = source.paged(2);
+
+    let page = pages.iter().nth(1).unwrap();
+    assert_eq!(page.iter().collect::<Vec<_>>(), [&'c', &'d']);
+    assert_eq!(page.iter().collect::<Vec<_>>(), [&'c', &'d']);
+    assert_eq!(
+        pages.iter().map(|p| p.index()).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    assert_eq!(pages.page_count(), 3);
+}
+
+#[test]
+#[should_panic]
+fn paged_zero_page_size() {
+    let a = vec![1, 2, 3];
+    let _ = a.copied().paged(0);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_paged() {
+        let a = vec![1, 2, 3, 4, 5];
+        let numbers = a.copied();
+        let pages = numbers.paged(2);
+
+        let obj: &dyn IterableObj<Item = _> = &pages;
+        let sums: Vec<i32> = obj.boxed_iter().map(|p| p.boxed_iter().sum()).collect();
+        assert_eq!(sums, [3, 7, 5]);
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::{parallel::*, *};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const NUM_THREADS: [usize; 5] = [1, 2, 3, 8, 64];
+
+#[test]
+fn par_for_each() {
+    let a: Vec<usize> = (0..1000).collect();
+
+    for num_threads in NUM_THREADS {
+        let count = AtomicUsize::new(0);
+        let sum = AtomicUsize::new(0);
+        a.par().with_num_threads(num_threads).for_each(|x| {
+            count.fetch_add(1, Ordering::Relaxed);
+            sum.fetch_add(*x, Ordering::Relaxed);
+        });
+        assert_eq!(count.into_inner(), 1000);
+        assert_eq!(sum.into_inner(), a.iter().sum::<usize>());
+    }
+
+    let count = AtomicUsize::new(0);
+    a.par_for_each(|_| _ = count.fetch_add(1, Ordering::Relaxed));
+    assert_eq!(count.into_inner(), 1000);
+}
+
+#[test]
+fn par_map_collect_preserves_order() {
+    let a: Vec<String> = (0..257).map(|x| x.to_string()).collect();
+    let expected: Vec<usize> = a.iter().map(|x| x.len()).collect();
+
+    for num_threads in NUM_THREADS {
+        let par = a.par().with_num_threads(num_threads);
+        assert_eq!(par.map_collect(|x| x.len()), expected);
+    }
+    assert_eq!(a.par_map_collect(|x| x.len()), expected);
+}
+
+#[test]
+fn par_reduce() {
+    let a: Vec<String> = (0..100).map(|x| (x % 10).to_string()).collect();
+    let expected: String = a.iter().map(|x| x.as_str()).collect();
+
+    for num_threads in NUM_THREADS {
+        let par = a.par().with_num_threads(num_threads);
+        assert_eq!(par.reduce(|x, y| x + &y), Some(expected.clone()));
+    }
+    assert_eq!(a.par_reduce(|x, y| x + &y), Some(expected));
+
+    let single = [42];
+    assert_eq!(single.par_reduce(|x, y| x + y), Some(42));
+}
+
+#[test]
+fn par_sum() {
+    let a: Vec<i64> = (-500..1000).collect();
+    let expected: i64 = a.iter().sum();
+
+    for num_threads in NUM_THREADS {
+        let par = a.par().with_num_threads(num_threads);
+        assert_eq!(par.sum::<i64>(), expected);
+    }
+    assert_eq!(a.par_sum::<i64>(), expected);
+}
+
+#[test]
+fn par_on_empty_collection() {
+    let a: Vec<u32> = vec![];
+
+    let count = AtomicUsize::new(0);
+    a.par_for_each(|_| _ = count.fetch_add(1, Ordering::Relaxed));
+    assert_eq!(count.into_inner(), 0);
+
+    assert!(a.par_map_collect(|x| x + 1).is_empty());
+    assert_eq!(a.par_reduce(|x, y| x + y), None);
+    assert_eq!(a.par_sum::<u32>(), 0);
+
+    let mut b: Vec<u32> = vec![];
+    b.par_for_each_mut(|x| *x += 1);
+    assert!(b.is_empty());
+}
+
+#[test]
+fn par_for_each_mut() {
+    let mut a: Vec<usize> = (0..1000).collect();
+
+    for num_threads in NUM_THREADS {
+        a.par_mut()
+            .with_num_threads(num_threads)
+            .for_each(|x| *x += 1);
+    }
+    assert_eq!(a, (5..1005).collect::<Vec<_>>());
+
+    a.par_for_each_mut(|x| *x *= 2);
+    assert_eq!(a.iter().copied().take(3).collect::<Vec<_>>(), [10, 12, 14]);
+}
+
+#[test]
+fn par_on_other_collections() {
+    let array = [1, 2, 3, 4];
+    assert_eq!(array.par_sum::<i32>(), 10);
+
+    let mut boxed: Box<[i32]> = vec![1, 2, 3].into_boxed_slice();
+    boxed.par_for_each_mut(|x| *x = -*x);
+    assert_eq!(boxed.par_map_collect(|x| x * 2), [-2, -4, -6]);
+}
+
+#[test]
+fn par_with_generic_collection() {
+    fn total_len<C>(names: &C) -> usize
+    where
+        C: ParCollection<Item = String>,
+    {
+        let total = names.par_map_collect(|x| x.len()).iter().sum();
+        assert_eq!(total, names.iter().map(|x| x.len()).sum::<usize>());
+        total
+    }
+
+    let names = vec![String::from("abc"), String::from("de")];
+    assert_eq!(total_len(&names), 5);
+}
+
+#[test]
+fn par_num_threads() {
+    let a = vec![1, 2, 3];
+    assert!(a.par().num_threads() > 0);
+    assert_eq!(a.par().with_num_threads(5).num_threads(), 5);
+
+    let mut b = vec![1, 2, 3];
+    assert_eq!(b.par_mut().with_num_threads(7).num_threads(), 7);
+}
+
+#[test]
+#[should_panic]
+fn par_zero_threads() {
+    let a = vec![1, 2, 3];
+    _ = a.par().with_num_threads(0);
+}
+
+#[test]
+#[should_panic]
+fn par_propagates_worker_panic() {
+    let a: Vec<i32> = (0..100).collect();
+    a.par()
+        .with_num_threads(4)
+        .for_each(|x| assert!(*x != 77, "worker failed"));
+}
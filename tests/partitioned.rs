@@ -0,0 +1,67 @@
+mod common_testers;
+use common_testers::{test_col, test_it, test_it_val};
+use orx_iterable::*;
+
+#[test]
+fn partitioned() {
+    let a = vec![1usize, 2, 3, 4, 5, 6, 7];
+
+    let numbers = &a;
+    let (even, odd) = numbers.partitioned(|x| *x % 2 == 0);
+    assert!(even.satisfies_predicate());
+    assert!(!odd.satisfies_predicate());
+    assert_eq!(even.iter().collect::<Vec<_>>(), [&2, &4, &6]);
+    test_it(vec![2, 4, 6], even);
+    test_it(vec![1, 3, 5, 7], odd);
+
+    let numbers = a.copied();
+    let (small, large) = numbers.partitioned(|x| *x < 3);
+    test_it_val(vec![1, 2], small);
+    test_it_val(vec![3, 4, 5, 6, 7], large);
+
+    let range = 0..5usize;
+    let (all, none) = range.partitioned(|_| true);
+    test_it_val(vec![0, 1, 2, 3, 4], all);
+    test_it_val(vec![], none);
+}
+
+#[test]
+fn as_partitioned() {
+    let a = vec![1usize, 2, 3, 4, 5, 6, 7];
+
+    let (even, odd) = a.as_partitioned(|x| *x % 2 == 0);
+    assert!(even.satisfies_predicate());
+    test_it(vec![2, 4, 6], &even);
+    test_it(vec![1, 3, 5, 7], &odd);
+    test_col(vec![2, 4, 6], even);
+    test_col(vec![1, 3, 5, 7], odd);
+
+    let b: Vec<usize> = vec![];
+    let (x, y) = b.as_partitioned(|x| *x > 0);
+    test_col(vec![], x);
+    test_col(vec![], y);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_partitioned() {
+    use common_testers::{obj_test_col, obj_test_it, obj_test_it_val};
+
+    let a = vec![1usize, 2, 3, 4, 5, 6, 7];
+
+    let numbers = &a;
+    let (even, odd) = numbers.partitioned(|x| *x % 2 == 0);
+    obj_test_it(vec![2, 4, 6], &even);
+    obj_test_it(vec![1, 3, 5, 7], &odd);
+
+    let numbers = a.copied();
+    let (small, large) = numbers.partitioned(|x| *x < 3);
+    obj_test_it_val(vec![1, 2], &small);
+    obj_test_it_val(vec![3, 4, 5, 6, 7], &large);
+
+    let (even, odd) = a.as_partitioned(|x| *x % 2 == 0);
+    obj_test_col(vec![2, 4, 6], &even);
+    obj_test_col(vec![1, 3, 5, 7], &odd);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::*;
+
+fn test_it(values: Vec<usize>, col: impl Iterable<Item = usize>) {
+    // tests
+    assert_eq!(col.iter().count(), values.len());
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+}
+
+#[test]
+fn round_robin_of_collections() {
+    let a = vec![1, 2, 3];
+    let b = vec![10];
+    let c = vec![100, 200, 300, 400];
+
+    test_it(
+        vec![1, 10, 100, 2, 200, 3, 300, 400],
+        round_robin([&a, &b, &c]).copied(),
+    );
+    test_it(vec![1, 2, 3], round_robin([&a]).copied());
+    test_it(vec![], round_robin(Vec::<&Vec<usize>>::new()).copied());
+
+    let empty = vec![];
+    test_it(vec![1, 2, 3], round_robin([&empty, &a, &empty]).copied());
+}
+
+#[test]
+fn round_robin_of_iterables() {
+    test_it(
+        vec![0, 10, 20, 1, 21, 2],
+        round_robin(vec![0..3usize, 10..11, 20..22]),
+    );
+
+    let a = vec![1, 2, 3, 4];
+    let evens = a.skipped(1).stepped_by(2).copied();
+    let odds = a.skipped(0).stepped_by(2).copied();
+    test_it(vec![2, 1, 4, 3], round_robin([evens, odds]));
+}
+
+#[test]
+fn obj_round_robin() {
+    use orx_iterable::obj_safe::*;
+
+    let it = round_robin(vec![0..3usize, 10..11, 20..22]);
+    assert_eq!(it.boxed_iter().count(), 6);
+    assert_eq!(it.boxed_iter().collect::<Vec<_>>(), [0, 10, 20, 1, 21, 2]);
+}
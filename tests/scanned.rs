@@ -0,0 +1,89 @@
+use orx_iterable::*;
+
+fn test_it(values: Vec<i64>, col: impl Iterable<Item = i64>) {
+    // tests
+    assert_eq!(col.iter().count(), values.len());
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+
+    // state is reset on every iteration
+    assert_eq!(col.iter().collect::<Vec<_>>(), values);
+}
+
+#[test]
+fn scanned() {
+    let a = vec![1i64, 2, 3, 4, 5];
+
+    test_it(
+        vec![1, 3, 6, 10, 15],
+        a.scanned(0, |s, x| {
+            *s += x;
+            Some(*s)
+        }),
+    );
+
+    test_it(
+        vec![1, 3, 6],
+        a.scanned(0, |s, x| {
+            *s += x;
+            (*s < 10).then_some(*s)
+        }),
+    );
+
+    test_it(vec![], a.scanned(0, |_, _| None::<i64>));
+}
+
+#[test]
+fn prefix_sums() {
+    test_it(
+        vec![1, 3, 6, 10, 15],
+        [1, 2, 3, 4, 5].copied().prefix_sums(),
+    );
+    test_it(vec![-1], [-1].copied().prefix_sums());
+    test_it(vec![], Vec::<i64>::new().copied().prefix_sums());
+    test_it(vec![3, 7, 12], (3..6i64).prefix_sums());
+}
+
+#[test]
+fn running_max() {
+    test_it(vec![3, 3, 5, 5, 9], [3, 1, 5, 2, 9].copied().running_max());
+    test_it(vec![], Vec::<i64>::new().copied().running_max());
+}
+
+#[test]
+fn differences() {
+    test_it(vec![3, 2, -1, 0], [1, 4, 6, 5, 5].copied().differences());
+    test_it(vec![], [1].copied().differences());
+    test_it(vec![], Vec::<i64>::new().copied().differences());
+    test_it(
+        vec![2, 3, 4],
+        [1, 2, 3, 4].copied().prefix_sums().differences(),
+    );
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, *};
+
+    fn obj_test_it(values: Vec<i64>, col: &dyn IterableObj<Item = i64>) {
+        // tests
+        assert_eq!(col.boxed_iter().count(), values.len());
+        assert_eq!(col.boxed_iter().collect::<Vec<_>>(), values);
+        assert_eq!(col.boxed_iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn obj_scanned() {
+        let a = vec![1i64, 2, 3, 4, 5];
+
+        obj_test_it(
+            vec![1, 3, 6],
+            &a.scanned(0, |s, x| {
+                *s += x;
+                (*s < 10).then_some(*s)
+            }),
+        );
+        obj_test_it(vec![1, 3, 6, 10, 15], &a.copied().prefix_sums());
+        obj_test_it(vec![1, 2, 3, 4, 5], &a.copied().running_max());
+        obj_test_it(vec![1, 1, 1, 1], &a.copied().differences());
+    }
+}
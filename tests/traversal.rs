@@ -0,0 +1,131 @@
+#![cfg(feature = "std")]
+
+use orx_iterable::*;
+
+fn tree() -> Vec<Vec<usize>> {
+    //         0
+    //      /  |  \
+    //     1   2   3
+    //    / \      |
+    //   4   5     6
+    //             |
+    //             7
+    vec![
+        vec![1, 2, 3],
+        vec![4, 5],
+        vec![],
+        vec![6],
+        vec![],
+        vec![],
+        vec![7],
+        vec![],
+    ]
+}
+
+fn cyclic_graph() -> Vec<Vec<usize>> {
+    // edges: 0->1, 0->2, 1->2, 2->0, 2->3, 3->3
+    vec![vec![1, 2], vec![2], vec![0, 3], vec![3]]
+}
+
+#[test]
+fn bfs_tree() {
+    let adj = tree();
+    let it = bfs(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let it = bfs(3, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [3, 6, 7]);
+
+    let it = bfs(7, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [7]);
+}
+
+#[test]
+fn bfs_graph() {
+    let adj = cyclic_graph();
+    let it = bfs(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    let it = bfs(2, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [2, 0, 3, 1]);
+
+    let it = bfs(3, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn dfs_tree() {
+    let adj = tree();
+    let it = dfs_preorder(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 4, 5, 2, 3, 6, 7]);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 4, 5, 2, 3, 6, 7]);
+
+    let it = dfs_postorder(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [4, 5, 1, 2, 7, 6, 3, 0]);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [4, 5, 1, 2, 7, 6, 3, 0]);
+
+    let it = descendants(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 4, 5, 2, 3, 6, 7]);
+
+    let it = descendants(3, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [6, 7]);
+
+    let it = descendants(4, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().count(), 0);
+}
+
+#[test]
+fn dfs_graph() {
+    let adj = cyclic_graph();
+    let it = dfs_preorder(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    let it = dfs_postorder(0, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [3, 2, 1, 0]);
+
+    let it = descendants(2, |x: &usize| adj[*x].copied());
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 3]);
+}
+
+#[test]
+fn traversal_with_generated_children() {
+    // implicit binary tree over 1..16 where children of n are 2n and 2n+1
+    let children = |x: &usize| (2 * x..2 * x + 2).filtered(|c| *c < 16);
+
+    let it = bfs(1, children);
+    assert_eq!(it.iter().collect::<Vec<_>>(), (1..16).collect::<Vec<_>>());
+
+    let it = dfs_preorder(2, children);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [2, 4, 8, 9, 5, 10, 11]);
+
+    let it = dfs_postorder(2, children);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [8, 9, 4, 10, 11, 5, 2]);
+
+    let it = descendants(1, children).filtered(|x| x % 2 == 1);
+    assert_eq!(it.iter().count(), 7);
+}
+
+// obj
+
+#[test]
+fn obj_traversal() {
+    use orx_iterable::obj_safe::*;
+
+    let adj = tree();
+    let traversals: Vec<Box<dyn IterableObj<Item = usize> + '_>> = vec![
+        Box::new(bfs(0, |x: &usize| adj[*x].copied())),
+        Box::new(dfs_preorder(0, |x: &usize| adj[*x].copied())),
+        Box::new(dfs_postorder(0, |x: &usize| adj[*x].copied())),
+        Box::new(descendants(0, |x: &usize| adj[*x].copied())),
+    ];
+
+    let counts: Vec<_> = traversals.iter().map(|x| x.boxed_iter().count()).collect();
+    assert_eq!(counts, [8, 8, 8, 7]);
+
+    let sums: Vec<_> = traversals
+        .iter()
+        .map(|x| x.boxed_iter().sum::<usize>())
+        .collect();
+    assert_eq!(sums, [28, 28, 28, 28]);
+}
@@ -0,0 +1,112 @@
+use orx_iterable::*;
+
+fn parse(x: &&str) -> Result<usize, String> {
+    x.parse::<usize>().map_err(|_| x.to_string())
+}
+
+#[test]
+fn try_mapped() {
+    let a = ["1", "2", "3"];
+    let it = a.mapped(parse).try_mapped(|x| x * 10);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(10), Ok(20), Ok(30)]);
+    assert_eq!(it.iter().count(), 3);
+
+    let a = ["1", "x", "3", "y"];
+    let it = a.mapped(parse).try_mapped(|x| x * 10);
+    let expected = [Ok(10), Err("x".to_string())];
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+
+    let it = a.mapped(parse).try_mapped(|x| x + 1).try_mapped(|x| x * 2);
+    assert_eq!(it.try_collect::<Vec<_>>(), Err("x".to_string()));
+}
+
+#[test]
+fn try_filtered() {
+    let a = ["1", "2", "3", "4"];
+    let it = a.mapped(parse).try_filtered(|x| x % 2 == 0);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(2), Ok(4)]);
+    assert_eq!(it.try_sum::<usize>(), Ok(6));
+
+    let a = ["1", "2", "x", "4", "y"];
+    let it = a.mapped(parse).try_filtered(|x| x % 2 == 1);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [Ok(1), Err("x".to_string())]);
+    assert_eq!(it.try_sum::<usize>(), Err("x".to_string()));
+}
+
+#[test]
+fn ok_values_errors() {
+    let a = ["1", "x", "3", "y"];
+
+    let it = a.mapped(parse).ok_values();
+    assert_eq!(it.iter().collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(it.iter().sum::<usize>(), 4);
+
+    let it = a.mapped(parse).errors();
+    assert_eq!(it.iter().collect::<Vec<_>>(), ["x", "y"]);
+
+    let a: [&str; 0] = [];
+    assert_eq!(a.mapped(parse).ok_values().iter().count(), 0);
+    assert_eq!(a.mapped(parse).errors().iter().count(), 0);
+}
+
+#[test]
+fn reducers() {
+    let a = ["1", "2", "3"];
+    assert_eq!(a.mapped(parse).try_collect::<Vec<_>>(), Ok(vec![1, 2, 3]));
+    assert_eq!(a.mapped(parse).try_sum::<usize>(), Ok(6));
+
+    let mut sum = 0;
+    assert_eq!(a.mapped(parse).try_for_each(|x| sum += x), Ok(()));
+    assert_eq!(sum, 6);
+
+    let a = ["1", "2", "x", "3", "y"];
+    assert_eq!(
+        a.mapped(parse).try_collect::<Vec<_>>(),
+        Err("x".to_string())
+    );
+    assert_eq!(a.mapped(parse).try_sum::<usize>(), Err("x".to_string()));
+
+    let mut visited = vec![];
+    let result = a.mapped(parse).try_for_each(|x| visited.push(x));
+    assert_eq!(result, Err("x".to_string()));
+    assert_eq!(visited, [1, 2]);
+}
+
+#[test]
+fn try_iterable_as_bound() {
+    fn count_valid<I: TryIterable>(it: I) -> (usize, usize) {
+        let num_ok = it.iter().filter(|x| x.is_ok()).count();
+        let num_err = it.iter().count() - num_ok;
+        (num_ok, num_err)
+    }
+
+    let a = ["1", "x", "3", "y", "z"];
+    assert_eq!(count_valid(a.mapped(parse)), (2, 3));
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_try_iterable() {
+    use orx_iterable::obj_safe::*;
+
+    let a = ["1", "x", "3", "4"];
+
+    let it = a.mapped(parse).try_mapped(|x| x * 2);
+    let it: &dyn IterableObj<Item = Result<usize, String>> = &it;
+    assert_eq!(it.boxed_iter().count(), 2);
+
+    let it = a.mapped(parse).try_filtered(|x| *x > 1);
+    let it: &dyn IterableObj<Item = Result<usize, String>> = &it;
+    assert_eq!(it.boxed_iter().collect::<Vec<_>>(), [Err("x".to_string())]);
+
+    let it = a.mapped(parse).ok_values();
+    let it: &dyn IterableObj<Item = usize> = &it;
+    assert_eq!(it.boxed_iter().sum::<usize>(), 8);
+
+    let it = a.mapped(parse).errors();
+    let it: &dyn IterableObj<Item = String> = &it;
+    assert_eq!(it.boxed_iter().collect::<Vec<_>>(), ["x"]);
+}
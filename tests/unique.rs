@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+
+mod common_testers;
+use common_testers::test_it;
+use orx_iterable::*;
+
+#[test]
+fn unique() {
+    let a = vec![1, 3, 1, 4, 3, 8, 1];
+
+    test_it(vec![1, 3, 4, 8], a.unique());
+    test_it(vec![1, 3, 4, 8], a.unique().unique());
+    test_it(vec![], Vec::<usize>::new().unique());
+
+    let it = a.unique();
+    assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &3, &4, &8]);
+    assert_eq!(it.iter().collect::<Vec<_>>(), [&1, &3, &4, &8]);
+}
+
+#[test]
+fn unique_by_key() {
+    let a = vec![1, 3, 4, 6, 8, 7, 10, 12];
+
+    test_it(vec![1, 4], a.unique_by_key(|x| *x % 2));
+    test_it(vec![1, 4, 8, 12], a.unique_by_key(|x| *x / 4));
+    test_it(a.clone(), a.unique_by_key(|x| **x));
+}
+
+#[test]
+fn unique_values() {
+    let it = (0..20usize).mapped(|x| x % 3).unique();
+    assert_eq!(it.iter().collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(it.iter().sum::<usize>(), 3);
+}
+
+// obj
+
+#[test]
+fn obj_unique() {
+    use common_testers::obj_test_it;
+
+    let a = vec![1, 3, 1, 4, 3, 8, 1];
+
+    obj_test_it(vec![1, 3, 4, 8], &a.unique());
+    obj_test_it(vec![1, 4], &a.unique_by_key(|x| *x % 2));
+}
@@ -0,0 +1,62 @@
+mod common_testers;
+use common_testers::{test_col, test_it, test_it_val};
+use orx_iterable::*;
+
+#[test]
+fn unzipped() {
+    let a = vec![(1usize, 10usize), (2, 20), (3, 30)];
+
+    let pairs = a.copied();
+    let (left, right) = pairs.unzipped();
+    assert_eq!(left.iter().collect::<Vec<_>>(), [1, 2, 3]);
+    test_it_val(vec![1, 2, 3], left);
+    test_it_val(vec![10, 20, 30], right);
+
+    let pairs = a.copied().taken(2);
+    let (left, right) = pairs.unzipped();
+    test_it_val(vec![1, 2], right.mapped(|x| x / 10));
+    test_it_val(vec![1, 2], left);
+
+    let pairs = a.mapped(|(x, y)| (x, y));
+    let (left, right) = pairs.unzipped();
+    test_it(vec![1, 2, 3], left);
+    test_it(vec![10, 20, 30], right);
+}
+
+#[test]
+fn as_unzipped() {
+    let a = vec![(1usize, 10usize), (2, 20), (3, 30)];
+
+    let (left, right) = a.as_unzipped();
+    test_col(vec![1, 2, 3], left);
+    test_col(vec![10, 20, 30], right);
+
+    let (left, right) = a.as_unzipped();
+    test_it(vec![1, 2, 3], &left);
+    test_it(vec![10, 20, 30], &right);
+    test_it(vec![1, 2, 3], &left);
+
+    let b: Vec<(usize, char)> = vec![];
+    let (left, right) = b.as_unzipped();
+    test_col(vec![], left);
+    assert_eq!(right.iter().count(), 0);
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_unzipped() {
+    use common_testers::{obj_test_col, obj_test_it_val};
+
+    let a = vec![(1usize, 10usize), (2, 20), (3, 30)];
+
+    let pairs = a.copied();
+    let (left, right) = pairs.unzipped();
+    obj_test_it_val(vec![1, 2, 3], &left);
+    obj_test_it_val(vec![10, 20, 30], &right);
+
+    let (left, right) = a.as_unzipped();
+    obj_test_col(vec![1, 2, 3], &left);
+    obj_test_col(vec![10, 20, 30], &right);
+}
@@ -0,0 +1,96 @@
+use orx_iterable::transformations::Position;
+use orx_iterable::*;
+
+fn positions<I: Iterable>(it: I) -> Vec<Position> {
+    it.with_position().iter().map(|x| x.0).collect()
+}
+
+#[test]
+fn with_position() {
+    let a = vec![1, 2, 3, 4];
+    assert_eq!(
+        positions(&a),
+        [
+            Position::First,
+            Position::Middle,
+            Position::Middle,
+            Position::Last
+        ]
+    );
+    assert_eq!(positions(a.taken(2)), [Position::First, Position::Last]);
+    assert_eq!(positions(a.taken(1)), [Position::Only]);
+    assert_eq!(positions(a.taken(0)), []);
+
+    let it = a.copied().with_position();
+    assert_eq!(it.iter().map(|x| x.1).collect::<Vec<_>>(), a);
+    assert_eq!(it.iter().count(), 4);
+}
+
+#[test]
+fn with_position_on_non_fused_source() {
+    let a = vec![1, 2, 3, 4, 5];
+    let it = a.filtered(|x| *x % 2 == 1).with_position();
+    assert_eq!(
+        it.iter().collect::<Vec<_>>(),
+        [
+            (Position::First, &1),
+            (Position::Middle, &3),
+            (Position::Last, &5)
+        ]
+    );
+}
+
+#[test]
+fn position_is_first_is_last() {
+    assert!(Position::First.is_first());
+    assert!(!Position::First.is_last());
+    assert!(!Position::Middle.is_first());
+    assert!(!Position::Middle.is_last());
+    assert!(Position::Last.is_last());
+    assert!(!Position::Last.is_first());
+    assert!(Position::Only.is_first());
+    assert!(Position::Only.is_last());
+}
+
+#[test]
+fn with_position_collection() {
+    let a = vec!['a', 'b'].into_filtered(|x| *x != 'x');
+    assert_eq!(
+        (&a).with_position().iter().collect::<Vec<_>>(),
+        [(Position::First, &'a'), (Position::Last, &'b')]
+    );
+}
+
+#[test]
+fn with_position_mut() {
+    let mut a = vec![1, 2, 3];
+    for (p, x) in a.with_position_mut() {
+        if p.is_last() {
+            *x *= 100;
+        } else {
+            *x *= 10;
+        }
+    }
+    assert_eq!(a, [10, 20, 300]);
+
+    let mut b = vec![7];
+    let items: Vec<_> = b.with_position_mut().collect();
+    assert_eq!(items, [(Position::Only, &mut 7)]);
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::transformations::Position;
+    use orx_iterable::{obj_safe::*, *};
+
+    #[test]
+    fn obj_with_position() {
+        let a = vec![1, 2];
+        let it = a.with_position();
+        let obj: &dyn IterableObj<Item = (Position, &i32)> = &it;
+        assert_eq!(
+            obj.boxed_iter().collect::<Vec<_>>(),
+            [(Position::First, &1), (Position::Last, &2)]
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use orx_iterable::{transformations::LengthMismatch, *};
+
+#[test]
+fn zipped_exact() {
+    let a = vec![1, 2, 3];
+    let b = vec![true, false, true];
+
+    let it = a.copied().zipped_exact(b.copied());
+    assert_eq!(it.iter().count(), 3);
+    assert!(it.iter().all(|x| x.is_ok()));
+    assert_eq!(
+        it.iter().collect::<Result<Vec<_>, _>>(),
+        Ok(vec![(1, true), (2, false), (3, true)])
+    );
+}
+
+#[test]
+fn zipped_exact_first_longer() {
+    let a = vec![1, 2, 3];
+    let b = vec![true, false];
+
+    let it = a.copied().zipped_exact(b.copied());
+    let items: Vec<_> = it.iter().collect();
+    assert_eq!(
+        items,
+        [
+            Ok((1, true)),
+            Ok((2, false)),
+            Err(LengthMismatch::FirstIsLonger(2))
+        ]
+    );
+    assert_eq!(
+        it.iter().collect::<Result<Vec<_>, _>>(),
+        Err(LengthMismatch::FirstIsLonger(2))
+    );
+}
+
+#[test]
+fn zipped_exact_second_longer() {
+    let a = vec![1];
+    let b = vec![true, false];
+
+    let it = a.copied().zipped_exact(b.copied());
+    let items: Vec<_> = it.iter().collect();
+    assert_eq!(
+        items,
+        [Ok((1, true)), Err(LengthMismatch::SecondIsLonger(1))]
+    );
+
+    let it = a.taken(0).zipped_exact(&b);
+    assert_eq!(
+        it.iter().collect::<Vec<_>>(),
+        [Err(LengthMismatch::SecondIsLonger(0))]
+    );
+}
+
+#[test]
+fn zipped_exact_empty() {
+    let a: Vec<i32> = vec![];
+    let b: Vec<bool> = vec![];
+
+    let it = a.zipped_exact(&b);
+    assert_eq!(it.iter().count(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn length_mismatch_display() {
+    use std::string::ToString;
+
+    let error: Box<dyn std::error::Error> = Box::new(LengthMismatch::FirstIsLonger(2));
+    assert_eq!(
+        error.to_string(),
+        "the second iterable ended after 2 elements while the first continued"
+    );
+
+    let error: Box<dyn std::error::Error> = Box::new(LengthMismatch::SecondIsLonger(3));
+    assert_eq!(
+        error.to_string(),
+        "the first iterable ended after 3 elements while the second continued"
+    );
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, transformations::LengthMismatch, *};
+
+    #[test]
+    fn obj_zipped_exact() {
+        let a = vec![1, 2, 3];
+        let b = vec![true, false];
+
+        let it = a.copied().zipped_exact(b.copied());
+        let it: &dyn IterableObj<Item = Result<(i32, bool), LengthMismatch>> = &it;
+
+        assert_eq!(it.boxed_iter().count(), 3);
+        assert_eq!(
+            it.boxed_iter().last(),
+            Some(Err(LengthMismatch::FirstIsLonger(2)))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use orx_iterable::{transformations::EitherOrBoth, *};
+
+#[test]
+fn zipped_longest() {
+    let a = vec![1, 2, 3];
+    let b = vec![true, false];
+
+    let it = a.copied().zipped_longest(b.copied());
+    let expected = [
+        EitherOrBoth::Both(1, true),
+        EitherOrBoth::Both(2, false),
+        EitherOrBoth::Left(3),
+    ];
+    assert_eq!(it.iter().count(), 3);
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+
+    let it = b.copied().zipped_longest(a.copied());
+    let expected = [
+        EitherOrBoth::Both(true, 1),
+        EitherOrBoth::Both(false, 2),
+        EitherOrBoth::Right(3),
+    ];
+    assert_eq!(it.iter().collect::<Vec<_>>(), expected);
+
+    let it = a.zipped_longest(&a);
+    assert!(it.iter().all(|x| x.is_both()));
+
+    let it = a.taken(0).zipped_longest(b.taken(0));
+    assert_eq!(it.iter().count(), 0);
+}
+
+#[test]
+fn either_or_both() {
+    let x = EitherOrBoth::<i32, char>::Both(1, 'a');
+    assert_eq!(x.left(), Some(1));
+    assert_eq!(x.right(), Some('a'));
+    assert_eq!(x.both(), Some((1, 'a')));
+    assert_eq!(x.into_options(), (Some(1), Some('a')));
+
+    let x = EitherOrBoth::<i32, char>::Left(1);
+    assert!(x.is_left());
+    assert_eq!(x.left(), Some(1));
+    assert_eq!(x.right(), None);
+    assert_eq!(x.both(), None);
+    assert_eq!(x.into_options(), (Some(1), None));
+
+    let x = EitherOrBoth::<i32, char>::Right('a');
+    assert!(x.is_right());
+    assert_eq!(x.left(), None);
+    assert_eq!(x.right(), Some('a'));
+    assert_eq!(x.into_options(), (None, Some('a')));
+}
+
+#[cfg(feature = "std")]
+mod object_safe {
+    use orx_iterable::{obj_safe::*, transformations::EitherOrBoth, *};
+
+    #[test]
+    fn obj_zipped_longest() {
+        let a = vec![1, 2, 3];
+        let b = vec![true, false];
+
+        let it = a.copied().zipped_longest(b.copied());
+        let it: &dyn IterableObj<Item = EitherOrBoth<i32, bool>> = &it;
+
+        assert_eq!(it.boxed_iter().count(), 3);
+        assert_eq!(it.boxed_iter().filter(|x| x.is_both()).count(), 2);
+        assert_eq!(it.boxed_iter().filter_map(|x| x.left()).sum::<i32>(), 6);
+    }
+}
@@ -0,0 +1,29 @@
+mod common_testers;
+use common_testers::test_it_val;
+use orx_iterable::*;
+
+#[test]
+fn zipped_with() {
+    let a = vec![1, 2, 3, 4];
+    let b = vec![10, 20, 30];
+
+    test_it_val(vec![11, 22, 33], a.zipped_with(&b, |x, y| x + y));
+    test_it_val(vec![10, 40, 90], a.zipped_with(&b, |x, y| x * y));
+    test_it_val(vec![11, 22], a.zipped_with(b.taken(2), |x, y| x + y));
+    test_it_val(vec![], a.zipped_with(b.taken(0), |x, y| x + y));
+    test_it_val(vec![1, 3, 5], (0..3usize).zipped_with(&a, |x, y| x + y));
+}
+
+// obj
+
+#[cfg(feature = "std")]
+#[test]
+fn obj_zipped_with() {
+    use common_testers::obj_test_it_val;
+
+    let a = vec![1, 2, 3, 4];
+    let b = vec![10, 20, 30];
+
+    obj_test_it_val(vec![11, 22, 33], &a.zipped_with(&b, |x, y| x + y));
+    obj_test_it_val(vec![10, 40, 90], &a.zipped_with(&b, |x, y| x * y));
+}